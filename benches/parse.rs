@@ -0,0 +1,85 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Throughput,
+    Criterion
+};
+
+use sdp::attributes::Attributes;
+use sdp::Sdp;
+use std::convert::TryFrom;
+
+const AUDIO_VIDEO_DATACHANNEL: &str = include_str!("../tests/fixtures/audio_video_datachannel.sdp");
+const SIMULCAST_OFFER: &str = include_str!("../tests/fixtures/simulcast_offer.sdp");
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, input) in [
+        ("audio_video_datachannel", AUDIO_VIDEO_DATACHANNEL),
+        ("simulcast_offer", SIMULCAST_OFFER),
+    ] {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| Sdp::try_from(criterion::black_box(input)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn attributes(c: &mut Criterion) {
+    let lines = AUDIO_VIDEO_DATACHANNEL
+        .lines()
+        .filter_map(|l| l.strip_prefix("a="))
+        .collect::<Vec<_>>();
+
+    c.bench_function("attributes", |b| {
+        b.iter(|| {
+            for line in &lines {
+                Attributes::try_from(criterion::black_box(*line)).unwrap();
+            }
+        })
+    });
+
+    // attributes without a typed variant, which go through every name
+    // before they are kept as they are.
+    let others = lines
+        .iter()
+        .copied()
+        .filter(|l| matches!(Attributes::try_from(*l), Ok(Attributes::Other(..))))
+        .collect::<Vec<_>>();
+
+    c.bench_function("dispatch", |b| {
+        b.iter(|| {
+            for line in &others {
+                Attributes::try_from(criterion::black_box(*line)).unwrap();
+            }
+        })
+    });
+}
+
+#[cfg(feature = "bumpalo")]
+fn parse_in(c: &mut Criterion) {
+    let mut bump = bumpalo::Bump::new();
+    let mut group = c.benchmark_group("parse_in");
+    for (name, input) in [
+        ("audio_video_datachannel", AUDIO_VIDEO_DATACHANNEL),
+        ("simulcast_offer", SIMULCAST_OFFER),
+    ] {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                criterion::black_box(Sdp::parse_in(&bump, criterion::black_box(input)).unwrap());
+                bump.reset();
+            })
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(not(feature = "bumpalo"))]
+criterion_group!(benches, parse, attributes);
+#[cfg(feature = "bumpalo")]
+criterion_group!(benches, parse, attributes, parse_in);
+criterion_main!(benches);
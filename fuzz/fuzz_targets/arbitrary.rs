@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+use sdp::Sdp;
+
+// structured fuzzing: arbitrary sessions may not serialize to valid
+// text, but serializing must not panic, and anything that parses back
+// must be stable from then on.
+fuzz_target!(|sdp: Sdp| {
+    let first = sdp.to_string();
+    let parsed = match Sdp::try_from(first.as_str()) {
+        Ok(v) => v.to_string(),
+        Err(_) => return,
+    };
+
+    let second = Sdp::try_from(parsed.as_str())
+        .unwrap_or_else(|e| panic!("reparse failed: {}\n{}", e, parsed))
+        .to_string();
+    assert_eq!(parsed, second);
+});
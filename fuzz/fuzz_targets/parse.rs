@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sdp::options::ParseOptions;
+use sdp::lines::Lines;
+use sdp::Sdp;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(value) = std::str::from_utf8(data) {
+        let _ = Sdp::parse(value, &ParseOptions::default());
+        let _ = Sdp::parse(value, &ParseOptions::strict());
+        Lines::new(value, &ParseOptions::default()).for_each(drop);
+    }
+});
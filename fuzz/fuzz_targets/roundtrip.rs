@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+use sdp::Sdp;
+
+// parse -> display -> parse must succeed, and displaying the second
+// parse must give the same text again.  The serialized forms are
+// compared rather than the values, because a repeat time of "NaN"
+// never compares equal to itself.
+fuzz_target!(|data: &[u8]| {
+    let value = match std::str::from_utf8(data) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    let sdp = match Sdp::try_from(value) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    let first = sdp.to_string();
+    let second = Sdp::try_from(first.as_str())
+        .unwrap_or_else(|e| panic!("reparse failed: {}\n{}", e, first))
+        .to_string();
+    assert_eq!(first, second);
+});
@@ -0,0 +1,109 @@
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use super::{
+    options::{
+        ParseOptions,
+        Limit
+    },
+    attributes::{
+        Attributes,
+        Attribute
+    },
+    lines::{
+        Lines,
+        Line
+    },
+    media::Media
+};
+
+/// Arena allocated media description.
+#[derive(Debug)]
+pub struct ArenaMedia<'bump, 'a> {
+    /// The "m=" line, its attribute list is left empty.
+    pub media: Media<'a>,
+    /// Lines following the "m=" line, in input order.
+    pub lines: Vec<'bump, Line<'a>>,
+}
+
+/// Arena allocated session description.
+///
+/// Produced by [`Sdp::parse_in`](crate::Sdp::parse_in).  The lines of the
+/// session and of every media description are kept in input order in
+/// vectors that live in the arena, so that parsing a large number of
+/// descriptions puts no pressure on the global allocator.  The arena can
+/// be reset once the descriptions are no longer needed.
+#[derive(Debug)]
+pub struct ArenaSdp<'bump, 'a> {
+    /// Session level lines, in input order.
+    pub lines: Vec<'bump, Line<'a>>,
+    /// Media descriptions.
+    pub medias: Vec<'bump, ArenaMedia<'bump, 'a>>,
+}
+
+impl<'bump, 'a> ArenaMedia<'bump, 'a> {
+    /// media level attributes.
+    pub fn attributes(&self) -> impl Iterator<Item = &Attribute<'a>> {
+        attributes(&self.lines)
+    }
+}
+
+impl<'bump, 'a> ArenaSdp<'bump, 'a> {
+    /// parse a session description into the arena.
+    pub fn parse(
+        bump: &'bump Bump, 
+        value: &'a str, 
+        options: &ParseOptions
+    ) -> anyhow::Result<Self> {
+        let mut sdp = Self {
+            lines: Vec::with_capacity_in(16, bump),
+            medias: Vec::new_in(bump),
+        };
+
+        let limits = &options.limits;
+        let mut attributes = 0;
+        for line in Lines::new(value, options) {
+            let line = line?;
+            match &line {
+                Line::Media(_) => {
+                    limits.check(Limit::Medias, sdp.medias.len() + 1)?;
+                    attributes = 0;
+                },
+                Line::Attribute(a) => {
+                    if let Attributes::Fmtp(fmtp) = &a.value {
+                        limits.check(Limit::FmtpParams, fmtp.values.len())?;
+                    }
+
+                    attributes += 1;
+                    limits.check(Limit::Attributes, attributes)?;
+                    options.check_usage(a, !sdp.medias.is_empty())?;
+                },
+                _ => ()
+            }
+
+            match line {
+                Line::Media(media) => sdp.medias.push(ArenaMedia {
+                    lines: Vec::with_capacity_in(32, bump),
+                    media
+                }),
+                line => match sdp.medias.last_mut() {
+                    Some(media) => media.lines.push(line),
+                    None => sdp.lines.push(line),
+                }
+            }
+        }
+
+        Ok(sdp)
+    }
+
+    /// session level attributes.
+    pub fn attributes(&self) -> impl Iterator<Item = &Attribute<'a>> {
+        attributes(&self.lines)
+    }
+}
+
+fn attributes<'b, 'a>(lines: &'b [Line<'a>]) -> impl Iterator<Item = &'b Attribute<'a>> {
+    lines.iter().filter_map(|line| match line {
+        Line::Attribute(a) => Some(a),
+        _ => None
+    })
+}
@@ -0,0 +1,483 @@
+//! SDP capability negotiation
+//! [RFC5939](https://datatracker.ietf.org/doc/html/rfc5939).
+//!
+//! Capabilities ("a=tcap:", "a=acap:") are numbered, and the potential
+//! ("a=pcfg:") and actual ("a=acfg:") configurations refer to them by
+//! number.
+
+use crate::scanner::Scanner;
+use super::{
+    Attribute,
+    Attributes
+};
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// parse a "," separated list of capability numbers.
+fn parse_numbers(value: &str) -> Result<Vec<u32>> {
+    value
+        .split(',')
+        .map(|v| Ok(v.parse()?))
+        .collect()
+}
+
+fn write_numbers(f: &mut fmt::Formatter<'_>, numbers: &[u32], sep: &str) -> fmt::Result {
+    for (i, n) in numbers.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", sep)?;
+        }
+
+        write!(f, "{}", n)?;
+    }
+
+    Ok(())
+}
+
+/// Name:  tcap
+/// Value:  trpr-cap-num 1*(SP proto)
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=tcap:1 RTP/SAVPF RTP/SAVP
+///
+/// Transport protocol capabilities.  The first protocol has the given
+/// number, the following ones are numbered consecutively.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let tcap = Tcap::try_from("1 RTP/SAVPF RTP/SAVP").unwrap();
+/// assert_eq!(tcap.get(1), Some("RTP/SAVPF"));
+/// assert_eq!(tcap.get(2), Some("RTP/SAVP"));
+/// assert_eq!(tcap.get(3), None);
+/// assert_eq!(format!("{}", tcap), "1 RTP/SAVPF RTP/SAVP");
+///
+/// assert!(Tcap::try_from("0 RTP/SAVP").is_err());
+/// assert!(Tcap::try_from("1").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tcap<'a> {
+    /// the number of the first protocol.
+    pub number: u32,
+    pub protos: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Tcap<'a> {
+    /// the protocol with the given capability number.
+    pub fn get(&self, number: u32) -> Option<&str> {
+        let i = number.checked_sub(self.number)?;
+        self.protos.get(i as usize).map(|v| v.as_ref())
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Tcap<'b> {
+        Tcap {
+            number: self.number,
+            protos: self.protos
+                .into_iter()
+                .map(|v| Cow::Owned(v.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Tcap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number)?;
+        for v in &self.protos {
+            write!(f, " {}", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Tcap<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "tcap");
+        let number = scanner.field()?.parse()?;
+        let protos: Vec<_> = scanner.fields().map(Cow::Borrowed).collect();
+        ensure!(number > 0 && !protos.is_empty(), "invalid tcap!");
+        Ok(Self {
+            number,
+            protos,
+        })
+    }
+}
+
+/// Name:  acap
+/// Value:  att-cap-num SP att-par
+/// Usage Level:  session, media
+/// Charset Dependent:  depends on the capability attribute
+///
+/// Example:
+/// a=acap:1 crypto:1 AES_CM_128_HMAC_SHA1_80 inline:WVNfX19zZW1jdGwgKCkgewkyMjA7fQp9CnVubGVz|2^20|1:4
+///
+/// An attribute capability, i.e. an attribute that a configuration can
+/// add to the media description.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let acap = Acap::try_from("2 ptime:20").unwrap();
+/// assert_eq!(acap.number, 2);
+/// assert_eq!(acap.attribute, "ptime:20");
+/// assert!(matches!(acap.value().unwrap(), Attributes::Ptime(20)));
+/// assert_eq!(format!("{}", acap), "2 ptime:20");
+///
+/// assert!(Acap::try_from("2").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Acap<'a> {
+    pub number: u32,
+    /// the attribute, without the "a=" prefix.
+    pub attribute: Cow<'a, str>,
+}
+
+impl<'a> Acap<'a> {
+    /// parse the attribute.
+    pub fn value(&self) -> Result<Attributes<'_>> {
+        Attributes::try_from(self.attribute.as_ref())
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Acap<'b> {
+        Acap {
+            number: self.number,
+            attribute: Cow::Owned(self.attribute.into_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Acap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.number, self.attribute)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Acap<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "acap");
+        let number = scanner.field()?.parse()?;
+        let attribute = scanner.rest()?;
+        ensure!(number > 0, "invalid acap!");
+        Ok(Self {
+            number,
+            attribute: Cow::Borrowed(attribute),
+        })
+    }
+}
+
+/// the existing attributes a configuration deletes ("a=-m:", "a=-s:",
+/// "a=-ms:").
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteAttributes {
+    /// the media level attributes.
+    Media,
+    /// the session level attributes.
+    Session,
+    /// both.
+    Both,
+}
+
+impl fmt::Display for DeleteAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Media =>   "-m",
+            Self::Session => "-s",
+            Self::Both =>    "-ms",
+        })
+    }
+}
+
+/// one alternative of attribute capabilities in a configuration, e.g.
+/// "1,3,[4]".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttributeAlternative {
+    /// the capabilities that must be supported to use the configuration.
+    pub mandatory: Vec<u32>,
+    /// the capabilities that are added only if supported.
+    pub optional: Vec<u32>,
+}
+
+impl fmt::Display for AttributeAlternative {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_numbers(f, &self.mandatory, ",")?;
+        if !self.optional.is_empty() {
+            if !self.mandatory.is_empty() {
+                write!(f, ",")?;
+            }
+
+            write!(f, "[")?;
+            write_numbers(f, &self.optional, ",")?;
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for AttributeAlternative {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (mandatory, optional) = match value.split_once('[') {
+            Some((m, o)) => {
+                let o = o.strip_suffix(']').ok_or_else(|| anyhow!("invalid attribute config!"))?;
+                (m.strip_suffix(',').unwrap_or(m), parse_numbers(o)?)
+            },
+            None => (value, Vec::new()),
+        };
+
+        let mandatory = match mandatory {
+            "" => Vec::new(),
+            v => parse_numbers(v)?,
+        };
+
+        ensure!(!mandatory.is_empty() || !optional.is_empty(), "invalid attribute config!");
+        Ok(Self {
+            mandatory,
+            optional,
+        })
+    }
+}
+
+/// Name:  pcfg / acfg
+/// Value:  config-number [1*(SP config-list)]
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=pcfg:1 a=-m:1,[2]|3 t=1|2
+///
+/// A potential ("a=pcfg:") or actual ("a=acfg:") configuration.  The
+/// attribute alternatives and the transport alternatives are separated
+/// by "|", in order of preference.  An actual configuration lists the
+/// single alternative selected by the answerer.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "1 a=-m:1,[2]|3 t=1|2 x-ext=4";
+/// let config = Config::try_from(temp).unwrap();
+/// assert_eq!(config.number, 1);
+/// assert_eq!(config.delete, Some(DeleteAttributes::Media));
+/// assert_eq!(config.attributes.len(), 2);
+/// assert_eq!(config.attributes[0].mandatory, vec![1]);
+/// assert_eq!(config.attributes[0].optional, vec![2]);
+/// assert_eq!(config.attributes[1].mandatory, vec![3]);
+/// assert_eq!(config.transports, vec![1, 2]);
+/// assert_eq!(config.extensions, vec![("x-ext".into(), "4".into())]);
+/// assert_eq!(format!("{}", config), temp);
+///
+/// let config = Config::try_from("2 t=2 a=[1]").unwrap();
+/// assert_eq!(config.attributes[0].optional, vec![1]);
+/// assert_eq!(format!("{}", config), "2 a=[1] t=2");
+///
+/// assert!(Config::try_from("1 a=").is_err());
+/// assert!(Config::try_from("1 a=-x:1").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config<'a> {
+    /// the configuration number, lower numbers are preferred.
+    pub number: u32,
+    pub delete: Option<DeleteAttributes>,
+    /// the attribute capability alternatives, empty for none.
+    pub attributes: Vec<AttributeAlternative>,
+    /// the transport capability alternatives, empty for none.
+    pub transports: Vec<u32>,
+    /// extension configurations as name and value.
+    pub extensions: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> Config<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Config<'b> {
+        Config {
+            number: self.number,
+            delete: self.delete,
+            attributes: self.attributes,
+            transports: self.transports,
+            extensions: self.extensions
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(k.into_owned()), Cow::Owned(v.into_owned())))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Config<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number)?;
+        if !self.attributes.is_empty() {
+            write!(f, " a=")?;
+            if let Some(v) = self.delete {
+                write!(f, "{}:", v)?;
+            }
+
+            for (i, v) in self.attributes.iter().enumerate() {
+                write!(f, "{}{}", if i == 0 { "" } else { "|" }, v)?;
+            }
+        }
+
+        if !self.transports.is_empty() {
+            write!(f, " t=")?;
+            write_numbers(f, &self.transports, "|")?;
+        }
+
+        for (k, v) in &self.extensions {
+            write!(f, " {}={}", k, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Config<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "config");
+        let mut config = Self {
+            number: scanner.field()?.parse()?,
+            ..Default::default()
+        };
+
+        for v in scanner.fields() {
+            let (k, v) = v
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid config!"))?;
+            match k {
+                "a" => {
+                    let v = match v.split_once(':') {
+                        Some((delete, v)) => {
+                            config.delete = Some(match delete {
+                                "-m" => DeleteAttributes::Media,
+                                "-s" => DeleteAttributes::Session,
+                                "-ms" => DeleteAttributes::Both,
+                                _ => return Err(anyhow!("invalid config!"))
+                            });
+
+                            v
+                        },
+                        None => v,
+                    };
+
+                    config.attributes = v
+                        .split('|')
+                        .map(AttributeAlternative::try_from)
+                        .collect::<Result<_>>()?;
+                },
+                "t" => {
+                    config.transports = v
+                        .split('|')
+                        .map(|v| Ok(v.parse()?))
+                        .collect::<Result<_>>()?;
+                },
+                _ => config.extensions.push((Cow::Borrowed(k), Cow::Borrowed(v))),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// The capabilities declared in a session description.
+///
+/// Capability numbers are unique across the session, and capabilities
+/// can be declared at session and media level, so lookups search both.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=tcap:1 RTP/SAVPF RTP/SAVP\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     a=acap:1 ptime:20\r\n\
+///     a=pcfg:1 a=1 t=2\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let caps = Capabilities::new(&sdp.attributes, &sdp.medias[0].attributes);
+/// assert_eq!(caps.transport(2), Some("RTP/SAVP"));
+/// assert_eq!(caps.attribute(1).map(|v| v.attribute.as_ref()), Some("ptime:20"));
+/// assert_eq!(caps.potential().count(), 1);
+/// assert!(caps.attribute(2).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Capabilities<'b, 'a> {
+    session: &'b [Attribute<'a>],
+    media: &'b [Attribute<'a>],
+}
+
+impl<'b, 'a> Capabilities<'b, 'a> {
+    pub fn new(session: &'b [Attribute<'a>], media: &'b [Attribute<'a>]) -> Self {
+        Self {
+            session,
+            media,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'b Attributes<'a>> {
+        self.session.iter().chain(self.media).map(|a| &a.value)
+    }
+
+    /// the transport protocol with the given capability number.
+    pub fn transport(&self, number: u32) -> Option<&'b str> {
+        self.iter().find_map(|a| match a {
+            Attributes::Tcap(v) => v.get(number),
+            _ => None,
+        })
+    }
+
+    /// the attribute capability with the given number.
+    pub fn attribute(&self, number: u32) -> Option<&'b Acap<'a>> {
+        self.iter().find_map(|a| match a {
+            Attributes::Acap(v) if v.number == number => Some(v),
+            _ => None,
+        })
+    }
+
+    /// the potential configurations of the media description.
+    pub fn potential(&self) -> impl Iterator<Item = &'b Config<'a>> {
+        self.media.iter().filter_map(|a| match &a.value {
+            Attributes::Pcfg(v) => Some(v),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  cat
+/// Value:  cat-value
+/// Usage Level:  session
+/// Charset Dependent:  no
+///
+/// Syntax:
+/// cat-value = category
+/// category = non-ws-string
+///
+/// Example:
+/// a=cat:foo.bar
+///
+/// The dot-separated hierarchical category of the session, so that
+/// receivers can filter unwanted sessions by category.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let category = Category::try_from("sport.football.premier-league").unwrap();
+/// assert_eq!(category.parts().collect::<Vec<_>>(), vec!["sport", "football", "premier-league"]);
+/// assert!(category.starts_with("sport.football"));
+/// assert!(!category.starts_with("sport.foot"));
+/// assert_eq!(format!("{}", category), "sport.football.premier-league");
+///
+/// assert!(Category::try_from("").is_err());
+/// assert!(Category::try_from("sport football").is_err());
+/// assert!(Category::try_from("sport..football").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category<'a>(pub Cow<'a, str>);
+
+impl<'a> Category<'a> {
+    /// the levels of the hierarchy, from the most general one.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.')
+    }
+
+    /// whether the category is `prefix` or one of its subcategories.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut parts = self.parts();
+        prefix.split('.').all(|v| parts.next() == Some(v))
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Category<'b> {
+        Category(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl fmt::Display for Category<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Category<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        ensure!(!value.contains(|c: char| c.is_ascii_whitespace()), "invalid category!");
+        ensure!(value.split('.').all(|v| !v.is_empty()), "invalid category!");
+        Ok(Self(Cow::Borrowed(value)))
+    }
+}
@@ -0,0 +1,303 @@
+/// names and aliases of the
+/// [IANA character set registry](https://www.iana.org/assignments/character-sets).
+const CHARSETS: &[&str] = &[
+    // ASCII
+    "US-ASCII",
+    "ANSI_X3.4-1968",
+    "iso-ir-6",
+    "ANSI_X3.4-1986",
+    "ISO_646.irv:1991",
+    "ISO646-US",
+    "us",
+    "IBM367",
+    "cp367",
+    "csASCII",
+    // ISO 8859
+    "ISO-8859-1",
+    "ISO_8859-1:1987",
+    "ISO_8859-1",
+    "iso-ir-100",
+    "latin1",
+    "l1",
+    "IBM819",
+    "CP819",
+    "csISOLatin1",
+    "ISO-8859-2",
+    "ISO_8859-2:1987",
+    "ISO_8859-2",
+    "iso-ir-101",
+    "latin2",
+    "l2",
+    "csISOLatin2",
+    "ISO-8859-3",
+    "ISO_8859-3:1988",
+    "ISO_8859-3",
+    "iso-ir-109",
+    "latin3",
+    "l3",
+    "csISOLatin3",
+    "ISO-8859-4",
+    "ISO_8859-4:1988",
+    "ISO_8859-4",
+    "iso-ir-110",
+    "latin4",
+    "l4",
+    "csISOLatin4",
+    "ISO-8859-5",
+    "ISO_8859-5:1988",
+    "ISO_8859-5",
+    "iso-ir-144",
+    "cyrillic",
+    "csISOLatinCyrillic",
+    "ISO-8859-6",
+    "ISO_8859-6:1987",
+    "ISO_8859-6",
+    "iso-ir-127",
+    "ECMA-114",
+    "ASMO-708",
+    "arabic",
+    "csISOLatinArabic",
+    "ISO-8859-6-E",
+    "ISO_8859-6-E",
+    "csISO88596E",
+    "ISO-8859-6-I",
+    "ISO_8859-6-I",
+    "csISO88596I",
+    "ISO-8859-7",
+    "ISO_8859-7:1987",
+    "ISO_8859-7",
+    "iso-ir-126",
+    "ELOT_928",
+    "ECMA-118",
+    "greek",
+    "greek8",
+    "csISOLatinGreek",
+    "ISO-8859-8",
+    "ISO_8859-8:1988",
+    "ISO_8859-8",
+    "iso-ir-138",
+    "hebrew",
+    "csISOLatinHebrew",
+    "ISO-8859-8-E",
+    "ISO_8859-8-E",
+    "csISO88598E",
+    "ISO-8859-8-I",
+    "ISO_8859-8-I",
+    "csISO88598I",
+    "ISO-8859-9",
+    "ISO_8859-9:1989",
+    "ISO_8859-9",
+    "iso-ir-148",
+    "latin5",
+    "l5",
+    "csISOLatin5",
+    "ISO-8859-10",
+    "iso-ir-157",
+    "l6",
+    "ISO_8859-10:1992",
+    "csISOLatin6",
+    "latin6",
+    "TIS-620",
+    "csTIS620",
+    "ISO-8859-11",
+    "ISO-8859-13",
+    "csISO885913",
+    "ISO-8859-14",
+    "iso-ir-199",
+    "ISO_8859-14:1998",
+    "ISO_8859-14",
+    "latin8",
+    "iso-celtic",
+    "l8",
+    "csISO885914",
+    "ISO-8859-15",
+    "ISO_8859-15",
+    "Latin-9",
+    "csISO885915",
+    "ISO-8859-16",
+    "iso-ir-226",
+    "ISO_8859-16:2001",
+    "ISO_8859-16",
+    "latin10",
+    "l10",
+    "csISO885916",
+    // Unicode
+    "UTF-8",
+    "csUTF8",
+    "UTF-7",
+    "csUTF7",
+    "UTF-7-IMAP",
+    "csUTF7IMAP",
+    "UTF-16BE",
+    "csUTF16BE",
+    "UTF-16LE",
+    "csUTF16LE",
+    "UTF-16",
+    "csUTF16",
+    "UTF-32",
+    "csUTF32",
+    "UTF-32BE",
+    "csUTF32BE",
+    "UTF-32LE",
+    "csUTF32LE",
+    "ISO-10646-UCS-2",
+    "csUnicode",
+    "ISO-10646-UCS-4",
+    "csUCS4",
+    "ISO-10646-UCS-Basic",
+    "csUnicodeASCII",
+    "ISO-10646-Unicode-Latin1",
+    "csUnicodeLatin1",
+    "ISO-10646",
+    "SCSU",
+    "csSCSU",
+    "BOCU-1",
+    "csBOCU1",
+    "CESU-8",
+    "csCESU8",
+    "csCESU-8",
+    "GB18030",
+    "csGB18030",
+    // Japanese
+    "Shift_JIS",
+    "MS_Kanji",
+    "csShiftJIS",
+    "EUC-JP",
+    "Extended_UNIX_Code_Packed_Format_for_Japanese",
+    "csEUCPkdFmtJapanese",
+    "ISO-2022-JP",
+    "csISO2022JP",
+    "ISO-2022-JP-2",
+    "csISO2022JP2",
+    "Windows-31J",
+    "csWindows31J",
+    "JIS_Encoding",
+    "csJISEncoding",
+    // Chinese
+    "GB2312",
+    "csGB2312",
+    "GBK",
+    "CP936",
+    "MS936",
+    "windows-936",
+    "csGBK",
+    "Big5",
+    "csBig5",
+    "Big5-HKSCS",
+    "csBig5HKSCS",
+    "HZ-GB-2312",
+    "ISO-2022-CN",
+    "csISO2022CN",
+    "ISO-2022-CN-EXT",
+    "csISO2022CNEXT",
+    // Korean
+    "EUC-KR",
+    "csEUCKR",
+    "ISO-2022-KR",
+    "csISO2022KR",
+    "KS_C_5601-1987",
+    "iso-ir-149",
+    "KS_C_5601-1989",
+    "KSC_5601",
+    "korean",
+    "csKSC56011987",
+    // Cyrillic
+    "KOI8-R",
+    "csKOI8R",
+    "KOI8-U",
+    "csKOI8U",
+    // Windows
+    "windows-874",
+    "cswindows874",
+    "windows-1250",
+    "cswindows1250",
+    "windows-1251",
+    "cswindows1251",
+    "windows-1252",
+    "cswindows1252",
+    "windows-1253",
+    "cswindows1253",
+    "windows-1254",
+    "cswindows1254",
+    "windows-1255",
+    "cswindows1255",
+    "windows-1256",
+    "cswindows1256",
+    "windows-1257",
+    "cswindows1257",
+    "windows-1258",
+    "cswindows1258",
+    // IBM
+    "IBM437",
+    "cp437",
+    "437",
+    "csPC8CodePage437",
+    "IBM850",
+    "cp850",
+    "850",
+    "csPC850Multilingual",
+    "IBM852",
+    "cp852",
+    "852",
+    "csPCp852",
+    "IBM855",
+    "cp855",
+    "855",
+    "csIBM855",
+    "IBM857",
+    "cp857",
+    "857",
+    "csIBM857",
+    "IBM862",
+    "cp862",
+    "862",
+    "csPC862LatinHebrew",
+    "IBM866",
+    "cp866",
+    "866",
+    "csIBM866",
+    "IBM037",
+    "cp037",
+    "ebcdic-cp-us",
+    "ebcdic-cp-ca",
+    "ebcdic-cp-wt",
+    "ebcdic-cp-nl",
+    "csIBM037",
+    "IBM1047",
+    "IBM-1047",
+    "csIBM1047",
+    // Apple
+    "macintosh",
+    "mac",
+    "csMacintosh",
+    // Other
+    "VISCII",
+    "csVISCII",
+    "TSCII",
+    "csTSCII",
+];
+
+/// whether the value is a registered
+/// [IANA character set](https://www.iana.org/assignments/character-sets)
+/// name or alias, as required for "a=charset:"
+/// ([RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-6.10)).
+///
+/// Names are compared case-insensitively.  The compiled list covers the
+/// registered names of the ASCII, ISO 8859, Unicode, CJK, Cyrillic,
+/// Windows and common IBM character sets.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::is_charset;
+///
+/// assert!(is_charset("ISO-8859-1"));
+/// assert!(is_charset("utf-8"));
+/// assert!(is_charset("latin1"));
+/// assert!(is_charset("Shift_JIS"));
+/// assert!(!is_charset("UTF8"));
+/// assert!(!is_charset(""));
+/// ```
+pub fn is_charset(name: &str) -> bool {
+    CHARSETS.iter().any(|v| v.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,360 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// parse a "-" separated hex identifier, e.g. an EUI-64.
+fn parse_eui<const N: usize>(value: &str) -> Result<[u8; N]> {
+    let mut eui = [0u8; N];
+    let mut parts = value.split('-');
+    for b in eui.iter_mut() {
+        let part = parts.next().ok_or_else(|| anyhow!("invalid eui!"))?;
+        ensure!(part.len() == 2, "invalid eui!");
+        *b = u8::from_str_radix(part, 16)?;
+    }
+
+    ensure!(parts.next().is_none(), "invalid eui!");
+    Ok(eui)
+}
+
+fn write_eui(f: &mut fmt::Formatter<'_>, eui: &[u8]) -> fmt::Result {
+    for (i, b) in eui.iter().enumerate() {
+        if i > 0 {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{:02X}", b)?;
+    }
+
+    Ok(())
+}
+
+/// Name:  ts-refclk
+/// Value:  timestamp-refclk
+/// Usage Level:  session, media, source
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:0
+///
+/// The reference clock used to generate the media timestamps
+/// [RFC7273](https://datatracker.ietf.org/doc/html/rfc7273#section-4.8),
+/// e.g. the PTP grandmaster of an AES67 or SMPTE ST 2110 network.
+/// Clock sources this crate does not know are kept as `Other`.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefClock<'a> {
+    /// NTP server address, or "/traceable/".
+    Ntp(Cow<'a, str>),
+    /// Precision Time Protocol.
+    Ptp {
+        /// e.g. "IEEE1588-2008" or "IEEE802.1AS-2011".
+        version: Cow<'a, str>,
+        /// the grandmaster clock identity, `None` for "traceable".
+        gmid: Option<[u8; 8]>,
+        /// the PTP domain number.
+        domain: Option<u8>,
+    },
+    Gps,
+    Gal,
+    Glonass,
+    /// local clock, not locked to an external reference.
+    Local,
+    /// private clock, optionally traceable.
+    Private {
+        traceable: bool,
+    },
+    /// the local clock of the given MAC address.
+    LocalMac([u8; 6]),
+    /// the complete value, for clock sources this crate does not know.
+    Other(Cow<'a, str>),
+}
+
+impl<'a> RefClock<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> RefClock<'b> {
+        match self {
+            Self::Ntp(v) => RefClock::Ntp(Cow::Owned(v.into_owned())),
+            Self::Ptp { version, gmid, domain } => RefClock::Ptp {
+                version: Cow::Owned(version.into_owned()),
+                gmid,
+                domain,
+            },
+            Self::Gps => RefClock::Gps,
+            Self::Gal => RefClock::Gal,
+            Self::Glonass => RefClock::Glonass,
+            Self::Local => RefClock::Local,
+            Self::Private { traceable } => RefClock::Private { traceable },
+            Self::LocalMac(v) => RefClock::LocalMac(v),
+            Self::Other(v) => RefClock::Other(Cow::Owned(v.into_owned())),
+        }
+    }
+
+    fn parse_ptp(value: &'a str) -> Result<Self> {
+        let mut parts = value.split(':');
+        let version = parts.next().ok_or_else(|| anyhow!("invalid ptp!"))?;
+        let gmid = parts.next().ok_or_else(|| anyhow!("invalid ptp!"))?;
+        let domain = parts.next();
+        ensure!(!version.is_empty() && parts.next().is_none(), "invalid ptp!");
+
+        Ok(Self::Ptp {
+            version: Cow::Borrowed(version),
+            gmid: match gmid {
+                "traceable" => None,
+                _ => Some(parse_eui(gmid)?),
+            },
+            domain: domain.map(str::parse).transpose()?,
+        })
+    }
+}
+
+impl fmt::Display for RefClock<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// for temp in [
+    ///     "ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:0",
+    ///     "ptp=IEEE1588-2008:traceable",
+    ///     "ntp=203.0.113.10",
+    ///     "localmac=CA-FE-01-02-03-04",
+    ///     "private:traceable",
+    ///     "gps",
+    /// ] {
+    ///     assert_eq!(format!("{}", RefClock::try_from(temp).unwrap()), temp);
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ntp(v) => write!(f, "ntp={}", v),
+            Self::Ptp { version, gmid, domain } => {
+                write!(f, "ptp={}:", version)?;
+                match gmid {
+                    Some(v) => write_eui(f, v)?,
+                    None => write!(f, "traceable")?,
+                }
+
+                match domain {
+                    Some(v) => write!(f, ":{}", v),
+                    None => Ok(()),
+                }
+            },
+            Self::Gps => write!(f, "gps"),
+            Self::Gal => write!(f, "gal"),
+            Self::Glonass => write!(f, "glonass"),
+            Self::Local => write!(f, "local"),
+            Self::Private { traceable: false } => write!(f, "private"),
+            Self::Private { traceable: true } => write!(f, "private:traceable"),
+            Self::LocalMac(v) => {
+                write!(f, "localmac=")?;
+                write_eui(f, v)
+            },
+            Self::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RefClock<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let clock = RefClock::try_from("ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:0").unwrap();
+    /// assert_eq!(clock, RefClock::Ptp {
+    ///     version: "IEEE1588-2008".into(),
+    ///     gmid: Some([0x39, 0xA7, 0x94, 0xFF, 0xFE, 0x07, 0xCB, 0xD0]),
+    ///     domain: Some(0)
+    /// });
+    ///
+    /// assert_eq!(RefClock::try_from("local").unwrap(), RefClock::Local);
+    /// assert_eq!(RefClock::try_from("panda").unwrap(), RefClock::Other("panda".into()));
+    /// assert!(RefClock::try_from("ptp=IEEE1588-2008:39-A7").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value.split_once('=') {
+            Some(("ntp", v)) => Self::Ntp(Cow::Borrowed(v)),
+            Some(("ptp", v)) => Self::parse_ptp(v)?,
+            Some(("localmac", v)) => Self::LocalMac(parse_eui(v)?),
+            _ => match value {
+                "gps" => Self::Gps,
+                "gal" => Self::Gal,
+                "glonass" => Self::Glonass,
+                "local" => Self::Local,
+                "private" => Self::Private { traceable: false },
+                "private:traceable" => Self::Private { traceable: true },
+                _ => Self::Other(Cow::Borrowed(value)),
+            }
+        })
+    }
+}
+
+/// media clock source.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaClock<'a> {
+    /// the media clock is directly derived from the reference clock,
+    /// with the RTP timestamp `offset` at the reference clock epoch and
+    /// an optional `rate` ratio (e.g. 1000/1001).
+    Direct {
+        offset: Option<u64>,
+        rate: Option<(u32, u32)>,
+    },
+    /// the media clock is recovered from the RTP timestamps of the
+    /// sender.
+    Sender,
+    /// IEEE 1722 AVB stream identifier.
+    Ieee1722([u8; 8]),
+    /// the complete value, for media clocks this crate does not know.
+    Other(Cow<'a, str>),
+}
+
+/// Name:  mediaclk
+/// Value:  media-clock
+/// Usage Level:  session, media, source
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=mediaclk:direct=963214424 rate=1000/1001
+///
+/// The media clock source of the stream, and how it relates to the
+/// reference clock
+/// [RFC7273](https://datatracker.ietf.org/doc/html/rfc7273#section-5.8).
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaClk<'a> {
+    /// the media clock identifier ("id="), if given.
+    pub id: Option<Cow<'a, str>>,
+    pub clock: MediaClock<'a>,
+}
+
+impl<'a> MediaClk<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> MediaClk<'b> {
+        MediaClk {
+            id: self.id.map(|v| Cow::Owned(v.into_owned())),
+            clock: match self.clock {
+                MediaClock::Direct { offset, rate } => MediaClock::Direct { offset, rate },
+                MediaClock::Sender => MediaClock::Sender,
+                MediaClock::Ieee1722(v) => MediaClock::Ieee1722(v),
+                MediaClock::Other(v) => MediaClock::Other(Cow::Owned(v.into_owned())),
+            },
+        }
+    }
+}
+
+impl fmt::Display for MediaClk<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// for temp in [
+    ///     "direct=963214424 rate=1000/1001",
+    ///     "direct=0",
+    ///     "direct",
+    ///     "sender",
+    ///     "id=src1 IEEE1722=38-D6-6D-8E-D2-78-13-2A",
+    /// ] {
+    ///     assert_eq!(format!("{}", MediaClk::try_from(temp).unwrap()), temp);
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(id) = &self.id {
+            write!(f, "id={} ", id)?;
+        }
+
+        match &self.clock {
+            MediaClock::Direct { offset, rate } => {
+                write!(f, "direct")?;
+                if let Some(v) = offset {
+                    write!(f, "={}", v)?;
+                }
+
+                match rate {
+                    Some((n, d)) => write!(f, " rate={}/{}", n, d),
+                    None => Ok(()),
+                }
+            },
+            MediaClock::Sender => write!(f, "sender"),
+            MediaClock::Ieee1722(v) => {
+                write!(f, "IEEE1722=")?;
+                write_eui(f, v)
+            },
+            MediaClock::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MediaClk<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let clk = MediaClk::try_from("direct=963214424 rate=1000/1001").unwrap();
+    /// assert_eq!(clk.id, None);
+    /// assert_eq!(clk.clock, MediaClock::Direct {
+    ///     offset: Some(963214424),
+    ///     rate: Some((1000, 1001))
+    /// });
+    ///
+    /// assert!(MediaClk::try_from("direct=a").is_err());
+    /// assert!(MediaClk::try_from("direct rate=1000").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (id, value) = match value.strip_prefix("id=") {
+            Some(v) => {
+                let (id, v) = v
+                    .split_once(' ')
+                    .ok_or_else(|| anyhow!("invalid mediaclk!"))?;
+                (Some(Cow::Borrowed(id)), v)
+            },
+            None => (None, value),
+        };
+
+        let (clock, rate) = match value.split_once(' ') {
+            Some((clock, rate)) => (clock, Some(rate)),
+            None => (value, None),
+        };
+
+        let clock = match clock.split_once('=') {
+            _ if clock == "direct" || clock.starts_with("direct=") => MediaClock::Direct {
+                offset: clock.strip_prefix("direct=").map(str::parse).transpose()?,
+                rate: rate
+                    .map(|rate| -> Result<(u32, u32)> {
+                        let (n, d) = rate
+                            .strip_prefix("rate=")
+                            .and_then(|v| v.split_once('/'))
+                            .ok_or_else(|| anyhow!("invalid mediaclk rate!"))?;
+                        Ok((n.parse()?, d.parse()?))
+                    })
+                    .transpose()?,
+            },
+            Some(("IEEE1722", v)) if rate.is_none() => MediaClock::Ieee1722(parse_eui(v)?),
+            None if clock == "sender" && rate.is_none() => MediaClock::Sender,
+            _ => MediaClock::Other(Cow::Borrowed(value)),
+        };
+
+        Ok(Self { id, clock })
+    }
+}
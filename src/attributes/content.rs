@@ -0,0 +1,105 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  content
+/// Value:  mediacnt-tag *("," mediacnt-tag)
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=content:slides,speaker
+///
+/// The content of the media stream
+/// [RFC4796](https://datatracker.ietf.org/doc/html/rfc4796#section-5),
+/// so that a receiver can choose how to render it, e.g. presentation
+/// slides next to the main video of a telepresence room.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content<'a> {
+    /// presentation slides.
+    Slides,
+    /// the image of the speaker.
+    Speaker,
+    /// sign language.
+    Sl,
+    /// the main media stream.
+    Main,
+    /// an alternative to the main media stream.
+    Alt,
+    /// extension tags.
+    Other(Cow<'a, str>),
+}
+
+impl<'a> Content<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Content<'b> {
+        match self {
+            Self::Slides =>   Content::Slides,
+            Self::Speaker =>  Content::Speaker,
+            Self::Sl =>       Content::Sl,
+            Self::Main =>     Content::Main,
+            Self::Alt =>      Content::Alt,
+            Self::Other(v) => Content::Other(Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Content<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert_eq!(format!("{}", Content::Slides), "slides");
+    /// assert_eq!(format!("{}", Content::Sl), "sl");
+    /// assert_eq!(format!("{}", Content::Other("g.3gpp.floor".into())), "g.3gpp.floor");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Slides =>   "slides",
+            Self::Speaker =>  "speaker",
+            Self::Sl =>       "sl",
+            Self::Main =>     "main",
+            Self::Alt =>      "alt",
+            Self::Other(v) => v,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Content<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(Content::try_from("speaker").unwrap(), Content::Speaker);
+    /// assert_eq!(Content::try_from("main").unwrap(), Content::Main);
+    /// assert_eq!(Content::try_from("x-screen").unwrap(), Content::Other("x-screen".into()));
+    /// assert!(Content::try_from("").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "slides" =>  Self::Slides,
+            "speaker" => Self::Speaker,
+            "sl" =>      Self::Sl,
+            "main" =>    Self::Main,
+            "alt" =>     Self::Alt,
+            _ => {
+                ensure!(!value.is_empty() && !value.contains(' '), "invalid content!");
+                Self::Other(Cow::Borrowed(value))
+            }
+        })
+    }
+}
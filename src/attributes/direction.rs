@@ -0,0 +1,212 @@
+use super::{
+    Attribute,
+    Attributes
+};
+
+use crate::media::Media;
+use alloc::borrow::Cow;
+use crate::Sdp;
+use anyhow::{
+    Result,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Media direction.
+///
+/// The "a=sendrecv", "a=sendonly", "a=recvonly" and "a=inactive"
+/// attributes, from the perspective of the description that contains
+/// them.  Without any of them the media is "sendrecv".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    #[default]
+    SendRecv,
+    SendOnly,
+    RecvOnly,
+    Inactive,
+}
+
+impl Direction {
+    fn new(send: bool, recv: bool) -> Self {
+        match (send, recv) {
+            (true, true) =>   Self::SendRecv,
+            (true, false) =>  Self::SendOnly,
+            (false, true) =>  Self::RecvOnly,
+            (false, false) => Self::Inactive,
+        }
+    }
+
+    pub fn sends(&self) -> bool {
+        matches!(self, Self::SendRecv | Self::SendOnly)
+    }
+
+    pub fn receives(&self) -> bool {
+        matches!(self, Self::SendRecv | Self::RecvOnly)
+    }
+
+    /// the direction as seen by the peer.
+    pub fn reverse(&self) -> Self {
+        Self::new(self.receives(), self.sends())
+    }
+
+    /// the direction of an answer to an offered direction, given the
+    /// directions the answerer supports
+    /// [RFC3264](https://datatracker.ietf.org/doc/html/rfc3264#section-6.1).
+    ///
+    /// The answerer only sends if the offerer receives, and only
+    /// receives if the offerer sends.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// use Direction::*;
+    /// assert_eq!(Direction::answer_to(SendRecv, SendRecv), SendRecv);
+    /// assert_eq!(Direction::answer_to(SendRecv, RecvOnly), RecvOnly);
+    /// assert_eq!(Direction::answer_to(SendOnly, SendRecv), RecvOnly);
+    /// assert_eq!(Direction::answer_to(RecvOnly, SendRecv), SendOnly);
+    /// assert_eq!(Direction::answer_to(SendOnly, SendOnly), Inactive);
+    /// assert_eq!(Direction::answer_to(Inactive, SendRecv), Inactive);
+    /// ```
+    pub fn answer_to(offer: Self, local: Self) -> Self {
+        Self::new(
+            offer.receives() && local.sends(), 
+            offer.sends() && local.receives()
+        )
+    }
+
+    /// the direction given by an attribute, if it is a direction
+    /// attribute.
+    pub fn from_attribute(attribute: &Attributes<'_>) -> Option<Self> {
+        match attribute {
+            Attributes::Sendrecv(_) => Some(Self::SendRecv),
+            Attributes::Sendonly(_) => Some(Self::SendOnly),
+            Attributes::Recvonly(_) => Some(Self::RecvOnly),
+            Attributes::Inactive(_) => Some(Self::Inactive),
+            Attributes::Other(k, None) => Self::try_from(k.as_ref()).ok(),
+            _ => None,
+        }
+    }
+
+    /// the attribute name of the direction.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SendRecv => "sendrecv",
+            Self::SendOnly => "sendonly",
+            Self::RecvOnly => "recvonly",
+            Self::Inactive => "inactive",
+        }
+    }
+
+    fn find(attributes: &[Attribute<'_>]) -> Option<Self> {
+        attributes.iter().find_map(|a| Self::from_attribute(&a.value))
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Direction {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "sendrecv" => Ok(Self::SendRecv),
+            "sendonly" => Ok(Self::SendOnly),
+            "recvonly" => Ok(Self::RecvOnly),
+            "inactive" => Ok(Self::Inactive),
+            _ => Err(anyhow!("invalid direction!"))
+        }
+    }
+}
+
+impl<'a> Media<'a> {
+    /// the direction attribute of the media description.
+    pub fn direction(&self) -> Option<Direction> {
+        Direction::find(&self.attributes)
+    }
+
+    /// replaces the direction attributes of the media description with
+    /// the given direction, in place of the first one.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::media::*;
+    /// use std::convert::*;
+    ///
+    /// let mut media = Media::try_from("audio 9 RTP/AVP 0").unwrap();
+    /// media.set_direction(Direction::RecvOnly);
+    /// assert_eq!(media.direction(), Some(Direction::RecvOnly));
+    ///
+    /// media.attributes.insert(0, Attribute::try_from("mid:0").unwrap());
+    /// media.attributes.push(Attribute::try_from("sendonly").unwrap());
+    /// media.set_direction(Direction::Inactive);
+    /// assert_eq!(media.attributes.len(), 2);
+    /// assert_eq!(media.attributes[1].to_string(), "inactive");
+    /// ```
+    pub fn set_direction(&mut self, direction: Direction) {
+        let value = Attributes::Other(Cow::Borrowed(direction.name()), None);
+        match self.attributes.iter().position(|a| Direction::from_attribute(&a.value).is_some()) {
+            Some(index) => {
+                self.attributes[index] = value.into();
+                let mut i = index + 1;
+                while i < self.attributes.len() {
+                    match Direction::from_attribute(&self.attributes[i].value) {
+                        Some(_) => drop(self.attributes.remove(i)),
+                        None => i += 1,
+                    }
+                }
+            }
+            None => self.attributes.push(value.into()),
+        }
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// the effective direction of a media description.
+    ///
+    /// The media level direction attribute if present, else the session
+    /// level one, else "sendrecv".  `None` if there is no media with the
+    /// index.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=recvonly\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     a=inactive\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].direction(), None);
+    /// assert_eq!(sdp.direction_for(0), Some(Direction::RecvOnly));
+    /// assert_eq!(sdp.direction_for(1), Some(Direction::Inactive));
+    /// assert_eq!(sdp.direction_for(2), None);
+    ///
+    /// let sdp = Sdp::try_from("v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\n").unwrap();
+    /// assert_eq!(sdp.direction_for(0), Some(Direction::SendRecv));
+    /// ```
+    pub fn direction_for(&self, index: usize) -> Option<Direction> {
+        Some(self.medias.get(index)?
+            .direction()
+            .or_else(|| Direction::find(&self.attributes))
+            .unwrap_or_default())
+    }
+}
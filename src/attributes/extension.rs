@@ -1,5 +1,10 @@
-use crate::util::tuple2_from_split;
-use std::convert::TryFrom;
+use crate::scanner::Scanner;
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+use alloc::borrow::Cow;
 use anyhow::Result;
 
 /// attribute name (as it will appear in SDP): extmap
@@ -16,10 +21,54 @@ use anyhow::Result;
 /// attribute defines the mapping from the extension numbers used in
 /// packet headers into extension names as documented in
 /// specifications and appropriately registered.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExtMap<'a> {
     pub key: u8, 
-    pub value: &'a str,
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> ExtMap<'a> {
+    /// the header extension the identifier is mapped to.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let extmap = ExtMap::try_from("1 urn:ietf:params:rtp-hdrext:ssrc-audio-level").unwrap();
+    /// assert_eq!(extmap.uri(), KnownExtension::SsrcAudioLevel);
+    ///
+    /// let extmap = ExtMap::try_from("2 urn:3gpp:video-orientation").unwrap();
+    /// assert_eq!(extmap.uri(), KnownExtension::VideoOrientation);
+    /// ```
+    pub fn uri(&self) -> KnownExtension<'_> {
+        KnownExtension::from(self.value.as_ref())
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> ExtMap<'b> {
+        ExtMap {
+            key: self.key,
+            value: Cow::Owned(self.value.into_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ExtMap<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// let temp = "1 urn:ietf:params:rtp-hdrext:toffset";
+    /// assert_eq!(format!("{}", ExtMap::try_from(temp).unwrap()), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.key, self.value)
+    }
 }
 
 impl<'a> TryFrom<&'a str> for ExtMap<'a> {
@@ -36,10 +85,110 @@ impl<'a> TryFrom<&'a str> for ExtMap<'a> {
     /// assert!(ExtMap::try_from("4 name panda").is_err());
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (k, value) = tuple2_from_split(value, ' ', "invalid extmap!")?;
+        let mut scanner = Scanner::new(value, "extmap");
+        let k = scanner.field()?;
+        let value = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
             key: k.parse()?, 
-            value, 
+            value: Cow::Borrowed(value), 
         })
     }
 }
+
+/// RTP header extension URI of an "a=extmap:" attribute
+/// [RFC8285](https://datatracker.ietf.org/doc/html/rfc8285#section-8).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+///
+/// assert_eq!(KnownExtension::from("urn:ietf:params:rtp-hdrext:sdes:mid"), KnownExtension::SdesMid);
+/// assert_eq!(KnownExtension::from("urn:example:panda"), KnownExtension::Other("urn:example:panda"));
+/// assert_eq!(
+///     KnownExtension::TransportWideCc.to_string(),
+///     "http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01"
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownExtension<'a> {
+    /// the audio level of the source, "urn:ietf:params:rtp-hdrext:ssrc-audio-level"
+    /// [RFC6464](https://datatracker.ietf.org/doc/html/rfc6464#section-4).
+    SsrcAudioLevel,
+    /// the audio levels of the mixed sources, "urn:ietf:params:rtp-hdrext:csrc-audio-level"
+    /// [RFC6465](https://datatracker.ietf.org/doc/html/rfc6465#section-4).
+    CsrcAudioLevel,
+    /// transmission time offset, "urn:ietf:params:rtp-hdrext:toffset"
+    /// [RFC5450](https://datatracker.ietf.org/doc/html/rfc5450#section-5).
+    Toffset,
+    /// absolute send time, "http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time".
+    AbsSendTime,
+    /// transport-wide sequence number, "http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01".
+    TransportWideCc,
+    /// coordination of video orientation, "urn:3gpp:video-orientation".
+    VideoOrientation,
+    /// media identification, "urn:ietf:params:rtp-hdrext:sdes:mid"
+    /// [RFC8843](https://datatracker.ietf.org/doc/html/rfc8843#section-15.1).
+    SdesMid,
+    /// "urn:ietf:params:rtp-hdrext:sdes:rtp-stream-id"
+    /// [RFC8852](https://datatracker.ietf.org/doc/html/rfc8852#section-3.1).
+    RtpStreamId,
+    /// "urn:ietf:params:rtp-hdrext:sdes:repaired-rtp-stream-id"
+    /// [RFC8852](https://datatracker.ietf.org/doc/html/rfc8852#section-3.2).
+    RepairedRtpStreamId,
+    /// frame marking, "urn:ietf:params:rtp-hdrext:framemarking".
+    FrameMarking,
+    /// absolute capture time, "http://www.webrtc.org/experiments/rtp-hdrext/abs-capture-time".
+    AbsCaptureTime,
+    Other(&'a str),
+}
+
+/// former name of [`KnownExtension`].
+pub type HdrExtUri<'a> = KnownExtension<'a>;
+
+/// the URIs of the known header extensions.
+pub(crate) const KNOWN_EXTENSIONS: [(KnownExtension<'static>, &str); 11] = [
+    (KnownExtension::SsrcAudioLevel, "urn:ietf:params:rtp-hdrext:ssrc-audio-level"),
+    (KnownExtension::CsrcAudioLevel, "urn:ietf:params:rtp-hdrext:csrc-audio-level"),
+    (KnownExtension::Toffset, "urn:ietf:params:rtp-hdrext:toffset"),
+    (KnownExtension::AbsSendTime, "http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time"),
+    (KnownExtension::TransportWideCc, "http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01"),
+    (KnownExtension::VideoOrientation, "urn:3gpp:video-orientation"),
+    (KnownExtension::SdesMid, "urn:ietf:params:rtp-hdrext:sdes:mid"),
+    (KnownExtension::RtpStreamId, "urn:ietf:params:rtp-hdrext:sdes:rtp-stream-id"),
+    (KnownExtension::RepairedRtpStreamId, "urn:ietf:params:rtp-hdrext:sdes:repaired-rtp-stream-id"),
+    (KnownExtension::FrameMarking, "urn:ietf:params:rtp-hdrext:framemarking"),
+    (KnownExtension::AbsCaptureTime, "http://www.webrtc.org/experiments/rtp-hdrext/abs-capture-time"),
+];
+
+impl<'a> KnownExtension<'a> {
+    /// the URI of the header extension.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::Other(v) => v,
+            known => KNOWN_EXTENSIONS
+                .iter()
+                .find(|(v, _)| v == known)
+                .map(|(_, uri)| *uri)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KnownExtension<'a> {
+    fn from(value: &'a str) -> Self {
+        KNOWN_EXTENSIONS
+            .iter()
+            .find(|(_, uri)| *uri == value)
+            .map(|(v, _)| *v)
+            .unwrap_or(Self::Other(value))
+    }
+}
+
+impl fmt::Display for KnownExtension<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
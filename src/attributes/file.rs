@@ -0,0 +1,280 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// split on spaces that are not inside double quotes.
+fn split_quoted(value: &str) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    value
+        .split(move |c| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+
+            c == ' ' && !quoted
+        })
+        .filter(|v| !v.is_empty())
+}
+
+/// strip the double quotes around a value.
+fn unquote(value: &str) -> Result<&str> {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(|| anyhow!("invalid quoted string!"))
+}
+
+/// file hash, e.g. "sha-1:72:24:5F:...".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileHash<'a> {
+    /// e.g. "sha-1".
+    pub algorithm: Cow<'a, str>,
+    pub value: Vec<u8>,
+}
+
+impl<'a> FileHash<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> FileHash<'b> {
+        FileHash {
+            algorithm: Cow::Owned(self.algorithm.into_owned()),
+            value: self.value,
+        }
+    }
+}
+
+impl fmt::Display for FileHash<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.algorithm)?;
+        for b in &self.value {
+            write!(f, ":{:02X}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FileHash<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut values = value.split(':');
+        let algorithm = values.next().unwrap_or_default();
+        let value = values
+            .map(|v| {
+                ensure!(v.len() == 2, "invalid file hash!");
+                Ok(u8::from_str_radix(v, 16)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        ensure!(!algorithm.is_empty() && !value.is_empty(), "invalid file hash!");
+        Ok(Self {
+            algorithm: Cow::Borrowed(algorithm),
+            value,
+        })
+    }
+}
+
+/// Name:  file-selector
+/// Value:  selector-attr *(SP selector-attr)
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=file-selector:name:"My cool picture.jpg" type:image/jpeg size:32349
+///
+/// Selects the file to transfer
+/// [RFC5547](https://datatracker.ietf.org/doc/html/rfc5547#section-6),
+/// by any combination of its name, media type, size and hash.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "name:\"My cool picture.jpg\" type:image/jpeg size:32349 \
+///     hash:sha-1:72:24:5F:E8:65:3D:DA:F3:71:36:2F:86:D4:71:91:3E:E4:A2:CE:2E";
+/// let selector = FileSelector::try_from(temp).unwrap();
+/// assert_eq!(selector.name.as_deref(), Some("My cool picture.jpg"));
+/// assert_eq!(selector.kind.as_deref(), Some("image/jpeg"));
+/// assert_eq!(selector.size, Some(32349));
+///
+/// let hash = selector.hash.as_ref().unwrap();
+/// assert_eq!(hash.algorithm, "sha-1");
+/// assert_eq!(hash.value.len(), 20);
+/// assert_eq!(format!("{}", selector), temp);
+///
+/// let selector = FileSelector::try_from("type:text/html;charset=ISO-8859-1").unwrap();
+/// assert_eq!(selector.kind.as_deref(), Some("text/html;charset=ISO-8859-1"));
+/// assert_eq!(selector.name, None);
+///
+/// assert!(FileSelector::try_from("name:picture.jpg").is_err());
+/// assert!(FileSelector::try_from("size:big").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileSelector<'a> {
+    /// the file name, without the quotes.
+    pub name: Option<Cow<'a, str>>,
+    /// the media type, with its parameters.
+    pub kind: Option<Cow<'a, str>>,
+    /// the size in octets.
+    pub size: Option<u64>,
+    pub hash: Option<FileHash<'a>>,
+}
+
+impl<'a> FileSelector<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> FileSelector<'b> {
+        FileSelector {
+            name: self.name.map(|v| Cow::Owned(v.into_owned())),
+            kind: self.kind.map(|v| Cow::Owned(v.into_owned())),
+            size: self.size,
+            hash: self.hash.map(FileHash::into_owned),
+        }
+    }
+}
+
+impl fmt::Display for FileSelector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sep = "";
+        if let Some(v) = &self.name {
+            write!(f, "name:\"{}\"", v)?;
+            sep = " ";
+        }
+
+        if let Some(v) = &self.kind {
+            write!(f, "{}type:{}", sep, v)?;
+            sep = " ";
+        }
+
+        if let Some(v) = self.size {
+            write!(f, "{}size:{}", sep, v)?;
+            sep = " ";
+        }
+
+        if let Some(v) = &self.hash {
+            write!(f, "{}hash:{}", sep, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FileSelector<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut selector = Self::default();
+        for v in split_quoted(value) {
+            let (k, v) = v
+                .split_once(':')
+                .ok_or_else(|| anyhow!("invalid file selector!"))?;
+            match k {
+                "name" => selector.name = Some(Cow::Borrowed(unquote(v)?)),
+                "type" => selector.kind = Some(Cow::Borrowed(v)),
+                "size" => selector.size = Some(v.parse()?),
+                "hash" => selector.hash = Some(FileHash::try_from(v)?),
+                _ => return Err(anyhow!("invalid file selector!"))
+            }
+        }
+
+        Ok(selector)
+    }
+}
+
+/// Name:  file-date
+/// Value:  date-param *(SP date-param)
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=file-date:creation:"Mon, 15 May 2006 15:01:31 +0300"
+///
+/// The dates of the file
+/// [RFC5547](https://datatracker.ietf.org/doc/html/rfc5547#section-6),
+/// as RFC 5322 date-time strings.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "creation:\"Mon, 15 May 2006 15:01:31 +0300\" read:\"Tue, 16 May 2006 09:00:00 +0300\"";
+/// let date = FileDate::try_from(temp).unwrap();
+/// assert_eq!(date.creation.as_deref(), Some("Mon, 15 May 2006 15:01:31 +0300"));
+/// assert_eq!(date.modification, None);
+/// assert_eq!(date.read.as_deref(), Some("Tue, 16 May 2006 09:00:00 +0300"));
+/// assert_eq!(format!("{}", date), temp);
+///
+/// assert!(FileDate::try_from("creation:yesterday").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDate<'a> {
+    pub creation: Option<Cow<'a, str>>,
+    pub modification: Option<Cow<'a, str>>,
+    pub read: Option<Cow<'a, str>>,
+}
+
+impl<'a> FileDate<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> FileDate<'b> {
+        FileDate {
+            creation: self.creation.map(|v| Cow::Owned(v.into_owned())),
+            modification: self.modification.map(|v| Cow::Owned(v.into_owned())),
+            read: self.read.map(|v| Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for FileDate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sep = "";
+        for (k, v) in [
+            ("creation", &self.creation),
+            ("modification", &self.modification),
+            ("read", &self.read),
+        ] {
+            if let Some(v) = v {
+                write!(f, "{}{}:\"{}\"", sep, k, v)?;
+                sep = " ";
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FileDate<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut date = Self::default();
+        for v in split_quoted(value) {
+            let (k, v) = v
+                .split_once(':')
+                .ok_or_else(|| anyhow!("invalid file date!"))?;
+            let v = Some(Cow::Borrowed(unquote(v)?));
+            match k {
+                "creation" => date.creation = v,
+                "modification" => date.modification = v,
+                "read" => date.read = v,
+                _ => return Err(anyhow!("invalid file date!"))
+            }
+        }
+
+        Ok(date)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use smallvec::SmallVec;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// fingerprint hash function.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashFunction<'a> {
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Md5,
+    Md2,
+    Other(Cow<'a, str>),
+}
+
+impl<'a> HashFunction<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> HashFunction<'b> {
+        match self {
+            Self::Sha1 =>     HashFunction::Sha1,
+            Self::Sha224 =>   HashFunction::Sha224,
+            Self::Sha256 =>   HashFunction::Sha256,
+            Self::Sha384 =>   HashFunction::Sha384,
+            Self::Sha512 =>   HashFunction::Sha512,
+            Self::Md5 =>      HashFunction::Md5,
+            Self::Md2 =>      HashFunction::Md2,
+            Self::Other(v) => HashFunction::Other(Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for HashFunction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Sha1 =>     "sha-1",
+            Self::Sha224 =>   "sha-224",
+            Self::Sha256 =>   "sha-256",
+            Self::Sha384 =>   "sha-384",
+            Self::Sha512 =>   "sha-512",
+            Self::Md5 =>      "md5",
+            Self::Md2 =>      "md2",
+            Self::Other(v) => v,
+        })
+    }
+}
+
+impl<'a> From<&'a str> for HashFunction<'a> {
+    /// The names are compared case-insensitively.
+    fn from(value: &'a str) -> Self {
+        [
+            ("sha-1", Self::Sha1),
+            ("sha-224", Self::Sha224),
+            ("sha-256", Self::Sha256),
+            ("sha-384", Self::Sha384),
+            ("sha-512", Self::Sha512),
+            ("md5", Self::Md5),
+            ("md2", Self::Md2),
+        ]
+        .into_iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+        .map(|(_, hash)| hash)
+        .unwrap_or(Self::Other(Cow::Borrowed(value)))
+    }
+}
+
+/// Name:  fingerprint
+/// Value:  hash-func SP fingerprint
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=fingerprint:sha-256 8B:87:09:8A:5D:C2:F3:33:EF:C5:B1:F6:84:3A:3D:D6:
+/// A3:E2:9C:17:4C:E7:46:3B:1B:CE:84:98:DD:8E:AF:7B
+///
+/// The hash of the certificate of a DTLS or TLS endpoint
+/// [RFC8122](https://datatracker.ietf.org/doc/html/rfc8122#section-5),
+/// written as upper case hex bytes separated by colons.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "sha-256 8B:87:09:8A:5D:C2:F3:33:EF:C5:B1:F6:84:3A:3D:D6:A3:E2:9C:17:4C:E7:46:3B:1B:CE:84:98:DD:8E:AF:7B";
+/// let fingerprint = Fingerprint::try_from(temp).unwrap();
+/// assert_eq!(fingerprint.hash, HashFunction::Sha256);
+/// assert_eq!(fingerprint.value.len(), 32);
+/// assert_eq!(fingerprint.value[0], 0x8B);
+/// assert_eq!(format!("{}", fingerprint), temp);
+///
+/// let fingerprint = Fingerprint::try_from("SHA-1 4a:ad").unwrap();
+/// assert_eq!(fingerprint.hash, HashFunction::Sha1);
+/// assert_eq!(format!("{}", fingerprint), "sha-1 4A:AD");
+///
+/// assert!(Fingerprint::try_from("sha-256").is_err());
+/// assert!(Fingerprint::try_from("sha-256 8B:8").is_err());
+/// assert!(Fingerprint::try_from("sha-256 8B::87").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint<'a> {
+    pub hash: HashFunction<'a>,
+    /// the digest, at most 64 bytes (SHA-512).
+    pub value: SmallVec<[u8; 64]>,
+}
+
+impl<'a> Fingerprint<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Fingerprint<'b> {
+        Fingerprint {
+            hash: self.hash.into_owned(),
+            value: self.value,
+        }
+    }
+
+    /// the fingerprint of a DER encoded certificate.
+    ///
+    /// Fails for MD2 and unknown hash functions.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// let fingerprint = Fingerprint::from_der(b"abc", HashFunction::Sha256).unwrap();
+    /// assert_eq!(
+    ///     format!("{}", fingerprint),
+    ///     "sha-256 BA:78:16:BF:8F:01:CF:EA:41:41:40:DE:5D:AE:22:23:B0:03:61:A3:96:17:7A:9C:B4:10:FF:61:F2:00:15:AD"
+    /// );
+    ///
+    /// assert!(fingerprint.verify(b"abc"));
+    /// assert!(!fingerprint.verify(b"abd"));
+    ///
+    /// assert_eq!(Fingerprint::from_der(b"abc", HashFunction::Sha1).unwrap().value.len(), 20);
+    /// assert_eq!(Fingerprint::from_der(b"abc", HashFunction::Sha512).unwrap().value.len(), 64);
+    /// assert!(Fingerprint::from_der(b"abc", HashFunction::Md2).is_err());
+    /// ```
+    #[cfg(feature = "dtls")]
+    pub fn from_der(der: &[u8], hash: HashFunction<'a>) -> Result<Self> {
+        use anyhow::anyhow;
+        use sha2::Digest;
+
+        let value = match hash {
+            HashFunction::Sha1 =>   SmallVec::from_slice(&sha1::Sha1::digest(der)),
+            HashFunction::Sha224 => SmallVec::from_slice(&sha2::Sha224::digest(der)),
+            HashFunction::Sha256 => SmallVec::from_slice(&sha2::Sha256::digest(der)),
+            HashFunction::Sha384 => SmallVec::from_slice(&sha2::Sha384::digest(der)),
+            HashFunction::Sha512 => SmallVec::from_slice(&sha2::Sha512::digest(der)),
+            HashFunction::Md5 =>    SmallVec::from_slice(&md5::Md5::digest(der)),
+            _ => return Err(anyhow!("unsupported hash function {}!", hash)),
+        };
+
+        Ok(Self {
+            hash,
+            value,
+        })
+    }
+
+    /// whether the fingerprint matches a DER encoded certificate.
+    #[cfg(feature = "dtls")]
+    pub fn verify(&self, der: &[u8]) -> bool {
+        Self::from_der(der, self.hash.clone())
+            .map(|v| v.value == self.value)
+            .unwrap_or(false)
+    }
+}
+
+impl fmt::Display for Fingerprint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.hash)?;
+        for (i, byte) in self.value.iter().enumerate() {
+            if i > 0 {
+                write!(f, ":")?;
+            }
+
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Fingerprint<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "fingerprint");
+        let hash = scanner.field()?;
+        let fingerprint = scanner.field()?;
+        scanner.finish()?;
+
+        let mut value = SmallVec::new();
+        for byte in fingerprint.split(':') {
+            ensure!(byte.len() == 2 && value.len() < 64, "invalid fingerprint!");
+            value.push(u8::from_str_radix(byte, 16)?);
+        }
+
+        Ok(Self {
+            hash: HashFunction::from(hash),
+            value,
+        })
+    }
+}
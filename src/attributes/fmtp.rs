@@ -1,8 +1,13 @@
-use crate::util::tuple2_from_split;
-use std::collections::HashMap;
-use std::convert::TryFrom;
+use crate::scanner::Scanner;
+use core::convert::TryFrom;
+use core::fmt;
+use alloc::borrow::Cow;
+use smallvec::SmallVec;
 use anyhow::Result;
 
+/// format parameter name and optional value.
+pub type FmtpParam<'a> = (Cow<'a, str>, Option<Cow<'a, str>>);
+
 /// This attribute allows parameters that are specific to a
 /// particular format to be conveyed in a way that SDP does not
 /// have to understand them.  The format must be one of the formats
@@ -13,28 +18,150 @@ use anyhow::Result;
 /// 
 /// It is a media-level attribute, and it is not dependent on
 /// charset.
-#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Fmtp<'a> {
     pub key: u8,
-    pub values: HashMap<&'a str, Option<&'a str>>
+    /// format parameters in the order they appear in the attribute.
+    pub values: SmallVec<[FmtpParam<'a>; 4]>
+}
+
+impl<'a> Fmtp<'a> {
+    /// get format parameter by name.
+    ///
+    /// Whitespace around parameter names and values is ignored, as some
+    /// formats (e.g. SMPTE ST 2110) separate parameters with "; ".
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let fmtp: Fmtp = Fmtp::try_from("111 minptime=10;useinbandfec=1").unwrap();
+    /// assert_eq!(fmtp.get("minptime"), Some(Some("10")));
+    /// assert_eq!(fmtp.get("stereo"), None);
+    ///
+    /// let fmtp: Fmtp = Fmtp::try_from("112 width=1920; height=1080; interlace;").unwrap();
+    /// assert_eq!(fmtp.get("height"), Some(Some("1080")));
+    /// assert_eq!(fmtp.get("interlace"), Some(None));
+    /// ```
+    pub fn get(&self, name: &str) -> Option<Option<&str>> {
+        self.values
+            .iter()
+            .find(|(k, _)| k.trim() == name)
+            .map(|(_, v)| v.as_deref().map(str::trim))
+    }
+
+    /// set a format parameter, replacing the value of an existing
+    /// parameter with the same name, or appending it.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let mut fmtp: Fmtp = Fmtp::try_from("111 minptime=10;useinbandfec=1").unwrap();
+    /// fmtp.set("minptime", Some("20".into()));
+    /// fmtp.set("stereo", Some("1".into()));
+    /// assert_eq!(fmtp.to_string(), "111 minptime=20;useinbandfec=1;stereo=1");
+    /// ```
+    pub fn set(&mut self, name: &str, value: Option<Cow<'a, str>>) {
+        match self.values.iter_mut().find(|(k, _)| k.trim() == name) {
+            Some((_, v)) => *v = value,
+            None => self.values.push((Cow::Owned(name.into()), value)),
+        }
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Fmtp<'b> {
+        Fmtp {
+            key: self.key,
+            values: self.values
+                .into_iter()
+                .map(|(k, v)| (
+                    Cow::Owned(k.into_owned()), 
+                    v.map(|v| Cow::Owned(v.into_owned()))
+                ))
+                .collect()
+        }
+    }
+}
+
+impl fmt::Display for Fmtp<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "111 minptime=10;useinbandfec=1;x-flag";
+    /// assert_eq!(format!("{}", Fmtp::try_from(temp).unwrap()), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.key)?;
+        for (i, (k, v)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+
+            match v {
+                Some(v) => write!(f, "{}={}", k, v)?,
+                None => write!(f, "{}", k)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'u: 'a, 'a> arbitrary::Arbitrary<'u> for Fmtp<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'u>) -> arbitrary::Result<Self> {
+        let key = u.arbitrary()?;
+        let mut values = SmallVec::new();
+        for param in u.arbitrary_iter::<FmtpParam<'u>>()? {
+            values.push(param?);
+        }
+
+        Ok(Self { key, values })
+    }
 }
 
 impl<'a> TryFrom<&'a str> for Fmtp<'a> {
     type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let fmtp: Fmtp = Fmtp::try_from("102 level-asymmetry-allowed=1;packetization-mode=1").unwrap();
+    /// assert_eq!(fmtp.key, 102);
+    /// assert_eq!(fmtp.values.len(), 2);
+    /// assert_eq!(fmtp.values[0].0, "level-asymmetry-allowed");
+    /// assert_eq!(fmtp.values[0].1.as_deref(), Some("1"));
+    /// assert!(Fmtp::try_from("102").is_err());
+    ///
+    /// let fmtp: Fmtp = Fmtp::try_from("112 width=1920; height=1080").unwrap();
+    /// assert_eq!(fmtp.values[1].0, " height");
+    /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (code, value) = tuple2_from_split(value, ' ', "invalid fmtp!")?;
-        let mut values = HashMap::with_capacity(5);
-        let key: u8 = code.parse()?;
-
+        // parameters can contain spaces, e.g. "; " separated parameters.
+        let mut scanner = Scanner::new(value, "fmtp");
+        let code = scanner.field()?;
+        let value = scanner.rest()?;
+        let mut values = SmallVec::new();
         for value in value.split(';') {
-            let mut value_spt = value.split('=');
-            values.insert(value_spt.next().ok_or_else(|| {
+            let mut value_spt = value.splitn(2, '=');
+            values.push((Cow::Borrowed(value_spt.next().ok_or_else(|| {
                 anyhow::anyhow!("invalid fmtp!")
-            })?, value_spt.next());
+            })?), value_spt.next().map(Cow::Borrowed)));
         }
 
         Ok(Self {
-            key,
+            key: code.parse()?,
             values
         })
     }
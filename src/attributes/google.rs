@@ -0,0 +1,71 @@
+use super::Fmtp;
+use alloc::borrow::Cow;
+use alloc::format;
+
+/// the bitrate format parameters Chrome reads from the "a=fmtp:" of
+/// video codecs, in kbps, e.g.
+/// "a=fmtp:96 x-google-min-bitrate=300;x-google-max-bitrate=2500".
+///
+/// They are not standardized, but widely used to cap the bitrate of
+/// a WebRTC sender.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GoogleBitrates {
+    /// "x-google-min-bitrate".
+    pub min: Option<u32>,
+    /// "x-google-max-bitrate".
+    pub max: Option<u32>,
+    /// "x-google-start-bitrate".
+    pub start: Option<u32>,
+}
+
+impl<'a> Fmtp<'a> {
+    /// the Chrome bitrate parameters, unset parameters and values that
+    /// are not numbers are `None`.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let mut fmtp: Fmtp = Fmtp::try_from("96 x-google-min-bitrate=300;x-google-max-bitrate=2500").unwrap();
+    /// assert_eq!(fmtp.google_bitrates(), GoogleBitrates {
+    ///     min: Some(300),
+    ///     max: Some(2500),
+    ///     start: None,
+    /// });
+    ///
+    /// fmtp.set_google_bitrates(&GoogleBitrates {
+    ///     max: Some(1500),
+    ///     start: Some(800),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(fmtp.to_string(), "96 x-google-max-bitrate=1500;x-google-start-bitrate=800");
+    /// ```
+    pub fn google_bitrates(&self) -> GoogleBitrates {
+        let get = |name| self.get(name).flatten().and_then(|v| v.parse().ok());
+        GoogleBitrates {
+            min: get("x-google-min-bitrate"),
+            max: get("x-google-max-bitrate"),
+            start: get("x-google-start-bitrate"),
+        }
+    }
+
+    /// set the Chrome bitrate parameters, `None` removes a parameter.
+    pub fn set_google_bitrates(&mut self, bitrates: &GoogleBitrates) {
+        let params = [
+            ("x-google-min-bitrate", bitrates.min),
+            ("x-google-max-bitrate", bitrates.max),
+            ("x-google-start-bitrate", bitrates.start),
+        ];
+
+        for (name, value) in params {
+            match value {
+                Some(v) => self.set(name, Some(Cow::Owned(format!("{}", v)))),
+                None => self.values.retain(|(k, _)| k.trim() != name),
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::scanner::Scanner;
+use super::Mid;
+use alloc::borrow::Cow;
+use smallvec::SmallVec;
+use anyhow::Result;
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// grouping semantics.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Semantics<'a> {
+    /// the media share one transport
+    /// [RFC9143](https://datatracker.ietf.org/doc/html/rfc9143).
+    Bundle,
+    /// lip synchronization
+    /// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888#section-7).
+    Ls,
+    /// flow identification
+    /// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888#section-8).
+    Fid,
+    /// other semantics, e.g. "FEC" or "SRF".
+    Other(Cow<'a, str>),
+}
+
+impl<'a> Semantics<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Semantics<'b> {
+        match self {
+            Self::Bundle =>   Semantics::Bundle,
+            Self::Ls =>       Semantics::Ls,
+            Self::Fid =>      Semantics::Fid,
+            Self::Other(v) => Semantics::Other(Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Semantics<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Bundle =>   "BUNDLE",
+            Self::Ls =>       "LS",
+            Self::Fid =>      "FID",
+            Self::Other(v) => v,
+        })
+    }
+}
+
+impl<'a> From<&'a str> for Semantics<'a> {
+    fn from(value: &'a str) -> Self {
+        match value {
+            "BUNDLE" => Self::Bundle,
+            "LS" =>     Self::Ls,
+            "FID" =>    Self::Fid,
+            _ => Self::Other(Cow::Borrowed(value))
+        }
+    }
+}
+
+/// Name:  group
+/// Value:  semantics *(SP identification-tag)
+/// Usage Level:  session
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=group:BUNDLE 0 1 2
+///
+/// Groups the media identified by their "a=mid:"
+/// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888#section-5).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let group = Group::try_from("BUNDLE 0 1 video").unwrap();
+/// assert_eq!(group.semantics, Semantics::Bundle);
+/// assert_eq!(group.mids.as_slice(), &[Mid::Ref(0), Mid::Ref(1), Mid::Video]);
+/// assert_eq!(format!("{}", group), "BUNDLE 0 1 video");
+///
+/// let group = Group::try_from("BUNDLE").unwrap();
+/// assert!(group.mids.is_empty());
+///
+/// assert!(Group::try_from("").is_err());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group<'a> {
+    pub semantics: Semantics<'a>,
+    pub mids: SmallVec<[Mid<'a>; 4]>,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'u: 'a, 'a> arbitrary::Arbitrary<'u> for Group<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'u>) -> arbitrary::Result<Self> {
+        let semantics = u.arbitrary()?;
+        let mut mids = SmallVec::new();
+        for mid in u.arbitrary_iter::<Mid<'u>>()? {
+            mids.push(mid?);
+        }
+
+        Ok(Self { semantics, mids })
+    }
+}
+
+impl<'a> Group<'a> {
+    /// whether the group contains the media with the given mid.
+    pub fn contains(&self, mid: &Mid<'_>) -> bool {
+        self.mids.iter().any(|v| v == mid)
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Group<'b> {
+        Group {
+            semantics: self.semantics.into_owned(),
+            mids: self.mids
+                .into_iter()
+                .map(Mid::into_owned)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Group<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.semantics)?;
+        for mid in &self.mids {
+            write!(f, " {}", mid)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Group<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "group");
+        let semantics = scanner.field()?;
+
+        let mut mids = SmallVec::new();
+        for v in scanner.fields() {
+            mids.push(Mid::try_from(v)?);
+        }
+
+        Ok(Self {
+            semantics: Semantics::from(semantics),
+            mids,
+        })
+    }
+}
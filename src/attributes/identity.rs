@@ -0,0 +1,105 @@
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  identity
+/// Value:  identity-assertion *(SP identity-extension)
+/// Usage Level:  session
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=identity:eyJpZHAiOnsiZG9tYWluIjoiZXhhbXBsZS5vcmcifX0= x-ext=1
+///
+/// The identity assertion of the WebRTC endpoint
+/// [RFC8827](https://datatracker.ietf.org/doc/html/rfc8827#section-7.1),
+/// a base64 encoded JSON object created by the identity provider, which
+/// binds the user identity to the DTLS fingerprints.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "eyJpZHAiOnsiZG9tYWluIjoiZXhhbXBsZS5vcmcifX0= x-ext=1 x-flag";
+/// let identity = Identity::try_from(temp).unwrap();
+/// assert_eq!(identity.assertion, "eyJpZHAiOnsiZG9tYWluIjoiZXhhbXBsZS5vcmcifX0=");
+/// assert_eq!(identity.extensions, vec![
+///     ("x-ext".into(), Some("1".into())),
+///     ("x-flag".into(), None),
+/// ]);
+///
+/// assert_eq!(format!("{}", identity), temp);
+/// assert!(Identity::try_from("not-base64!").is_err());
+/// assert!(Identity::try_from("").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity<'a> {
+    /// the base64 encoded assertion.
+    pub assertion: Cow<'a, str>,
+    /// extension names and values.
+    pub extensions: Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+}
+
+impl<'a> Identity<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Identity<'b> {
+        Identity {
+            assertion: Cow::Owned(self.assertion.into_owned()),
+            extensions: self.extensions
+                .into_iter()
+                .map(|(k, v)| (
+                    Cow::Owned(k.into_owned()),
+                    v.map(|v| Cow::Owned(v.into_owned()))
+                ))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Identity<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.assertion)?;
+        for (k, v) in &self.extensions {
+            write!(f, " {}", k)?;
+            if let Some(v) = v {
+                write!(f, "={}", v)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Identity<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "identity");
+        let assertion = scanner.field()?;
+        ensure!(assertion.bytes().all(|c| {
+            c.is_ascii_alphanumeric() || matches!(c, b'+' | b'/' | b'=')
+        }), "invalid identity!");
+
+        Ok(Self {
+            assertion: Cow::Borrowed(assertion),
+            extensions: scanner
+                .fields()
+                .map(|v| match v.split_once('=') {
+                    Some((k, v)) => (Cow::Borrowed(k), Some(Cow::Borrowed(v))),
+                    None => (Cow::Borrowed(v), None),
+                })
+                .collect(),
+        })
+    }
+}
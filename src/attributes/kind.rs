@@ -3,7 +3,7 @@ use anyhow::{
     anyhow
 };
 
-use std::{
+use core::{
     convert::TryFrom,
     fmt
 };
@@ -14,25 +14,28 @@ use std::{
 /// appropriate:
 /// 
 /// *  When "a=type:broadcast" is specified, "a=recvonly" is probably
-/// appropriate for those connecting.
+///    appropriate for those connecting.
 /// 
 /// *  When "a=type:meeting" is specified, "a=sendrecv" is likely to be
-/// appropriate.
+///    appropriate.
 /// 
 /// *  "a=type:moderated" suggests the use of a floor control tool and
-/// that the media tools be started so as to mute new sites joining
-/// the multimedia conference.
+///    that the media tools be started so as to mute new sites joining
+///    the multimedia conference.
 /// 
 /// *  Specifying "a=type:H332" indicates that this loosely coupled
-/// session is part of an H.332 session as defined in the ITU H.332
-/// specification [ITU.H332.1998](https://datatracker.ietf.org/doc/
-/// html/rfc8866#ref-ITU.H332.1998). Media tools should be started 
-/// using "a=recvonly".
+///    session is part of an H.332 session as defined in the ITU H.332
+///    specification [ITU.H332.1998](https://datatracker.ietf.org/doc/
+///    html/rfc8866#ref-ITU.H332.1998). Media tools should be started 
+///    using "a=recvonly".
 /// 
 /// *  Specifying "a=type:test" is suggested as a hint that, unless
-/// explicitly requested otherwise, receivers can safely avoid
-/// displaying this session description to users.
-#[derive(Debug, PartialEq, Eq)]
+///    explicitly requested otherwise, receivers can safely avoid
+///    displaying this session description to users.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Kind {
     Broadcast,
     Meeting,
@@ -0,0 +1,146 @@
+/// tags of the "grandfathered" rule, registered before RFC 4646 and
+/// not matching the "langtag" rule.
+const GRANDFATHERED: [&str; 26] = [
+    "en-GB-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-BE-FR",
+    "sgn-BE-NL",
+    "sgn-CH-DE",
+    "art-lojban",
+    "cel-gaulish",
+    "no-bok",
+    "no-nyn",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+fn alpha(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn alphanum(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn digits(subtag: &str, len: usize) -> bool {
+    subtag.len() == len && subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// whether the value is a well-formed language tag of
+/// [RFC5646](https://datatracker.ietf.org/doc/html/rfc5646#section-2.1),
+/// as used by "a=sdplang:" and "a=lang:".
+///
+/// Only the syntax is checked, the subtags are not looked up in the
+/// language subtag registry.  Tags are case-insensitive.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::is_language_tag;
+///
+/// for tag in [
+///     "de",
+///     "en-US",
+///     "zh-Hant-TW",
+///     "zh-yue-HK",
+///     "es-419",
+///     "sl-rozaj-biske",
+///     "de-CH-1901",
+///     "en-a-bbb-x-a-ccc",
+///     "x-whatever",
+///     "i-klingon",
+///     "EN-gb-OED",
+/// ] {
+///     assert!(is_language_tag(tag), "{}", tag);
+/// }
+///
+/// for tag in [
+///     "",
+///     "e",
+///     "en_US",
+///     "en-",
+///     "de-419-DE",
+///     "a-DE",
+///     "ar-a-aaa-b-bbb-a",
+///     "en-x",
+///     "languages-US",
+/// ] {
+///     assert!(!is_language_tag(tag), "{}", tag);
+/// }
+/// ```
+pub fn is_language_tag(tag: &str) -> bool {
+    if GRANDFATHERED.iter().any(|v| v.eq_ignore_ascii_case(tag)) {
+        return true;
+    }
+
+    let mut subtags = tag.split('-').peekable();
+    let language = subtags.next().unwrap_or_default();
+    if language.eq_ignore_ascii_case("x") {
+        return is_private_use(subtags);
+    }
+
+    // language = 2*3ALPHA ["-" extlang] / 4ALPHA / 5*8ALPHA
+    if alpha(language, 2, 3) {
+        for _ in 0..3 {
+            if subtags.next_if(|v| alpha(v, 3, 3)).is_none() {
+                break;
+            }
+        }
+    } else if !alpha(language, 4, 8) {
+        return false;
+    }
+
+    // script = 4ALPHA, region = 2ALPHA / 3DIGIT
+    subtags.next_if(|v| alpha(v, 4, 4));
+    subtags.next_if(|v| alpha(v, 2, 2) || digits(v, 3));
+
+    // variant = 5*8alphanum / (DIGIT 3alphanum)
+    while subtags
+        .next_if(|v| {
+            alphanum(v, 5, 8) || (v.len() == 4 && v.as_bytes()[0].is_ascii_digit() && alphanum(v, 4, 4))
+        })
+        .is_some()
+    {}
+
+    // extension = singleton 1*("-" (2*8alphanum))
+    while subtags
+        .next_if(|v| alphanum(v, 1, 1) && !v.eq_ignore_ascii_case("x"))
+        .is_some()
+    {
+        if subtags.next_if(|v| alphanum(v, 2, 8)).is_none() {
+            return false;
+        }
+
+        while subtags.next_if(|v| alphanum(v, 2, 8)).is_some() {}
+    }
+
+    match subtags.next() {
+        None => true,
+        Some(v) if v.eq_ignore_ascii_case("x") => is_private_use(subtags),
+        Some(_) => false,
+    }
+}
+
+/// privateuse = "x" 1*("-" (1*8alphanum)), after the "x".
+fn is_private_use<'b>(mut subtags: impl Iterator<Item = &'b str>) -> bool {
+    let mut empty = true;
+    subtags.all(|v| {
+        empty = false;
+        alphanum(v, 1, 8)
+    }) && !empty
+}
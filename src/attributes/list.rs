@@ -0,0 +1,96 @@
+use super::Attribute;
+use alloc::vec::Vec;
+
+/// In place edits of an attribute list by attribute name, see
+/// [`super::Attributes::name`].
+///
+/// The other attributes keep their order, so munging code can change a
+/// single attribute without rebuilding the media description.
+/// `remove_named` is not called `remove`, which would be shadowed by
+/// [`Vec::remove`].
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+///     a=setup:actpass\r\n\
+///     a=mid:0\r\n\
+///     a=rtpmap:96 VP8/90000\r\n\
+///     a=rtpmap:97 rtx/90000\r\n\
+///     a=fmtp:97 apt=96\r\n\
+///     a=extmap-allow-mixed\r\n";
+///
+/// let mut sdp = Sdp::try_from(temp).unwrap();
+/// let attributes = &mut sdp.medias[0].attributes;
+///
+/// let old = attributes.replace("setup", Attributes::Setup(Setup::Active)).unwrap();
+/// assert_eq!(old.value, Attributes::Setup(Setup::Actpass));
+///
+/// assert_eq!(attributes.remove_named("extmap-allow-mixed").len(), 1);
+/// assert!(attributes.remove_named("extmap-allow-mixed").is_empty());
+///
+/// let rtcp_fb = Attributes::try_from("rtcp-fb:96 nack").unwrap();
+/// assert_eq!(attributes.insert_after("rtpmap", rtcp_fb), 4);
+///
+/// assert_eq!(sdp.to_string(), "v=0\r\n\
+///     s=-\r\n\
+///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+///     a=setup:active\r\n\
+///     a=mid:0\r\n\
+///     a=rtpmap:96 VP8/90000\r\n\
+///     a=rtpmap:97 rtx/90000\r\n\
+///     a=rtcp-fb:96 nack\r\n\
+///     a=fmtp:97 apt=96\r\n");
+/// ```
+pub trait AttributeList<'a> {
+    /// remove every attribute with the given name, and return them in
+    /// their previous order.
+    fn remove_named(&mut self, name: &str) -> Vec<Attribute<'a>>;
+    /// replace the first attribute with the given name, and return it.
+    /// The attribute is added at the end of the list if there is no
+    /// attribute with the name.
+    fn replace(&mut self, name: &str, value: impl Into<Attribute<'a>>) -> Option<Attribute<'a>>;
+    /// insert an attribute after the last attribute with the given name,
+    /// or at the end of the list, and return its index.
+    fn insert_after(&mut self, name: &str, value: impl Into<Attribute<'a>>) -> usize;
+}
+
+impl<'a> AttributeList<'a> for Vec<Attribute<'a>> {
+    fn remove_named(&mut self, name: &str) -> Vec<Attribute<'a>> {
+        let mut removed = Vec::new();
+        let mut kept = Vec::with_capacity(self.len());
+        for attribute in self.drain(..) {
+            match attribute.value.name() == name {
+                true => removed.push(attribute),
+                false => kept.push(attribute),
+            }
+        }
+
+        *self = kept;
+        removed
+    }
+
+    fn replace(&mut self, name: &str, value: impl Into<Attribute<'a>>) -> Option<Attribute<'a>> {
+        match self.iter_mut().find(|a| a.value.name() == name) {
+            Some(attribute) => Some(core::mem::replace(attribute, value.into())),
+            None => {
+                self.push(value.into());
+                None
+            }
+        }
+    }
+
+    fn insert_after(&mut self, name: &str, value: impl Into<Attribute<'a>>) -> usize {
+        let index = self.iter()
+            .rposition(|a| a.value.name() == name)
+            .map_or(self.len(), |i| i + 1);
+        self.insert(index, value.into());
+        index
+    }
+}
@@ -1,5 +1,12 @@
-use anyhow::Result;
-use std::{
+use crate::Sdp;
+use alloc::borrow::Cow;
+use alloc::format;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
     convert::TryFrom,
     fmt
 };
@@ -13,14 +20,100 @@ use std::{
 /// The media-identifier-attribute has the following 
 /// format in ABNF notation, as described in 
 /// [RFC5234](https://www.rfc-editor.org/rfc/rfc5234.txt)
-#[derive(Debug, PartialEq, Eq)]
-pub enum Mid {
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mid<'a> {
     Audio,
     Video,
-    Ref(u8)
+    Ref(u8),
+    /// any other identification tag, e.g. "sdparta_0".
+    Other(Cow<'a, str>),
+}
+
+impl<'a> Mid<'a> {
+    /// the recommended maximum length of a mid in bytes, so that it fits
+    /// the "sdes:mid" RTP header extension of
+    /// [RFC9143](https://datatracker.ietf.org/doc/html/rfc9143).
+    pub const MAX_LEN: usize = 16;
+
+    /// whether the mid is a valid token, as required by
+    /// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888#section-4).
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert!(Mid::Ref(0).is_token());
+    /// assert!(Mid::Other("sdparta_0".into()).is_token());
+    /// assert!(!Mid::Other("a/b".into()).is_token());
+    /// ```
+    pub fn is_token(&self) -> bool {
+        match self {
+            Self::Other(v) => !v.is_empty() && v.bytes().all(|c| {
+                c.is_ascii_alphanumeric() || b"-.!%*_+`'~".contains(&c)
+            }),
+            _ => true,
+        }
+    }
+
+    /// the length of the mid in bytes.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Audio =>    5,
+            Self::Video =>    5,
+            Self::Ref(n) =>   n.checked_ilog10().unwrap_or(0) as usize + 1,
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    /// check the mid against the token grammar of
+    /// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888#section-4)
+    /// and the length recommendation of [`Mid::MAX_LEN`].
+    ///
+    /// The parser only does this in strict mode, lenient parsing
+    /// accepts any mid without whitespace.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::options::*;
+    /// use sdp::Sdp;
+    ///
+    /// assert!(Mid::Ref(100).check().is_ok());
+    /// assert!(Mid::Other("sdparta_0".into()).check().is_ok());
+    ///
+    /// let err = Mid::Other("a/b".into()).check().unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid mid a/b: not a token!");
+    ///
+    /// let err = Mid::Other("0123456789abcdefg".into()).check().unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid mid 0123456789abcdefg: longer than 16 bytes!");
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=mid:a/b\r\n";
+    /// assert!(Sdp::parse(temp, &ParseOptions::default()).is_ok());
+    /// let err = Sdp::parse(temp, &ParseOptions::strict()).unwrap_err();
+    /// assert_eq!(format!("{:#}", err), "line 4: invalid mid a/b: not a token!");
+    /// ```
+    pub fn check(&self) -> Result<()> {
+        ensure!(self.is_token(), "invalid mid {}: not a token!", self);
+        ensure!(self.len() <= Self::MAX_LEN, "invalid mid {}: longer than {} bytes!", self, Self::MAX_LEN);
+        Ok(())
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Mid<'b> {
+        match self {
+            Self::Audio =>    Mid::Audio,
+            Self::Video =>    Mid::Video,
+            Self::Ref(n) =>   Mid::Ref(n),
+            Self::Other(v) => Mid::Other(Cow::Owned(v.into_owned())),
+        }
+    }
 }
 
-impl fmt::Display for Mid {
+impl fmt::Display for Mid<'_> {
     /// # Unit Test
     ///
     /// ```
@@ -29,17 +122,19 @@ impl fmt::Display for Mid {
     /// assert_eq!(format!("{}", Mid::Video), "video");
     /// assert_eq!(format!("{}", Mid::Audio), "audio");
     /// assert_eq!(format!("{}", Mid::Ref(8)), "8");
+    /// assert_eq!(format!("{}", Mid::Other("sdparta_0".into())), "sdparta_0");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", match self {
-            Self::Audio =>   "audio".to_string(),
-            Self::Video =>   "video".to_string(),
-            Self::Ref(n) =>  format!("{}", n)
-        })
+        match self {
+            Self::Audio =>    write!(f, "audio"),
+            Self::Video =>    write!(f, "video"),
+            Self::Ref(n) =>   write!(f, "{}", n),
+            Self::Other(v) => write!(f, "{}", v),
+        }
     }
 }
 
-impl<'a> TryFrom<&'a str> for Mid {
+impl<'a> TryFrom<&'a str> for Mid<'a> {
     type Error = anyhow::Error;
     /// # Unit Test
     ///
@@ -50,13 +145,104 @@ impl<'a> TryFrom<&'a str> for Mid {
     /// assert_eq!(Mid::try_from("video").unwrap(), Mid::Video);
     /// assert_eq!(Mid::try_from("audio").unwrap(), Mid::Audio);
     /// assert_eq!(Mid::try_from("8").unwrap(), Mid::Ref(8));
-    /// assert!(Mid::try_from("a").is_err());
+    /// assert_eq!(Mid::try_from("a").unwrap(), Mid::Other("a".into()));
+    /// assert_eq!(Mid::try_from("08").unwrap(), Mid::Other("08".into()));
+    /// assert_eq!(Mid::try_from("+8").unwrap(), Mid::Other("+8".into()));
+    /// assert_eq!(Mid::try_from("").unwrap_err().to_string(), "invalid mid: empty value!");
+    /// assert_eq!(Mid::try_from("a b").unwrap_err().to_string(), "invalid mid \"a b\": whitespace is not allowed!");
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        match value {
-            "video" =>  Ok(Self::Video),
-            "audio" =>  Ok(Self::Audio),
-            _ =>        Ok(Self::Ref(value.parse()?)),
+        Ok(match value {
+            "video" =>  Self::Video,
+            "audio" =>  Self::Audio,
+            // only the canonical form is a reference, so that "08" or
+            // "+8" is written back unchanged.
+            _ => match value.parse() {
+                Ok(n) if value.bytes().all(|c| c.is_ascii_digit())
+                    && (!value.starts_with('0') || value == "0") => Self::Ref(n),
+                _ => {
+                    ensure!(!value.is_empty(), "invalid mid: empty value!");
+                    ensure!(!value.contains(char::is_whitespace), "invalid mid {:?}: whitespace is not allowed!", value);
+                    Self::Other(Cow::Borrowed(value))
+                }
+            }
+        })
+    }
+}
+
+/// Mid generator for building offers.
+///
+/// Generates "0", "1", ... or, with a prefix, e.g. "m0", "m1", ...
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let mids = MidGenerator::new().take(3).collect::<Vec<_>>();
+/// assert_eq!(mids, vec![Mid::Ref(0), Mid::Ref(1), Mid::Ref(2)]);
+///
+/// let mut mids = MidGenerator::with_prefix("sdparta_");
+/// assert_eq!(mids.next(), Some(Mid::Other("sdparta_0".into())));
+///
+/// // continue after the mids of an existing description.
+/// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=mid:4\r\nm=video 9 RTP/AVP 96\r\na=mid:video\r\n";
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// assert_eq!(MidGenerator::new().resume(&sdp).next(), Some(Mid::Ref(5)));
+///
+/// let mut mids = MidGenerator::new();
+/// mids.nth(255);
+/// assert_eq!(mids.next(), Some(Mid::Other("256".into())));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MidGenerator {
+    prefix: Cow<'static, str>,
+    next: u32,
+}
+
+impl MidGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_prefix(prefix: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            next: 0,
         }
     }
+
+    /// skip the mids of this scheme used by the description.
+    pub fn resume(mut self, sdp: &Sdp<'_>) -> Self {
+        for mid in sdp.medias.iter().filter_map(|m| m.mid()) {
+            let n = match mid {
+                Mid::Ref(n) if self.prefix.is_empty() => Some(u32::from(*n)),
+                Mid::Other(v) => v
+                    .strip_prefix(self.prefix.as_ref())
+                    .filter(|v| !v.is_empty() && v.bytes().all(|c| c.is_ascii_digit()))
+                    .and_then(|v| v.parse().ok()),
+                _ => None,
+            };
+
+            if let Some(n) = n {
+                self.next = self.next.max(n.saturating_add(1));
+            }
+        }
+
+        self
+    }
+}
+
+impl Iterator for MidGenerator {
+    type Item = Mid<'static>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.next;
+        self.next = n.checked_add(1)?;
+        Some(match u8::try_from(n) {
+            Ok(n) if self.prefix.is_empty() => Mid::Ref(n),
+            _ => Mid::Other(Cow::Owned(format!("{}{}", self.prefix, n))),
+        })
+    }
 }
@@ -1,26 +1,123 @@
 mod rtp;
+#[cfg(feature = "broadcast")]
+mod clock;
+#[cfg(feature = "broadcast")]
+mod st2110;
+#[cfg(feature = "broadcast")]
+mod source_filter;
+mod rtcp_xr;
+mod zrtp;
+#[cfg(feature = "msrp")]
+mod msrp;
+#[cfg(feature = "msrp")]
+mod file;
+mod capneg;
+#[cfg(feature = "legacy")]
+mod simcap;
+mod content;
+mod tcp;
+mod tls_id;
+mod identity;
+mod group;
+mod usage;
+mod once;
+mod list;
+#[cfg(feature = "legacy")]
+mod category;
+mod tool;
+mod direction;
+#[cfg(feature = "rtsp")]
+mod rtsp;
 mod mid;
 mod fmtp;
 mod kind;
 mod ssrc;
 mod orient;
 mod extension;
+mod fingerprint;
+mod charset;
+#[cfg(feature = "webrtc")]
+mod google;
+#[cfg(feature = "rtsp")]
+mod pss;
+#[cfg(feature = "legacy")]
+mod silence;
+#[cfg(feature = "bcp47")]
+mod language;
 
 pub use orient::Orient;
 pub use extension::*;
 pub use kind::Kind;
-pub use mid::Mid;
+pub use mid::{
+    Mid,
+    MidGenerator
+};
 pub use ssrc::*;
 pub use fmtp::*;
 pub use rtp::*;
+#[cfg(feature = "rtsp")]
+pub use rtsp::*;
+#[cfg(feature = "broadcast")]
+pub use clock::*;
+#[cfg(feature = "broadcast")]
+pub use st2110::*;
+#[cfg(feature = "broadcast")]
+pub use source_filter::*;
+pub use rtcp_xr::*;
+pub use zrtp::ZrtpHash;
+#[cfg(feature = "msrp")]
+pub use msrp::MsrpUri;
+#[cfg(feature = "msrp")]
+pub use file::*;
+pub use capneg::*;
+#[cfg(feature = "legacy")]
+pub use simcap::*;
+pub use content::Content;
+pub use tcp::*;
+pub use tls_id::TlsId;
+pub use identity::Identity;
+pub use group::*;
+pub use usage::UsageLevel;
+pub use once::OnceKey;
+pub use list::AttributeList;
+#[cfg(feature = "legacy")]
+pub use category::Category;
+pub use tool::Tool;
+pub use direction::Direction;
+pub use charset::is_charset;
+#[cfg(feature = "webrtc")]
+pub use google::GoogleBitrates;
+#[cfg(feature = "rtsp")]
+pub use pss::Framesize;
+#[cfg(feature = "legacy")]
+pub use silence::*;
+#[cfg(feature = "bcp47")]
+pub use language::is_language_tag;
+pub use fingerprint::{
+    Fingerprint,
+    HashFunction
+};
 
-use std::convert::TryFrom;
+use crate::span::Span;
+use crate::util;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec::Vec;
+use itertools::Itertools;
+use core::{
+    convert::TryFrom,
+    str::FromStr,
+    fmt
+};
 use anyhow::{
     Result,
     anyhow
 };
 
-#[derive(Debug)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Attributes<'a> {
     /// ptime (Packet Time)
     /// 
@@ -153,7 +250,7 @@ pub enum Attributes<'a> {
     /// are valid according to the definition of the selected character set.
     /// Furthermore, charset-dependent fields MUST NOT contain the bytes 0x00
     /// (Nul), 0x0A (LF), and 0x0d (CR).
-    Charset(&'a str),
+    Charset(Cow<'a, str>),
     /// Name:  sdplang
     /// Value:  sdplang-value
     /// Usage Level:  session, media
@@ -190,7 +287,7 @@ pub enum Attributes<'a> {
     /// distributed with sufficient scope to cross geographic boundaries, 
     /// where the language of recipients cannot be assumed, or where the 
     /// session is in a different language from the locally assumed norm.
-    SdpLang(&'a str),
+    SdpLang(Cow<'a, str>),
     /// Name:  lang
     /// Value:  lang-value
     /// Usage Level:  session, media
@@ -236,7 +333,7 @@ pub enum Attributes<'a> {
     /// indicate such intentions.  Without such semantics, it is assumed that
     /// for a negotiated session one of the declared languages will be
     /// selected and used.
-    Lang(&'a str),
+    Lang(Cow<'a, str>),
     /// Name:  framerate
     /// Value:  framerate-value
     /// Usage Level:  media
@@ -360,11 +457,917 @@ pub enum Attributes<'a> {
     /// sdp extmap attribute
     Extmap(ExtMap<'a>),
     /// sdp mid attribute
-    Mid(Mid),
+    Mid(Mid<'a>),
     /// sdp ssrc attribute
     Ssrc(Ssrc<'a>),
+    /// Name:  control
+    /// Value:  url
+    /// Usage Level:  session, media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=control:rtsp://example.com/movie/trackID=1
+    ///
+    /// The RTSP control URL of the aggregate session or of a single
+    /// track.  It can be absolute, relative to the base URL of the
+    /// session description, or "*" for the base URL itself.
+    #[cfg(feature = "rtsp")]
+    Control(Cow<'a, str>),
+    /// RTSP presentation range ("a=range:").
+    #[cfg(feature = "rtsp")]
+    Range(Range<'a>),
+    /// Name:  rtptime
+    /// Value:  integer
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=rtptime:3781123
+    ///
+    /// The RTP timestamp corresponding to the start of the range, as
+    /// sent by some RTSP servers in the description instead of the
+    /// RTP-Info header.
+    #[cfg(feature = "rtsp")]
+    RtpTime(u32),
+    /// reference clock ("a=ts-refclk:").
+    #[cfg(feature = "broadcast")]
+    TsRefClk(RefClock<'a>),
+    /// media clock ("a=mediaclk:").
+    #[cfg(feature = "broadcast")]
+    MediaClk(MediaClk<'a>),
+    /// source filter ("a=source-filter:").
+    #[cfg(feature = "broadcast")]
+    SourceFilter(SourceFilter<'a>),
+    /// RTCP extended reports ("a=rtcp-xr:").
+    RtcpXr(RtcpXr<'a>),
+    /// ZRTP Hello hash ("a=zrtp-hash:").
+    ZrtpHash(ZrtpHash<'a>),
+    /// Name:  path
+    /// Value:  msrp-uri *(SP msrp-uri)
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=path:msrp://atlanta.example.com:7654/jshA7weztas;tcp
+    ///
+    /// The MSRP URIs of the endpoint and of the relays in front of it
+    /// [RFC4975](https://datatracker.ietf.org/doc/html/rfc4975#section-8.2),
+    /// the last one is the endpoint itself.
+    #[cfg(feature = "msrp")]
+    Path(Vec<MsrpUri<'a>>),
+    /// Name:  accept-types
+    /// Value:  format-list
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=accept-types:message/cpim text/plain text/html
+    ///
+    /// The media types the MSRP endpoint accepts in SEND requests, "*"
+    /// for any type.
+    #[cfg(feature = "msrp")]
+    AcceptTypes(Vec<Cow<'a, str>>),
+    /// The media types accepted only inside a wrapper type such as
+    /// message/cpim ("a=accept-wrapped-types:").
+    #[cfg(feature = "msrp")]
+    AcceptWrappedTypes(Vec<Cow<'a, str>>),
+    /// Name:  max-size
+    /// Value:  1*DIGIT
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=max-size:131072
+    ///
+    /// The largest MSRP message in octets the endpoint accepts.
+    #[cfg(feature = "msrp")]
+    MaxSize(u64),
+    /// file selector of a file transfer ("a=file-selector:").
+    #[cfg(feature = "msrp")]
+    FileSelector(FileSelector<'a>),
+    /// Name:  file-transfer-id
+    /// Value:  token
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=file-transfer-id:vBnG916bdberum2fFEABR1FR3ExZMUrd
+    ///
+    /// Identifies the file transfer, a new identifier starts a new
+    /// transfer in a re-offer
+    /// [RFC5547](https://datatracker.ietf.org/doc/html/rfc5547#section-6).
+    #[cfg(feature = "msrp")]
+    FileTransferId(Cow<'a, str>),
+    /// Name:  file-disposition
+    /// Value:  token
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=file-disposition:attachment
+    ///
+    /// How the receiver should handle the file, e.g. "render" or
+    /// "attachment".
+    #[cfg(feature = "msrp")]
+    FileDisposition(Cow<'a, str>),
+    /// file dates of a file transfer ("a=file-date:").
+    #[cfg(feature = "msrp")]
+    FileDate(FileDate<'a>),
+    /// Name:  file-icon
+    /// Value:  uri
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=file-icon:cid:id2@alicepc.example.com
+    ///
+    /// A Content-ID URI of an icon representing the file.
+    #[cfg(feature = "msrp")]
+    FileIcon(Cow<'a, str>),
+    /// transport protocol capabilities ("a=tcap:").
+    Tcap(Tcap<'a>),
+    /// attribute capability ("a=acap:").
+    Acap(Acap<'a>),
+    /// potential configuration ("a=pcfg:").
+    Pcfg(Config<'a>),
+    /// actual configuration ("a=acfg:").
+    Acfg(Config<'a>),
+    /// Name:  sqn
+    /// Value:  0-255
+    /// Usage Level:  session
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=sqn: 0
+    ///
+    /// The sequence number of the simple capability set
+    /// [RFC3407](https://datatracker.ietf.org/doc/html/rfc3407#section-3),
+    /// incremented whenever the set changes.
+    #[cfg(feature = "legacy")]
+    Sqn(u8),
+    /// capability description ("a=cdsc:").
+    #[cfg(feature = "legacy")]
+    Cdsc(Cdsc<'a>),
+    /// capability parameter ("a=cpar:"), an SDP line such as
+    /// "a=fmtp:18 annexb=yes".
+    #[cfg(feature = "legacy")]
+    Cpar(Cow<'a, str>),
+    /// minimum of a numeric capability parameter ("a=cparmin:").
+    #[cfg(feature = "legacy")]
+    CparMin(Cow<'a, str>),
+    /// maximum of a numeric capability parameter ("a=cparmax:").
+    #[cfg(feature = "legacy")]
+    CparMax(Cow<'a, str>),
+    /// Name:  label
+    /// Value:  pointer
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=label:1
+    ///
+    /// An identifier of the media stream, unique within the session
+    /// description
+    /// [RFC4574](https://datatracker.ietf.org/doc/html/rfc4574#section-4),
+    /// that other documents such as conference policies refer to.
+    Label(Cow<'a, str>),
+    /// media stream content tags ("a=content:").
+    Content(Vec<Content<'a>>),
+    /// connection setup role ("a=setup:").
+    Setup(Setup),
+    /// TCP connection reuse ("a=connection:").
+    Connection(TcpConnection),
+    /// DTLS association identifier ("a=tls-id:").
+    TlsId(TlsId<'a>),
+    /// WebRTC identity assertion ("a=identity:").
+    Identity(Identity<'a>),
+    /// Name:  bundle-only
+    /// Value:  N/A
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=bundle-only
+    ///
+    /// The media is only accepted as part of a BUNDLE group
+    /// [RFC9143](https://datatracker.ietf.org/doc/html/rfc9143#section-6),
+    /// and uses the transport of the group.  The offerer sets its port
+    /// to 0, so that an endpoint without BUNDLE support rejects it.
+    BundleOnly,
+    /// Name:  rtcp-mux-only
+    /// Value:  N/A
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=rtcp-mux-only
+    ///
+    /// The media is only accepted with RTP and RTCP multiplexed on one
+    /// port
+    /// [RFC8858](https://datatracker.ietf.org/doc/html/rfc8858#section-3),
+    /// "a=rtcp-mux" must be present as well.
+    RtcpMuxOnly,
+    /// Name:  ice-mismatch
+    /// Value:  N/A
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=ice-mismatch
+    ///
+    /// The answerer found that the default destination of the offered
+    /// media does not match any of its candidates
+    /// [RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5.5),
+    /// see [`Sdp::ice_mismatch`](crate::Sdp::ice_mismatch).
+    IceMismatch,
+    /// Name:  ice-pacing
+    /// Value:  ice-pacing-att
+    /// Usage Level:  session
+    /// Charset Dependent:  no
+    ///
+    /// Syntax:
+    /// ice-pacing-att = "ice-pacing:" pacing-value
+    /// pacing-value = 1*10DIGIT
+    ///
+    /// Example:
+    /// a=ice-pacing:50
+    ///
+    /// The connectivity check pacing value in milliseconds the agent
+    /// wants to use
+    /// [RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5.7).
+    /// Agents that do not find the attribute use 50 ms.
+    IcePacing(u64),
+    /// Name:  x-google-flag
+    /// Value:  flag
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=x-google-flag:conference
+    ///
+    /// A Chrome specific media flag, "conference" enables the simulcast
+    /// screen sharing layers of conference mode.
+    #[cfg(feature = "webrtc")]
+    XGoogleFlag(Cow<'a, str>),
+    /// Name:  maxprate
+    /// Value:  maxprate-value
+    /// Usage Level:  session, media
+    /// Charset Dependent:  no
+    ///
+    /// Syntax:
+    /// maxprate-value = integer [ "." *int ]
+    ///
+    /// Example:
+    /// a=maxprate:12.5
+    ///
+    /// The maximum packet rate in packets per second
+    /// [RFC3890](https://datatracker.ietf.org/doc/html/rfc3890#section-6.3),
+    /// used with "b=TIAS:" to calculate the bandwidth including the
+    /// packet headers.
+    MaxPrate(f64),
+    /// the largest frame size of a video payload type ("a=framesize:").
+    #[cfg(feature = "rtsp")]
+    Framesize(Framesize),
+    /// Name:  3GPP-Adaptation-Support
+    /// Value:  report-frequency
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=3GPP-Adaptation-Support:2
+    ///
+    /// The client supports bit-rate adaptation of the 3GPP streaming
+    /// service, and sends a report every given number of RTCP reports.
+    #[cfg(feature = "rtsp")]
+    AdaptationSupport(u32),
+    /// Name:  X-predecbufsize
+    /// Value:  size
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=X-predecbufsize:51200
+    ///
+    /// The size in bytes of the 3GPP pre-decoder buffer.
+    #[cfg(feature = "rtsp")]
+    PreDecBufSize(u32),
+    /// Name:  X-initpredecbufperiod
+    /// Value:  period
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=X-initpredecbufperiod:90000
+    ///
+    /// The initial pre-decoder buffering period, in 90 kHz clock ticks.
+    #[cfg(feature = "rtsp")]
+    InitPreDecBufPeriod(u32),
+    /// Name:  X-initpostdecbufperiod
+    /// Value:  period
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=X-initpostdecbufperiod:90000
+    ///
+    /// The initial post-decoder buffering period, in 90 kHz clock ticks.
+    #[cfg(feature = "rtsp")]
+    InitPostDecBufPeriod(u32),
+    /// Name:  X-decbyterate
+    /// Value:  rate
+    /// Usage Level:  media
+    /// Charset Dependent:  no
+    ///
+    /// Example:
+    /// a=X-decbyterate:16000
+    ///
+    /// The peak decoding byte rate in bytes per second of the 3GPP
+    /// buffering model.
+    #[cfg(feature = "rtsp")]
+    DecByteRate(u32),
+    /// the silence suppression of a gateway ("a=silenceSupp:").
+    #[cfg(feature = "legacy")]
+    SilenceSupp(SilenceSupp),
+    /// media grouping ("a=group:").
+    Group(Group<'a>),
+    /// Name:  keywds
+    /// Value:  keywds-value
+    /// Usage Level:  session
+    /// Charset Dependent:  yes
+    ///
+    /// Syntax:
+    /// keywds-value = keywords
+    /// keywords = text
+    ///
+    /// Example:
+    /// a=keywds:SDP session description protocol
+    ///
+    /// Like the "a=cat:" attribute, helps receivers to identify wanted
+    /// sessions.  The keywords are interpreted in the charset given by
+    /// "a=charset:".
+    #[cfg(feature = "legacy")]
+    Keywds(Cow<'a, str>),
+    /// the session category ("a=cat:").
+    #[cfg(feature = "legacy")]
+    Cat(Category<'a>),
+    /// the tool that created the description ("a=tool:").
+    Tool(Tool<'a>),
+    /// the certificate fingerprint of a DTLS or TLS endpoint ("a=fingerprint:").
+    Fingerprint(Fingerprint<'a>),
     /// otner
-    Other(&'a str, Option<&'a str>),
+    Other(Cow<'a, str>, Option<Cow<'a, str>>),
+}
+
+/// Attribute line.
+///
+/// Pairs the typed attribute value with the original text it was parsed
+/// from, so callers can still see the exact input even for attributes
+/// that are typed by this crate, or that gain typed support in a future
+/// version.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone)]
+pub struct Attribute<'a> {
+    /// The attribute as it appeared in the input, without the "a="
+    /// prefix.  `None` for attributes that were not parsed from text.
+    /// This is not updated when the typed value is modified.  It is not
+    /// compared by `==`, so that parsed and constructed attributes with
+    /// the same value are equal.
+    pub raw: Option<Cow<'a, str>>,
+    /// The typed attribute value.
+    pub value: Attributes<'a>,
+    /// The position of the "a=" line in the input, set by
+    /// [`crate::Sdp::parse`].  It is not compared by `==`, so that
+    /// equal attributes are equal wherever they appear.
+    pub span: Option<Span>,
+}
+
+impl PartialEq for Attribute<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<'a> Attribute<'a> {
+    /// the text the attribute was parsed from, if the typed value has
+    /// not been modified since.
+    ///
+    /// The raw text is parsed again and written to compare it with the
+    /// typed value, so this is only cheap enough for pass-through uses,
+    /// see
+    /// [`crate::options::WriteOptions::raw`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let mut attr = Attribute::try_from("rtpmap:96  VP8/90000").unwrap();
+    /// assert_eq!(attr.original(), Some("rtpmap:96  VP8/90000"));
+    /// assert_eq!(attr.to_string(), "rtpmap:96 VP8/90000");
+    /// assert_eq!(attr, Attribute::try_from("rtpmap:96 VP8/90000").unwrap());
+    ///
+    /// if let Attributes::Rtpmap(v) = &mut attr.value {
+    ///     v.key = 97;
+    /// }
+    ///
+    /// assert_eq!(attr.original(), None);
+    /// assert_eq!(Attribute::from(Attributes::Ptime(20)).original(), None);
+    /// assert_eq!(Attribute::try_from("ptime:20").unwrap(), Attributes::Ptime(20).into());
+    /// ```
+    pub fn original(&self) -> Option<&str> {
+        // the values are compared as written, since the parsed value
+        // borrows from `self` and not from the input.
+        let raw = self.raw.as_deref()?;
+        Attributes::try_from(raw)
+            .is_ok_and(|v| format!("{}", v) == format!("{}", self.value))
+            .then_some(raw)
+    }
+
+    /// the position of the attribute in the input.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=ptime:20\r\n";
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let span = sdp.medias[0].attributes[0].span().unwrap();
+    /// assert_eq!(span.line, 4);
+    /// assert_eq!(&temp[span.range()], "a=ptime:20");
+    /// ```
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Attribute<'b> {
+        Attribute {
+            raw: self.raw.map(|r| Cow::Owned(r.into_owned())),
+            value: self.value.into_owned(),
+            span: self.span,
+        }
+    }
+}
+
+impl<'a> Attributes<'a> {
+    /// convert to an owned value that does not borrow the input.
+    #[rustfmt::skip]
+    pub fn into_owned<'b>(self) -> Attributes<'b> {
+        match self {
+            Self::Ptime(v) =>       Attributes::Ptime(v),
+            Self::MaxPtime(v) =>    Attributes::MaxPtime(v),
+            Self::Rtpmap(v) =>      Attributes::Rtpmap(v.into_owned()),
+            Self::Fmtp(v) =>        Attributes::Fmtp(v.into_owned()),
+            Self::Orient(v) =>      Attributes::Orient(v),
+            Self::Charset(v) =>     Attributes::Charset(Cow::Owned(v.into_owned())),
+            Self::SdpLang(v) =>     Attributes::SdpLang(Cow::Owned(v.into_owned())),
+            Self::Lang(v) =>        Attributes::Lang(Cow::Owned(v.into_owned())),
+            Self::Framerate(v) =>   Attributes::Framerate(v),
+            Self::Quality(v) =>     Attributes::Quality(v),
+            Self::Kind(v) =>        Attributes::Kind(v),
+            Self::Recvonly(v) =>    Attributes::Recvonly(v),
+            Self::Sendrecv(v) =>    Attributes::Sendrecv(v),
+            Self::Sendonly(v) =>    Attributes::Sendonly(v),
+            Self::Inactive(v) =>    Attributes::Inactive(v),
+            Self::Extmap(v) =>      Attributes::Extmap(v.into_owned()),
+            Self::Mid(v) =>         Attributes::Mid(v.into_owned()),
+            Self::Ssrc(v) =>        Attributes::Ssrc(v.into_owned()),
+            #[cfg(feature = "rtsp")]
+            Self::Control(v) =>     Attributes::Control(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "rtsp")]
+            Self::Range(v) =>       Attributes::Range(v.into_owned()),
+            #[cfg(feature = "rtsp")]
+            Self::RtpTime(v) =>     Attributes::RtpTime(v),
+            #[cfg(feature = "broadcast")]
+            Self::TsRefClk(v) =>    Attributes::TsRefClk(v.into_owned()),
+            #[cfg(feature = "broadcast")]
+            Self::MediaClk(v) =>    Attributes::MediaClk(v.into_owned()),
+            #[cfg(feature = "broadcast")]
+            Self::SourceFilter(v) => Attributes::SourceFilter(v.into_owned()),
+            Self::RtcpXr(v) =>      Attributes::RtcpXr(v.into_owned()),
+            Self::ZrtpHash(v) =>    Attributes::ZrtpHash(v.into_owned()),
+            #[cfg(feature = "msrp")]
+            Self::Path(v) =>        Attributes::Path(v.into_iter().map(MsrpUri::into_owned).collect()),
+            #[cfg(feature = "msrp")]
+            Self::AcceptTypes(v) => Attributes::AcceptTypes(v.into_iter().map(|v| Cow::Owned(v.into_owned())).collect()),
+            #[cfg(feature = "msrp")]
+            Self::AcceptWrappedTypes(v) => Attributes::AcceptWrappedTypes(
+                v.into_iter().map(|v| Cow::Owned(v.into_owned())).collect()
+            ),
+            #[cfg(feature = "msrp")]
+            Self::MaxSize(v) =>     Attributes::MaxSize(v),
+            #[cfg(feature = "msrp")]
+            Self::FileSelector(v) => Attributes::FileSelector(v.into_owned()),
+            #[cfg(feature = "msrp")]
+            Self::FileTransferId(v) => Attributes::FileTransferId(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "msrp")]
+            Self::FileDisposition(v) => Attributes::FileDisposition(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "msrp")]
+            Self::FileDate(v) =>    Attributes::FileDate(v.into_owned()),
+            #[cfg(feature = "msrp")]
+            Self::FileIcon(v) =>    Attributes::FileIcon(Cow::Owned(v.into_owned())),
+            Self::Tcap(v) =>        Attributes::Tcap(v.into_owned()),
+            Self::Acap(v) =>        Attributes::Acap(v.into_owned()),
+            Self::Pcfg(v) =>        Attributes::Pcfg(v.into_owned()),
+            Self::Acfg(v) =>        Attributes::Acfg(v.into_owned()),
+            #[cfg(feature = "legacy")]
+            Self::Sqn(v) =>         Attributes::Sqn(v),
+            #[cfg(feature = "legacy")]
+            Self::Cdsc(v) =>        Attributes::Cdsc(v.into_owned()),
+            #[cfg(feature = "legacy")]
+            Self::Cpar(v) =>        Attributes::Cpar(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "legacy")]
+            Self::CparMin(v) =>     Attributes::CparMin(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "legacy")]
+            Self::CparMax(v) =>     Attributes::CparMax(Cow::Owned(v.into_owned())),
+            Self::Label(v) =>       Attributes::Label(Cow::Owned(v.into_owned())),
+            Self::Content(v) =>     Attributes::Content(v.into_iter().map(Content::into_owned).collect()),
+            Self::Setup(v) =>       Attributes::Setup(v),
+            Self::Connection(v) =>  Attributes::Connection(v),
+            Self::TlsId(v) =>       Attributes::TlsId(v.into_owned()),
+            Self::Identity(v) =>    Attributes::Identity(v.into_owned()),
+            Self::BundleOnly =>     Attributes::BundleOnly,
+            Self::RtcpMuxOnly =>    Attributes::RtcpMuxOnly,
+            Self::IceMismatch =>    Attributes::IceMismatch,
+            Self::IcePacing(v) =>   Attributes::IcePacing(v),
+            #[cfg(feature = "webrtc")]
+            Self::XGoogleFlag(v) => Attributes::XGoogleFlag(Cow::Owned(v.into_owned())),
+            Self::MaxPrate(v) =>    Attributes::MaxPrate(v),
+            #[cfg(feature = "rtsp")]
+            Self::Framesize(v) =>   Attributes::Framesize(v),
+            #[cfg(feature = "rtsp")]
+            Self::AdaptationSupport(v) => Attributes::AdaptationSupport(v),
+            #[cfg(feature = "rtsp")]
+            Self::PreDecBufSize(v) => Attributes::PreDecBufSize(v),
+            #[cfg(feature = "rtsp")]
+            Self::InitPreDecBufPeriod(v) => Attributes::InitPreDecBufPeriod(v),
+            #[cfg(feature = "rtsp")]
+            Self::InitPostDecBufPeriod(v) => Attributes::InitPostDecBufPeriod(v),
+            #[cfg(feature = "rtsp")]
+            Self::DecByteRate(v) => Attributes::DecByteRate(v),
+            #[cfg(feature = "legacy")]
+            Self::SilenceSupp(v) => Attributes::SilenceSupp(v),
+            Self::Group(v) =>       Attributes::Group(v.into_owned()),
+            #[cfg(feature = "legacy")]
+            Self::Keywds(v) =>      Attributes::Keywds(Cow::Owned(v.into_owned())),
+            #[cfg(feature = "legacy")]
+            Self::Cat(v) =>         Attributes::Cat(v.into_owned()),
+            Self::Tool(v) =>        Attributes::Tool(v.into_owned()),
+            Self::Fingerprint(v) => Attributes::Fingerprint(v.into_owned()),
+            Self::Other(k, v) =>    Attributes::Other(
+                Cow::Owned(k.into_owned()), 
+                v.map(|v| Cow::Owned(v.into_owned()))
+            ),
+        }
+    }
+}
+
+impl Attributes<'_> {
+    /// the attribute name, as written before the ":" of the value.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(Attributes::try_from("rtpmap:111 opus/48000/2").unwrap().name(), "rtpmap");
+    /// assert_eq!(Attributes::try_from("sendonly").unwrap().name(), "sendonly");
+    /// assert_eq!(Attributes::try_from("x-panda:1").unwrap().name(), "x-panda");
+    /// ```
+    #[rustfmt::skip]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Ptime(_) =>                "ptime",
+            Self::MaxPtime(_) =>             "maxptime",
+            Self::Rtpmap(_) =>               "rtpmap",
+            Self::Fmtp(_) =>                 "fmtp",
+            Self::Orient(_) =>               "orient",
+            Self::Charset(_) =>              "charset",
+            Self::SdpLang(_) =>              "sdplang",
+            Self::Lang(_) =>                 "lang",
+            Self::Framerate(_) =>            "framerate",
+            Self::Quality(_) =>              "quality",
+            Self::Kind(_) =>                 "type",
+            Self::Recvonly(_) =>             "recvonly",
+            Self::Sendrecv(_) =>             "sendrecv",
+            Self::Sendonly(_) =>             "sendonly",
+            Self::Inactive(_) =>             "inactive",
+            Self::Extmap(_) =>               "extmap",
+            Self::Mid(_) =>                  "mid",
+            Self::Ssrc(_) =>                 "ssrc",
+            #[cfg(feature = "rtsp")]
+            Self::Control(_) =>              "control",
+            #[cfg(feature = "rtsp")]
+            Self::Range(_) =>                "range",
+            #[cfg(feature = "rtsp")]
+            Self::RtpTime(_) =>              "rtptime",
+            #[cfg(feature = "broadcast")]
+            Self::TsRefClk(_) =>             "ts-refclk",
+            #[cfg(feature = "broadcast")]
+            Self::MediaClk(_) =>             "mediaclk",
+            #[cfg(feature = "broadcast")]
+            Self::SourceFilter(_) =>         "source-filter",
+            Self::RtcpXr(_) =>               "rtcp-xr",
+            Self::ZrtpHash(_) =>             "zrtp-hash",
+            #[cfg(feature = "msrp")]
+            Self::Path(_) =>                 "path",
+            #[cfg(feature = "msrp")]
+            Self::AcceptTypes(_) =>          "accept-types",
+            #[cfg(feature = "msrp")]
+            Self::AcceptWrappedTypes(_) =>   "accept-wrapped-types",
+            #[cfg(feature = "msrp")]
+            Self::MaxSize(_) =>              "max-size",
+            #[cfg(feature = "msrp")]
+            Self::FileSelector(_) =>         "file-selector",
+            #[cfg(feature = "msrp")]
+            Self::FileTransferId(_) =>       "file-transfer-id",
+            #[cfg(feature = "msrp")]
+            Self::FileDisposition(_) =>      "file-disposition",
+            #[cfg(feature = "msrp")]
+            Self::FileDate(_) =>             "file-date",
+            #[cfg(feature = "msrp")]
+            Self::FileIcon(_) =>             "file-icon",
+            Self::Tcap(_) =>                 "tcap",
+            Self::Acap(_) =>                 "acap",
+            Self::Pcfg(_) =>                 "pcfg",
+            Self::Acfg(_) =>                 "acfg",
+            #[cfg(feature = "legacy")]
+            Self::Sqn(_) =>                  "sqn",
+            #[cfg(feature = "legacy")]
+            Self::Cdsc(_) =>                 "cdsc",
+            #[cfg(feature = "legacy")]
+            Self::Cpar(_) =>                 "cpar",
+            #[cfg(feature = "legacy")]
+            Self::CparMin(_) =>              "cparmin",
+            #[cfg(feature = "legacy")]
+            Self::CparMax(_) =>              "cparmax",
+            Self::Label(_) =>                "label",
+            Self::Content(_) =>              "content",
+            Self::Setup(_) =>                "setup",
+            Self::Connection(_) =>           "connection",
+            Self::TlsId(_) =>                "tls-id",
+            Self::Identity(_) =>             "identity",
+            Self::BundleOnly =>              "bundle-only",
+            Self::RtcpMuxOnly =>             "rtcp-mux-only",
+            Self::IceMismatch =>             "ice-mismatch",
+            Self::IcePacing(_) =>            "ice-pacing",
+            #[cfg(feature = "webrtc")]
+            Self::XGoogleFlag(_) =>          "x-google-flag",
+            Self::MaxPrate(_) =>             "maxprate",
+            #[cfg(feature = "rtsp")]
+            Self::Framesize(_) =>            "framesize",
+            #[cfg(feature = "rtsp")]
+            Self::AdaptationSupport(_) =>    "3GPP-Adaptation-Support",
+            #[cfg(feature = "rtsp")]
+            Self::PreDecBufSize(_) =>        "X-predecbufsize",
+            #[cfg(feature = "rtsp")]
+            Self::InitPreDecBufPeriod(_) =>  "X-initpredecbufperiod",
+            #[cfg(feature = "rtsp")]
+            Self::InitPostDecBufPeriod(_) => "X-initpostdecbufperiod",
+            #[cfg(feature = "rtsp")]
+            Self::DecByteRate(_) =>          "X-decbyterate",
+            #[cfg(feature = "legacy")]
+            Self::SilenceSupp(_) =>          "silenceSupp",
+            Self::Group(_) =>                "group",
+            #[cfg(feature = "legacy")]
+            Self::Keywds(_) =>               "keywds",
+            #[cfg(feature = "legacy")]
+            Self::Cat(_) =>                  "cat",
+            Self::Tool(_) =>                 "tool",
+            Self::Fingerprint(_) =>          "fingerprint",
+            Self::Other(k, _) =>             k,
+        }
+    }
+}
+
+impl fmt::Display for Attributes<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// for temp in [
+    ///     "ptime:20", 
+    ///     "rtpmap:111 opus/48000/2", 
+    ///     "fmtp:111 minptime=10;useinbandfec=1",
+    ///     "extmap:1 urn:ietf:params:rtp-hdrext:toffset",
+    ///     "ssrc:1175220440 cname:v1SBHP7c76XqYcWx",
+    ///     "type:meeting",
+    ///     "control:rtsp://example.com/movie/trackID=1",
+    ///     "range:npt=0-34.357",
+    ///     "rtptime:3781123",
+    ///     "ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:0",
+    ///     "mediaclk:direct=0",
+    ///     "source-filter: incl IN IP4 232.3.4.5 192.0.2.10",
+    ///     "rtcp-xr:rcvr-rtt=all:10 stat-summary=loss,dup,jitt",
+    ///     "zrtp-hash:1.10 fe30efd02423cb054e50efd0248742ac7a52c8f91bc2df881ae642c371ba46df",
+    ///     "path:msrp://relay.example.com:2855/asd;tcp msrp://atlanta.example.com:7654/jshA7weztas;tcp",
+    ///     "accept-types:message/cpim text/plain",
+    ///     "accept-wrapped-types:*",
+    ///     "max-size:131072",
+    ///     "file-selector:name:\"My cool picture.jpg\" type:image/jpeg size:32349",
+    ///     "file-transfer-id:vBnG916bdberum2fFEABR1FR3ExZMUrd",
+    ///     "file-disposition:attachment",
+    ///     "file-date:creation:\"Mon, 15 May 2006 15:01:31 +0300\"",
+    ///     "file-icon:cid:id2@alicepc.example.com",
+    ///     "tcap:1 RTP/SAVPF RTP/SAVP",
+    ///     "acap:1 ptime:20",
+    ///     "pcfg:1 a=-m:1,[2]|3 t=1|2",
+    ///     "acfg:1 a=1 t=1",
+    ///     "sqn: 0",
+    ///     "cdsc: 1 audio RTP/AVP 0 18",
+    ///     "cpar: a=fmtp:18 annexb=yes",
+    ///     "cparmax: b=AS:64",
+    ///     "label:1",
+    ///     "content:slides,speaker",
+    ///     "setup:actpass",
+    ///     "connection:existing",
+    ///     "tls-id:abc3de65cddef001be82",
+    ///     "identity:eyJpZHAiOnsiZG9tYWluIjoiZXhhbXBsZS5vcmcifX0=",
+    ///     "bundle-only",
+    ///     "rtcp-mux-only",
+    ///     "ice-mismatch",
+    ///     "ice-pacing:50",
+    ///     "x-google-flag:conference",
+    ///     "maxprate:12.5",
+    ///     "framesize:96 176-144",
+    ///     "3GPP-Adaptation-Support:2",
+    ///     "X-predecbufsize:51200",
+    ///     "X-initpredecbufperiod:90000",
+    ///     "X-initpostdecbufperiod:90000",
+    ///     "X-decbyterate:16000",
+    ///     "silenceSupp:off - - - -",
+    ///     "mid:0",
+    ///     "rtcp-mux",
+    ///     "group:BUNDLE 0 1",
+    ///     "keywds:SDP session description protocol",
+    ///     "cat:foo.bar",
+    ///     "tool:foo 1.2",
+    ///     "fingerprint:sha-256 8B:87:09:8A:5D:C2:F3:33:EF:C5:B1:F6:84:3A:3D:D6:A3:E2:9C:17:4C:E7:46:3B:1B:CE:84:98:DD:8E:AF:7B",
+    /// ] {
+    ///     assert_eq!(format!("{}", Attributes::try_from(temp).unwrap()), temp);
+    /// }
+    ///
+    /// assert_eq!(format!("{}", Attributes::Sendrecv(true)), "sendrecv");
+    /// ```
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ptime(v) =>           write!(f, "ptime:{}", v),
+            Self::MaxPtime(v) =>        write!(f, "maxptime:{}", v),
+            Self::Rtpmap(v) =>          write!(f, "rtpmap:{}", v),
+            Self::Fmtp(v) =>            write!(f, "fmtp:{}", v),
+            Self::Orient(v) =>          write!(f, "orient:{}", v),
+            Self::Charset(v) =>         write!(f, "charset:{}", v),
+            Self::SdpLang(v) =>         write!(f, "sdplang:{}", v),
+            Self::Lang(v) =>            write!(f, "lang:{}", v),
+            Self::Framerate(v) =>       write!(f, "framerate:{}", v),
+            Self::Quality(v) =>         write!(f, "quality:{}", v),
+            Self::Kind(v) =>            write!(f, "type:{}", v),
+            Self::Recvonly(_) =>        write!(f, "recvonly"),
+            Self::Sendrecv(_) =>        write!(f, "sendrecv"),
+            Self::Sendonly(_) =>        write!(f, "sendonly"),
+            Self::Inactive(_) =>        write!(f, "inactive"),
+            Self::Extmap(v) =>          write!(f, "extmap:{}", v),
+            Self::Mid(v) =>             write!(f, "mid:{}", v),
+            Self::Ssrc(v) =>            write!(f, "ssrc:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::Control(v) =>         write!(f, "control:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::Range(v) =>           write!(f, "range:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::RtpTime(v) =>         write!(f, "rtptime:{}", v),
+            #[cfg(feature = "broadcast")]
+            Self::TsRefClk(v) =>        write!(f, "ts-refclk:{}", v),
+            #[cfg(feature = "broadcast")]
+            Self::MediaClk(v) =>        write!(f, "mediaclk:{}", v),
+            #[cfg(feature = "broadcast")]
+            Self::SourceFilter(v) =>    write!(f, "source-filter:{}", v),
+            Self::RtcpXr(v) =>          write!(f, "rtcp-xr:{}", v),
+            Self::ZrtpHash(v) =>        write!(f, "zrtp-hash:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::Path(v) =>            write!(f, "path:{}", v.iter().format(" ")),
+            #[cfg(feature = "msrp")]
+            Self::AcceptTypes(v) =>     write!(f, "accept-types:{}", v.iter().format(" ")),
+            #[cfg(feature = "msrp")]
+            Self::AcceptWrappedTypes(v) => write!(f, "accept-wrapped-types:{}", v.iter().format(" ")),
+            #[cfg(feature = "msrp")]
+            Self::MaxSize(v) =>         write!(f, "max-size:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::FileSelector(v) =>    write!(f, "file-selector:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::FileTransferId(v) =>  write!(f, "file-transfer-id:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::FileDisposition(v) => write!(f, "file-disposition:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::FileDate(v) =>        write!(f, "file-date:{}", v),
+            #[cfg(feature = "msrp")]
+            Self::FileIcon(v) =>        write!(f, "file-icon:{}", v),
+            Self::Tcap(v) =>            write!(f, "tcap:{}", v),
+            Self::Acap(v) =>            write!(f, "acap:{}", v),
+            Self::Pcfg(v) =>            write!(f, "pcfg:{}", v),
+            Self::Acfg(v) =>            write!(f, "acfg:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::Sqn(v) =>             write!(f, "sqn: {}", v),
+            #[cfg(feature = "legacy")]
+            Self::Cdsc(v) =>            write!(f, "cdsc:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::Cpar(v) =>            write!(f, "cpar:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::CparMin(v) =>         write!(f, "cparmin:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::CparMax(v) =>         write!(f, "cparmax:{}", v),
+            Self::Label(v) =>           write!(f, "label:{}", v),
+            Self::Content(v) =>         write!(f, "content:{}", v.iter().format(",")),
+            Self::Setup(v) =>           write!(f, "setup:{}", v),
+            Self::Connection(v) =>      write!(f, "connection:{}", v),
+            Self::TlsId(v) =>           write!(f, "tls-id:{}", v),
+            Self::Identity(v) =>        write!(f, "identity:{}", v),
+            Self::BundleOnly =>         write!(f, "bundle-only"),
+            Self::RtcpMuxOnly =>        write!(f, "rtcp-mux-only"),
+            Self::IceMismatch =>        write!(f, "ice-mismatch"),
+            Self::IcePacing(v) =>       write!(f, "ice-pacing:{}", v),
+            #[cfg(feature = "webrtc")]
+            Self::XGoogleFlag(v) =>     write!(f, "x-google-flag:{}", v),
+            Self::MaxPrate(v) =>        write!(f, "maxprate:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::Framesize(v) =>       write!(f, "framesize:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::AdaptationSupport(v) => write!(f, "3GPP-Adaptation-Support:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::PreDecBufSize(v) =>   write!(f, "X-predecbufsize:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::InitPreDecBufPeriod(v) => write!(f, "X-initpredecbufperiod:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::InitPostDecBufPeriod(v) => write!(f, "X-initpostdecbufperiod:{}", v),
+            #[cfg(feature = "rtsp")]
+            Self::DecByteRate(v) =>     write!(f, "X-decbyterate:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::SilenceSupp(v) =>     write!(f, "silenceSupp:{}", v),
+            Self::Group(v) =>           write!(f, "group:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::Keywds(v) =>          write!(f, "keywds:{}", v),
+            #[cfg(feature = "legacy")]
+            Self::Cat(v) =>             write!(f, "cat:{}", v),
+            Self::Tool(v) =>            write!(f, "tool:{}", v),
+            Self::Fingerprint(v) =>     write!(f, "fingerprint:{}", v),
+            Self::Other(k, Some(v)) =>  write!(f, "{}:{}", k, v),
+            Self::Other(k, None) =>     write!(f, "{}", k),
+        }
+    }
+}
+
+impl fmt::Display for Attribute<'_> {
+    /// The typed value is written, not the raw text, so that changes to
+    /// the value are reflected in the output.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<'a> From<Attributes<'a>> for Attribute<'a> {
+    fn from(value: Attributes<'a>) -> Self {
+        Self {
+            raw: None,
+            value,
+            span: None,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Attribute<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let attr: Attribute = Attribute::try_from("ptime:20").unwrap();
+    /// assert_eq!(attr.raw.as_deref(), Some("ptime:20"));
+    /// assert!(matches!(attr.value, Attributes::Ptime(20)));
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: Attributes::try_from(value)?,
+            raw: Some(Cow::Borrowed(value)),
+            span: None,
+        })
+    }
+}
+
+impl FromStr for Attribute<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned attribute line, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Attribute::try_from(value).map(Attribute::into_owned)
+    }
 }
 
 impl<'a> TryFrom<&'a str> for Attributes<'a> {
@@ -375,12 +1378,18 @@ impl<'a> TryFrom<&'a str> for Attributes<'a> {
     /// use sdp::attributes::*;
     /// use std::convert::*;
     ///
-    /// let value: RtpValue = RtpValue::try_from("VP8/9000")
+    /// let value: Attributes = Attributes::try_from("ptime:20")
     ///     .unwrap();
     /// 
-    /// assert_eq!(value.codec, Codec::Vp8);
-    /// assert_eq!(value.frequency, Some(9000));
-    /// assert_eq!(value.channels, None);
+    /// assert!(matches!(value, Attributes::Ptime(20)));
+    /// assert!(matches!(Attributes::try_from("rtcp-mux").unwrap(), Attributes::Other(k, None) if k == "rtcp-mux"));
+    /// assert!(Attributes::try_from("ptime:a").is_err());
+    ///
+    /// assert_eq!(Attributes::try_from("type:H332").unwrap(), Attributes::Kind(Kind::H332));
+    /// let orient = Attributes::try_from("orient:Portrait").unwrap();
+    /// assert!(matches!(&orient, Attributes::Other(k, Some(v)) if k == "orient" && v == "Portrait"));
+    /// assert_eq!(orient.name(), "orient");
+    /// assert_eq!(orient.to_string(), "orient:Portrait");
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         let mut iter = value.splitn(2, ':');
@@ -389,25 +1398,124 @@ impl<'a> TryFrom<&'a str> for Attributes<'a> {
         })?;
         
         let v = match iter.next() {
-            None => return Ok(Self::Other(key, None)),
+            None => return Ok(match key {
+                "bundle-only" =>   Self::BundleOnly,
+                "rtcp-mux-only" => Self::RtcpMuxOnly,
+                "ice-mismatch" =>  Self::IceMismatch,
+                _ => Self::Other(Cow::Borrowed(key), None)
+            }),
             Some(v) => v,
         };
 
-        Ok(match key {
-            "fmtp"      => Self::Fmtp(Fmtp::try_from(v)?),
-            "rtpmap"    => Self::Rtpmap(RtpMap::try_from(v)?),
-            "extmap"    => Self::Extmap(ExtMap::try_from(v)?),
-            "lang"      => Self::Lang(v),
-            "charset"   => Self::Charset(v),
-            "sdplang"   => Self::SdpLang(v),
-            "ptime"     => Self::Ptime(v.parse()?),
-            "maxptime"  => Self::MaxPtime(v.parse()?),
-            "orient"    => Self::Orient(Orient::try_from(v)?),
-            "type"      => Self::Kind(Kind::try_from(v)?),
-            "framerate" => Self::Framerate(v.parse()?),
-            "quality"   => Self::Quality(v.parse()?),
-            "ssrc"      => Self::Ssrc(Ssrc::try_from(v)?),
-            _ => Self::Other(key, Some(v))
+        // values that are not one of the case-sensitive canonical names
+        // are kept as they are, so that they are written back unchanged.
+        let other = || Self::Other(Cow::Borrowed(key), Some(Cow::Borrowed(v)));
+
+        // names are matched by length first, so that an unknown name is
+        // only compared with the names of the same length.
+        Ok(match (key.len(), key) {
+            #[cfg(feature = "legacy")]
+            (3, "sqn")                      => Self::Sqn(v.trim_start().parse()?),
+            (3, "mid")                      => Self::Mid(Mid::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (3, "cat")                      => Self::Cat(Category::try_from(v)?),
+            (4, "fmtp")                     => Self::Fmtp(Fmtp::try_from(v)?),
+            (4, "lang")                     => Self::Lang(Cow::Borrowed(v)),
+            (4, "type")                     => Kind::try_from(v).map_or_else(|_| other(), Self::Kind),
+            (4, "ssrc")                     => Self::Ssrc(Ssrc::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (4, "path")                     => Self::Path(msrp::parse_path(v)?),
+            (4, "tcap")                     => Self::Tcap(Tcap::try_from(v)?),
+            (4, "acap")                     => Self::Acap(Acap::try_from(v)?),
+            (4, "pcfg")                     => Self::Pcfg(Config::try_from(v)?),
+            (4, "acfg")                     => Self::Acfg(Config::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (4, "cdsc")                     => Self::Cdsc(Cdsc::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (4, "cpar")                     => Self::Cpar(Cow::Borrowed(v)),
+            (4, "tool")                     => Self::Tool(Tool::try_from(v)?),
+            (5, "ptime")                    => Self::Ptime(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (5, "range")                    => Self::Range(Range::try_from(v)?),
+            (5, "label")                    => Self::Label(Cow::Borrowed(v)),
+            (5, "setup")                    => Self::Setup(Setup::try_from(v)?),
+            (5, "group")                    => Self::Group(Group::try_from(v)?),
+            (6, "rtpmap")                   => Self::Rtpmap(RtpMap::try_from(v)?),
+            (6, "extmap")                   => Self::Extmap(ExtMap::try_from(v)?),
+            (6, "orient")                   => Orient::try_from(v).map_or_else(|_| other(), Self::Orient),
+            (6, "tls-id")                   => Self::TlsId(TlsId::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (6, "keywds")                   => Self::Keywds(Cow::Borrowed(v)),
+            (7, "charset")                  => Self::Charset(Cow::Borrowed(v)),
+            (7, "sdplang")                  => Self::SdpLang(Cow::Borrowed(v)),
+            (7, "quality")                  => Self::Quality(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (7, "control")                  => Self::Control(Cow::Borrowed(v)),
+            #[cfg(feature = "rtsp")]
+            (7, "rtptime")                  => Self::RtpTime(v.parse()?),
+            (7, "rtcp-xr")                  => Self::RtcpXr(RtcpXr::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (7, "cparmin")                  => Self::CparMin(Cow::Borrowed(v)),
+            (7, "content")                  => Self::Content(
+                v.split(',').map(Content::try_from).collect::<Result<_>>()?
+            ),
+            #[cfg(feature = "legacy")]
+            (7, "cparmax")                  => Self::CparMax(Cow::Borrowed(v)),
+            (8, "maxptime")                 => Self::MaxPtime(v.parse()?),
+            (8, "maxprate")                 => Self::MaxPrate(util::decimal(v)?),
+            #[cfg(feature = "broadcast")]
+            (8, "mediaclk")                 => Self::MediaClk(MediaClk::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (8, "max-size")                 => Self::MaxSize(v.parse()?),
+            (8, "identity")                 => Self::Identity(Identity::try_from(v)?),
+            (9, "framerate")                => Self::Framerate(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (9, "framesize")                => Self::Framesize(Framesize::try_from(v)?),
+            #[cfg(feature = "broadcast")]
+            (9, "ts-refclk")                => Self::TsRefClk(RefClock::try_from(v)?),
+            (9, "zrtp-hash")                => Self::ZrtpHash(ZrtpHash::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (9, "file-date")                => Self::FileDate(FileDate::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (9, "file-icon")                => Self::FileIcon(Cow::Borrowed(v)),
+            (10, "connection")              => Self::Connection(TcpConnection::try_from(v)?),
+            (10, "ice-pacing")              => Self::IcePacing(v.parse()?),
+            (11, "fingerprint")             => Self::Fingerprint(Fingerprint::try_from(v)?),
+            #[cfg(feature = "legacy")]
+            (11, "silenceSupp")             => Self::SilenceSupp(SilenceSupp::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (12, "accept-types")            => Self::AcceptTypes(msrp::parse_types(v)?),
+            #[cfg(feature = "broadcast")]
+            (13, "source-filter")           => Self::SourceFilter(SourceFilter::try_from(v)?),
+            #[cfg(feature = "msrp")]
+            (13, "file-selector")           => Self::FileSelector(FileSelector::try_from(v)?),
+            #[cfg(feature = "webrtc")]
+            (13, "x-google-flag")           => Self::XGoogleFlag(Cow::Borrowed(v)),
+            #[cfg(feature = "rtsp")]
+            (13, "X-decbyterate")           => Self::DecByteRate(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (15, "X-predecbufsize")         => Self::PreDecBufSize(v.parse()?),
+            #[cfg(feature = "msrp")]
+            (16, "file-transfer-id")        => Self::FileTransferId(Cow::Borrowed(v)),
+            #[cfg(feature = "msrp")]
+            (16, "file-disposition")        => Self::FileDisposition(Cow::Borrowed(v)),
+            #[cfg(feature = "rtsp")]
+            (21, "X-initpredecbufperiod")   => Self::InitPreDecBufPeriod(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (22, "X-initpostdecbufperiod")  => Self::InitPostDecBufPeriod(v.parse()?),
+            #[cfg(feature = "rtsp")]
+            (23, "3GPP-Adaptation-Support") => Self::AdaptationSupport(v.parse()?),
+            #[cfg(feature = "msrp")]
+            (20, "accept-wrapped-types")    => Self::AcceptWrappedTypes(msrp::parse_types(v)?),
+            _ => other()
         })
     }
 }
+
+impl FromStr for Attributes<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned attribute, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Attributes::try_from(value).map(Attributes::into_owned)
+    }
+}
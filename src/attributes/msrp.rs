@@ -0,0 +1,145 @@
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// MSRP URI.
+///
+/// msrp-uri = msrp-scheme "://" authority ["/" session-id] ";" transport
+///     *( ";" URI-parameter)
+///
+/// An element of the "a=path:" attribute
+/// [RFC4975](https://datatracker.ietf.org/doc/html/rfc4975#section-6).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "msrps://atlanta.example.com:7654/jshA7weztas;tcp";
+/// let uri = MsrpUri::try_from(temp).unwrap();
+/// assert!(uri.secure);
+/// assert_eq!(uri.authority, "atlanta.example.com:7654");
+/// assert_eq!(uri.session_id.as_deref(), Some("jshA7weztas"));
+/// assert_eq!(uri.transport, "tcp");
+/// assert_eq!(format!("{}", uri), temp);
+///
+/// let temp = "msrp://192.0.2.1:7654;tcp;foo=bar";
+/// let uri = MsrpUri::try_from(temp).unwrap();
+/// assert_eq!(uri.session_id, None);
+/// assert_eq!(uri.params, vec!["foo=bar"]);
+/// assert_eq!(format!("{}", uri), temp);
+///
+/// assert!(MsrpUri::try_from("sip://atlanta.example.com;tcp").is_err());
+/// assert!(MsrpUri::try_from("msrp://atlanta.example.com/jshA7weztas").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MsrpUri<'a> {
+    /// "msrps" instead of "msrp".
+    pub secure: bool,
+    /// host and optional port.
+    pub authority: Cow<'a, str>,
+    pub session_id: Option<Cow<'a, str>>,
+    /// e.g. "tcp".
+    pub transport: Cow<'a, str>,
+    pub params: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MsrpUri<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> MsrpUri<'b> {
+        MsrpUri {
+            secure: self.secure,
+            authority: Cow::Owned(self.authority.into_owned()),
+            session_id: self.session_id.map(|v| Cow::Owned(v.into_owned())),
+            transport: Cow::Owned(self.transport.into_owned()),
+            params: self.params
+                .into_iter()
+                .map(|v| Cow::Owned(v.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for MsrpUri<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", if self.secure { "msrps" } else { "msrp" }, self.authority)?;
+        if let Some(v) = &self.session_id {
+            write!(f, "/{}", v)?;
+        }
+
+        write!(f, ";{}", self.transport)?;
+        for v in &self.params {
+            write!(f, ";{}", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MsrpUri<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (scheme, value) = value
+            .split_once("://")
+            .ok_or_else(|| anyhow!("invalid msrp uri!"))?;
+        let secure = match scheme {
+            "msrp" => false,
+            "msrps" => true,
+            _ => return Err(anyhow!("invalid msrp uri!"))
+        };
+
+        let mut values = value.split(';');
+        let address = values.next().unwrap_or_default();
+        let transport = values.next().ok_or_else(|| anyhow!("invalid msrp uri!"))?;
+        let (authority, session_id) = match address.split_once('/') {
+            Some((authority, session_id)) => (authority, Some(Cow::Borrowed(session_id))),
+            None => (address, None),
+        };
+
+        ensure!(!authority.is_empty() && !transport.is_empty(), "invalid msrp uri!");
+        Ok(Self {
+            secure,
+            authority: Cow::Borrowed(authority),
+            session_id,
+            transport: Cow::Borrowed(transport),
+            params: values.map(Cow::Borrowed).collect(),
+        })
+    }
+}
+
+/// parse the "a=path:" value, a space separated list of MSRP URIs.
+pub(crate) fn parse_path(value: &str) -> Result<Vec<MsrpUri<'_>>> {
+    let path = Scanner::new(value, "msrp path")
+        .fields()
+        .map(MsrpUri::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(!path.is_empty(), "invalid msrp path!");
+    Ok(path)
+}
+
+/// parse the "a=accept-types:" and "a=accept-wrapped-types:" values, a
+/// space separated list of media types, where "*" accepts any type.
+pub(crate) fn parse_types(value: &str) -> Result<Vec<Cow<'_, str>>> {
+    let types = Scanner::new(value, "media types")
+        .fields()
+        .map(|v| {
+            ensure!(v == "*" || v.contains('/'), "invalid media type!");
+            Ok(Cow::Borrowed(v))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(!types.is_empty(), "invalid media types!");
+    Ok(types)
+}
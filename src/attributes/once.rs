@@ -0,0 +1,102 @@
+use super::Attributes;
+use core::fmt;
+
+/// The identity of an attribute that may appear at most once per
+/// session or media description, see [`Attributes::once`].
+///
+/// Attributes that apply to a payload type, e.g. "a=fmtp:", may appear
+/// once per payload type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnceKey {
+    /// the attribute name, "direction" for the direction attributes
+    /// which exclude each other.
+    pub name: &'static str,
+    /// the payload type the attribute applies to.
+    pub payload: Option<u8>,
+}
+
+impl OnceKey {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            payload: None,
+        }
+    }
+}
+
+impl fmt::Display for OnceKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.payload {
+            Some(pt) => write!(f, "{}:{}", self.name, pt),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl Attributes<'_> {
+    /// the identity of the attribute if it may appear at most once per
+    /// session or media description, `None` if it may be repeated.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let key = Attributes::try_from("fmtp:96 apt=100").unwrap().once().unwrap();
+    /// assert_eq!(key.to_string(), "fmtp:96");
+    /// assert_eq!(Attributes::try_from("mid:0").unwrap().once().unwrap().to_string(), "mid");
+    /// assert_eq!(Attributes::try_from("ice-ufrag:8hhY").unwrap().once().unwrap().to_string(), "ice-ufrag");
+    ///
+    /// let sendonly = Attributes::try_from("sendonly").unwrap().once();
+    /// assert_eq!(sendonly.unwrap().to_string(), "direction");
+    /// assert_eq!(sendonly, Attributes::try_from("recvonly").unwrap().once());
+    /// assert_eq!(sendonly, Attributes::Sendonly(true).once());
+    ///
+    /// let orient = Attributes::try_from("orient:Portrait").unwrap().once();
+    /// assert_eq!(orient, Attributes::try_from("orient:portrait").unwrap().once());
+    ///
+    /// assert!(Attributes::try_from("ssrc:1 cname:panda").unwrap().once().is_none());
+    /// assert!(Attributes::try_from("x-panda:1").unwrap().once().is_none());
+    /// ```
+    pub fn once(&self) -> Option<OnceKey> {
+        Some(match self {
+            Self::Rtpmap(v) => OnceKey {
+                name: "rtpmap",
+                payload: Some(v.key),
+            },
+            Self::Fmtp(v) => OnceKey {
+                name: "fmtp",
+                payload: Some(v.key),
+            },
+            Self::Ptime(_) =>       OnceKey::new("ptime"),
+            Self::MaxPtime(_) =>    OnceKey::new("maxptime"),
+            Self::Orient(_) =>      OnceKey::new("orient"),
+            Self::Charset(_) =>     OnceKey::new("charset"),
+            Self::Framerate(_) =>   OnceKey::new("framerate"),
+            Self::Quality(_) =>     OnceKey::new("quality"),
+            Self::Kind(_) =>        OnceKey::new("type"),
+            Self::Mid(_) =>         OnceKey::new("mid"),
+            Self::Label(_) =>       OnceKey::new("label"),
+            Self::Setup(_) =>       OnceKey::new("setup"),
+            Self::Connection(_) =>  OnceKey::new("connection"),
+            Self::IcePacing(_) =>   OnceKey::new("ice-pacing"),
+            Self::Recvonly(_)
+            | Self::Sendrecv(_)
+            | Self::Sendonly(_)
+            | Self::Inactive(_) =>  OnceKey::new("direction"),
+            // the direction flags are kept as unknown attributes, and so
+            // are "a=orient:" and "a=type:" values that are not canonical.
+            Self::Other(k, v) => match (k.as_ref(), v) {
+                ("ice-ufrag", _) =>     OnceKey::new("ice-ufrag"),
+                ("ice-pwd", _) =>       OnceKey::new("ice-pwd"),
+                ("ice-options", _) =>   OnceKey::new("ice-options"),
+                ("orient", Some(_)) =>  OnceKey::new("orient"),
+                ("type", Some(_)) =>    OnceKey::new("type"),
+                ("recvonly" | "sendrecv" | "sendonly" | "inactive", None) => OnceKey::new("direction"),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+}
@@ -3,7 +3,7 @@ use anyhow::{
     anyhow
 };
 
-use std::{
+use core::{
     convert::TryFrom,
     fmt
 };
@@ -12,7 +12,10 @@ use std::{
 /// specifies the orientation of the workspace on the screen.  Permitted
 /// values are "portrait", "landscape", and "seascape" (upside-down
 /// landscape).s
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Orient {
     Portrait,
     Landscape,
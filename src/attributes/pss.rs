@@ -0,0 +1,70 @@
+use crate::scanner::Scanner;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  framesize
+/// Value:  payload-type SP width "-" height
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=framesize:96 176-144
+///
+/// The largest frame size in pixels of a video payload type, defined by
+/// the 3GPP packet-switched streaming service
+/// [TS 26.234](https://www.3gpp.org/ftp/Specs/archive/26_series/26.234/)
+/// and used by mobile streaming servers.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let framesize = Framesize::try_from("96 176-144").unwrap();
+/// assert_eq!(framesize, Framesize { key: 96, width: 176, height: 144 });
+/// assert_eq!(format!("{}", framesize), "96 176-144");
+///
+/// assert!(Framesize::try_from("96 176x144").is_err());
+/// assert!(Framesize::try_from("96 0-144").is_err());
+/// assert!(Framesize::try_from("96").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Framesize {
+    /// the payload type.
+    pub key: u8,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl fmt::Display for Framesize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}-{}", self.key, self.width, self.height)
+    }
+}
+
+impl TryFrom<&str> for Framesize {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "framesize");
+        let key = scanner.field()?;
+        let width = scanner.until('-')?.parse()?;
+        let height = scanner.field()?.parse()?;
+        scanner.finish()?;
+        ensure!(width > 0 && height > 0, "invalid framesize!");
+        Ok(Self {
+            key: key.parse()?,
+            width,
+            height,
+        })
+    }
+}
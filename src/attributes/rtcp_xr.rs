@@ -0,0 +1,270 @@
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// receiver reference time report mode.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RttMode {
+    /// all participants send receiver reference time reports.
+    All,
+    /// only active senders send them.
+    Sender,
+}
+
+/// statistics summary report flags.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatFlag {
+    Loss,
+    Dup,
+    Jitt,
+    Ttl,
+    Hl,
+}
+
+impl fmt::Display for StatFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Loss => "loss",
+            Self::Dup =>  "dup",
+            Self::Jitt => "jitt",
+            Self::Ttl =>  "TTL",
+            Self::Hl =>   "HL",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for StatFlag {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "loss" => Ok(Self::Loss),
+            "dup" =>  Ok(Self::Dup),
+            "jitt" => Ok(Self::Jitt),
+            "TTL" =>  Ok(Self::Ttl),
+            "HL" =>   Ok(Self::Hl),
+            _ => Err(anyhow!("invalid stat flag!"))
+        }
+    }
+}
+
+/// RTCP XR report block request.
+///
+/// The optional `max-size` values limit the size of the report block
+/// in bytes.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XrBlock<'a> {
+    PktLossRle(Option<u32>),
+    PktDupRle(Option<u32>),
+    PktRcptTimes(Option<u32>),
+    RcvrRtt {
+        mode: RttMode,
+        max_size: Option<u32>,
+    },
+    StatSummary(Vec<StatFlag>),
+    VoipMetrics,
+    /// the complete block, for formats this crate does not know.
+    Other(Cow<'a, str>),
+}
+
+impl<'a> XrBlock<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> XrBlock<'b> {
+        match self {
+            Self::PktLossRle(v) => XrBlock::PktLossRle(v),
+            Self::PktDupRle(v) => XrBlock::PktDupRle(v),
+            Self::PktRcptTimes(v) => XrBlock::PktRcptTimes(v),
+            Self::RcvrRtt { mode, max_size } => XrBlock::RcvrRtt { mode, max_size },
+            Self::StatSummary(v) => XrBlock::StatSummary(v),
+            Self::VoipMetrics => XrBlock::VoipMetrics,
+            Self::Other(v) => XrBlock::Other(Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for XrBlock<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, max_size) = match self {
+            Self::PktLossRle(v) => ("pkt-loss-rle", v),
+            Self::PktDupRle(v) => ("pkt-dup-rle", v),
+            Self::PktRcptTimes(v) => ("pkt-rcpt-times", v),
+            Self::RcvrRtt { mode, max_size } => {
+                write!(f, "rcvr-rtt={}", match mode {
+                    RttMode::All => "all",
+                    RttMode::Sender => "sender",
+                })?;
+
+                return match max_size {
+                    Some(v) => write!(f, ":{}", v),
+                    None => Ok(()),
+                }
+            },
+            Self::StatSummary(flags) => {
+                write!(f, "stat-summary")?;
+                for (i, flag) in flags.iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { "=" } else { "," }, flag)?;
+                }
+
+                return Ok(())
+            },
+            Self::VoipMetrics => return write!(f, "voip-metrics"),
+            Self::Other(v) => return write!(f, "{}", v),
+        };
+
+        write!(f, "{}", name)?;
+        match max_size {
+            Some(v) => write!(f, "={}", v),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for XrBlock<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(XrBlock::try_from("pkt-loss-rle").unwrap(), XrBlock::PktLossRle(None));
+    /// assert_eq!(XrBlock::try_from("pkt-dup-rle=100").unwrap(), XrBlock::PktDupRle(Some(100)));
+    /// assert_eq!(XrBlock::try_from("rcvr-rtt=all:10").unwrap(), XrBlock::RcvrRtt {
+    ///     mode: RttMode::All,
+    ///     max_size: Some(10)
+    /// });
+    ///
+    /// assert_eq!(
+    ///     XrBlock::try_from("stat-summary=loss,dup,jitt").unwrap(),
+    ///     XrBlock::StatSummary(vec![StatFlag::Loss, StatFlag::Dup, StatFlag::Jitt])
+    /// );
+    ///
+    /// assert_eq!(XrBlock::try_from("x-panda").unwrap(), XrBlock::Other("x-panda".into()));
+    /// assert!(XrBlock::try_from("rcvr-rtt=none").is_err());
+    /// assert!(XrBlock::try_from("pkt-loss-rle=a").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (name, v) = match value.split_once('=') {
+            Some((name, v)) => (name, Some(v)),
+            None => (value, None),
+        };
+
+        let max_size = || v.map(str::parse).transpose();
+        Ok(match name {
+            "pkt-loss-rle" => Self::PktLossRle(max_size()?),
+            "pkt-dup-rle" => Self::PktDupRle(max_size()?),
+            "pkt-rcpt-times" => Self::PktRcptTimes(max_size()?),
+            "voip-metrics" if v.is_none() => Self::VoipMetrics,
+            "stat-summary" => Self::StatSummary(match v {
+                Some(v) => v
+                    .split(',')
+                    .map(StatFlag::try_from)
+                    .collect::<Result<_>>()?,
+                None => Vec::new(),
+            }),
+            "rcvr-rtt" => {
+                let v = v.ok_or_else(|| anyhow!("invalid rcvr-rtt!"))?;
+                let (mode, max_size) = match v.split_once(':') {
+                    Some((mode, max_size)) => (mode, Some(max_size.parse()?)),
+                    None => (v, None),
+                };
+
+                Self::RcvrRtt {
+                    mode: match mode {
+                        "all" => RttMode::All,
+                        "sender" => RttMode::Sender,
+                        _ => return Err(anyhow!("invalid rcvr-rtt!"))
+                    },
+                    max_size,
+                }
+            },
+            _ => Self::Other(Cow::Borrowed(value)),
+        })
+    }
+}
+
+/// Name:  rtcp-xr
+/// Value:  [xr-format *(SP xr-format)]
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=rtcp-xr:rcvr-rtt=all:10 stat-summary=loss,dup,jitt voip-metrics
+///
+/// The RTCP extended report blocks that the participants are requested
+/// to send
+/// [RFC3611](https://datatracker.ietf.org/doc/html/rfc3611#section-5.1).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "rcvr-rtt=all:10 stat-summary=loss,dup,jitt voip-metrics pkt-loss-rle";
+/// let xr = RtcpXr::try_from(temp).unwrap();
+/// assert_eq!(xr.blocks.len(), 4);
+/// assert_eq!(xr.blocks[2], XrBlock::VoipMetrics);
+/// assert_eq!(format!("{}", xr), temp);
+///
+/// assert!(RtcpXr::try_from("").unwrap().blocks.is_empty());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtcpXr<'a> {
+    pub blocks: Vec<XrBlock<'a>>,
+}
+
+impl<'a> RtcpXr<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> RtcpXr<'b> {
+        RtcpXr {
+            blocks: self.blocks
+                .into_iter()
+                .map(XrBlock::into_owned)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for RtcpXr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, block) in self.blocks.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", block)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RtcpXr<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            blocks: Scanner::new(value, "rtcp-xr")
+                .fields()
+                .map(XrBlock::try_from)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
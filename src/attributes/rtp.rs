@@ -1,10 +1,12 @@
-use crate::util::tuple2_from_split;
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
 use anyhow::{
     Result,
-    ensure
+    ensure,
+    anyhow
 };
 
-use std::{
+use core::{
     convert::TryFrom, 
     fmt
 };
@@ -69,13 +71,91 @@ use std::{
 /// [RFC3551](https://datatracker.ietf.org/doc/html/rfc3551)) 
 /// packetization is required, the "a=ptime:" attribute is used as given 
 /// in [Section 6.4](https://datatracker.ietf.org/doc/html/rfc8866#section-6.4).
-#[derive(Debug)]
+/// static payload types of the RTP/AVP profile, with the encoding
+/// name, clock rate and audio channels
+/// [RFC3551](https://datatracker.ietf.org/doc/html/rfc3551#section-6).
+const STATIC_PAYLOAD_TYPES: [(u8, &str, u64, Option<u8>); 24] = [
+    (0, "PCMU", 8000, Some(1)),
+    (3, "GSM", 8000, Some(1)),
+    (4, "G723", 8000, Some(1)),
+    (5, "DVI4", 8000, Some(1)),
+    (6, "DVI4", 16000, Some(1)),
+    (7, "LPC", 8000, Some(1)),
+    (8, "PCMA", 8000, Some(1)),
+    (9, "G722", 8000, Some(1)),
+    (10, "L16", 44100, Some(2)),
+    (11, "L16", 44100, Some(1)),
+    (12, "QCELP", 8000, Some(1)),
+    (13, "CN", 8000, Some(1)),
+    (14, "MPA", 90000, None),
+    (15, "G728", 8000, Some(1)),
+    (16, "DVI4", 11025, Some(1)),
+    (17, "DVI4", 22050, Some(1)),
+    (18, "G729", 8000, Some(1)),
+    (25, "CelB", 90000, None),
+    (26, "JPEG", 90000, None),
+    (28, "nv", 90000, None),
+    (31, "H261", 90000, None),
+    (32, "MPV", 90000, None),
+    (33, "MP2T", 90000, None),
+    (34, "H263", 90000, None),
+];
+
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RtpValue<'a> {
-    pub codec: &'a str,
+    pub codec: Cow<'a, str>,
     pub frequency: Option<u64>,
     pub channels: Option<u8>
 }
 
+impl RtpValue<'static> {
+    /// the encoding of a static payload type of the RTP/AVP profile
+    /// [RFC3551](https://datatracker.ietf.org/doc/html/rfc3551#section-6),
+    /// which is used without an "a=rtpmap:" attribute.
+    ///
+    /// Audio encodings report their channels, video encodings have
+    /// none.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// let value = RtpValue::from_static(9).unwrap();
+    /// assert_eq!(value.codec, "G722");
+    /// assert_eq!(value.frequency, Some(8000));
+    /// assert_eq!(value.channels, Some(1));
+    ///
+    /// assert_eq!(RtpValue::from_static(10).unwrap().to_string(), "L16/44100/2");
+    /// assert_eq!(RtpValue::from_static(31).unwrap().to_string(), "H261/90000");
+    /// assert!(RtpValue::from_static(2).is_none());
+    /// assert!(RtpValue::from_static(96).is_none());
+    /// ```
+    pub fn from_static(pt: u8) -> Option<Self> {
+        let (_, codec, frequency, channels) = STATIC_PAYLOAD_TYPES
+            .iter()
+            .find(|(v, ..)| *v == pt)?;
+        Some(Self {
+            codec: Cow::Borrowed(codec),
+            frequency: Some(*frequency),
+            channels: *channels,
+        })
+    }
+}
+
+impl<'a> RtpValue<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> RtpValue<'b> {
+        RtpValue {
+            codec: Cow::Owned(self.codec.into_owned()),
+            frequency: self.frequency,
+            channels: self.channels,
+        }
+    }
+}
+
 impl fmt::Display for RtpValue<'_> {
     /// # Unit Test
     ///
@@ -83,7 +163,7 @@ impl fmt::Display for RtpValue<'_> {
     /// use sdp::attributes::*;
     ///
     /// let rtp = RtpValue {
-    ///     codec: Codec::Vp9,
+    ///     codec: "VP9".into(),
     ///     frequency: Some(9000),
     ///     channels: None
     /// };
@@ -116,28 +196,141 @@ impl<'a> TryFrom<&'a str> for RtpValue<'a> {
     /// let value: RtpValue = RtpValue::try_from("VP8/9000")
     ///     .unwrap();
     /// 
-    /// assert_eq!(value.codec, Codec::Vp8);
+    /// assert_eq!(value.codec, "VP8");
     /// assert_eq!(value.frequency, Some(9000));
     /// assert_eq!(value.channels, None);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split('/').collect::<Vec<&str>>();
-        ensure!(!values.is_empty(), "invalid attributes rtpmap!");
-        ensure!(values[0].len() > 0, "invalid attributes rtpmap!");
+        let mut values = value.split('/');
+        let codec = values.next().ok_or_else(|| {
+            anyhow!("invalid attributes rtpmap!")
+        })?;
+
+        ensure!(!codec.is_empty(), "invalid attributes rtpmap!");
         Ok(Self {
-            codec: values[0],
-            frequency: if let Some(c) = values.get(1) { Some(c.parse()?) } else { None },
-            channels: if let Some(c) = values.get(2) { Some(c.parse()?) } else { None }
+            codec: Cow::Borrowed(codec),
+            frequency: values.next().map(str::parse).transpose()?,
+            channels: values.next().map(str::parse).transpose()?
         })
     }
 }
 
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RtpMap<'a> {
     pub key: u8, 
     pub value: RtpValue<'a>,
 }
 
+impl RtpMap<'static> {
+    fn with(key: u8, codec: &'static str, frequency: u64, channels: Option<u8>) -> Self {
+        Self {
+            key,
+            value: RtpValue {
+                codec: Cow::Borrowed(codec),
+                frequency: Some(frequency),
+                channels,
+            },
+        }
+    }
+
+    /// "opus/48000/2", Opus is always signalled with 2 channels
+    /// [RFC7587](https://datatracker.ietf.org/doc/html/rfc7587#section-7).
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert_eq!(RtpMap::opus(111).to_string(), "111 opus/48000/2");
+    /// assert_eq!(RtpMap::pcmu(0).to_string(), "0 PCMU/8000");
+    /// assert_eq!(RtpMap::g722(9).to_string(), "9 G722/8000");
+    /// assert_eq!(RtpMap::telephone_event(101, 48000).to_string(), "101 telephone-event/48000");
+    /// assert_eq!(RtpMap::vp8(96).to_string(), "96 VP8/90000");
+    /// assert_eq!(RtpMap::h264(102).to_string(), "102 H264/90000");
+    /// assert_eq!(RtpMap::rtx(97, 90000).to_string(), "97 rtx/90000");
+    /// ```
+    pub fn opus(key: u8) -> Self {
+        Self::with(key, "opus", 48000, Some(2))
+    }
+
+    /// "PCMU/8000".
+    pub fn pcmu(key: u8) -> Self {
+        Self::with(key, "PCMU", 8000, None)
+    }
+
+    /// "PCMA/8000".
+    pub fn pcma(key: u8) -> Self {
+        Self::with(key, "PCMA", 8000, None)
+    }
+
+    /// "G722/8000", the clock rate of G.722 is 8000 for historical
+    /// reasons, although it samples at 16 kHz
+    /// [RFC3551](https://datatracker.ietf.org/doc/html/rfc3551#section-4.5.2).
+    pub fn g722(key: u8) -> Self {
+        Self::with(key, "G722", 8000, None)
+    }
+
+    /// "telephone-event" with the clock rate of the audio codec it is
+    /// used with
+    /// [RFC4733](https://datatracker.ietf.org/doc/html/rfc4733#section-7.1.1).
+    pub fn telephone_event(key: u8, frequency: u64) -> Self {
+        Self::with(key, "telephone-event", frequency, None)
+    }
+
+    /// "VP8/90000".
+    pub fn vp8(key: u8) -> Self {
+        Self::with(key, "VP8", 90000, None)
+    }
+
+    /// "VP9/90000".
+    pub fn vp9(key: u8) -> Self {
+        Self::with(key, "VP9", 90000, None)
+    }
+
+    /// "H264/90000".
+    pub fn h264(key: u8) -> Self {
+        Self::with(key, "H264", 90000, None)
+    }
+
+    /// "AV1/90000".
+    pub fn av1(key: u8) -> Self {
+        Self::with(key, "AV1", 90000, None)
+    }
+
+    /// "rtx" with the clock rate of the retransmitted payload type
+    /// [RFC4588](https://datatracker.ietf.org/doc/html/rfc4588#section-8.6).
+    pub fn rtx(key: u8, frequency: u64) -> Self {
+        Self::with(key, "rtx", frequency, None)
+    }
+}
+
+impl<'a> RtpMap<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> RtpMap<'b> {
+        RtpMap {
+            key: self.key,
+            value: self.value.into_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RtpMap<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "111 opus/48000/2";
+    /// assert_eq!(format!("{}", RtpMap::try_from(temp).unwrap()), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.key, self.value)
+    }
+}
+
 impl<'a> TryFrom<&'a str> for RtpMap<'a> {
     type Error = anyhow::Error;
     /// # Unit Test
@@ -152,7 +345,10 @@ impl<'a> TryFrom<&'a str> for RtpMap<'a> {
     /// assert!(RtpMap::try_from("98").is_err());
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (k, v) = tuple2_from_split(value, ' ', "invalid rtpmap!")?;
+        let mut scanner = Scanner::new(value, "rtpmap");
+        let k = scanner.field()?;
+        let v = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
             key: k.parse()?, 
             value: RtpValue::try_from(v)?,
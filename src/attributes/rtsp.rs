@@ -0,0 +1,235 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Normal play time.
+///
+/// NPT is the absolute position relative to the beginning of the
+/// presentation, either the special constant "now" for live events, or
+/// a time in seconds.  Both the "npt-sec" and the "npt-hhmmss" forms
+/// of [RFC2326](https://datatracker.ietf.org/doc/html/rfc2326#section-3.6)
+/// are accepted, and stored as seconds.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NptTime {
+    Now,
+    Seconds(f64),
+}
+
+impl fmt::Display for NptTime {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert_eq!(format!("{}", NptTime::Now), "now");
+    /// assert_eq!(format!("{}", NptTime::Seconds(34.5)), "34.5");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Now => write!(f, "now"),
+            Self::Seconds(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NptTime {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(NptTime::try_from("now").unwrap(), NptTime::Now);
+    /// assert_eq!(NptTime::try_from("34.5").unwrap(), NptTime::Seconds(34.5));
+    /// assert_eq!(NptTime::try_from("1:02:03.5").unwrap(), NptTime::Seconds(3723.5));
+    /// assert!(NptTime::try_from("inf").is_err());
+    /// assert!(NptTime::try_from("1:2").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if value == "now" {
+            return Ok(Self::Now)
+        }
+
+        ensure!(
+            !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b':'),
+            "invalid npt time!"
+        );
+
+        let mut parts = value.split(':');
+        let seconds = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(s), None, None, None) => s.parse::<f64>()?,
+            (Some(h), Some(m), Some(s), None) => {
+                h.parse::<u64>()? as f64 * 3600.0
+                    + m.parse::<u8>()? as f64 * 60.0
+                    + s.parse::<f64>()?
+            },
+            _ => return Err(anyhow!("invalid npt time!"))
+        };
+
+        Ok(Self::Seconds(seconds))
+    }
+}
+
+/// Name:  range
+/// Value:  range-value
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=range:npt=0-34.357
+///
+/// This attribute specifies the range of the presentation, or of a
+/// single track, that can be requested by an RTSP client
+/// [RFC2326](https://datatracker.ietf.org/doc/html/rfc2326#appendix-C.1.5).
+/// Either end of the range can be open.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Range<'a> {
+    /// normal play time range ("npt=").
+    Npt {
+        start: Option<NptTime>,
+        end: Option<NptTime>,
+    },
+    /// absolute UTC time range ("clock="), e.g. "19961108T142300Z".
+    Clock {
+        start: Option<Cow<'a, str>>,
+        end: Option<Cow<'a, str>>,
+    },
+    /// SMPTE time code range, the format is the unit name, e.g. "smpte",
+    /// "smpte-30-drop" or "smpte-25".
+    Smpte {
+        format: Cow<'a, str>,
+        start: Option<Cow<'a, str>>,
+        end: Option<Cow<'a, str>>,
+    },
+}
+
+impl<'a> Range<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Range<'b> {
+        let owned = |v: Option<Cow<'a, str>>| v.map(|v| Cow::Owned(v.into_owned()));
+        match self {
+            Self::Npt { start, end } => Range::Npt { start, end },
+            Self::Clock { start, end } => Range::Clock {
+                start: owned(start),
+                end: owned(end),
+            },
+            Self::Smpte { format, start, end } => Range::Smpte {
+                format: Cow::Owned(format.into_owned()),
+                start: owned(start),
+                end: owned(end),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Range<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// for temp in [
+    ///     "npt=0-34.357",
+    ///     "npt=now-",
+    ///     "npt=-20",
+    ///     "clock=19961108T142300Z-19961108T143520Z",
+    ///     "smpte-30-drop=10:07:00-",
+    /// ] {
+    ///     assert_eq!(format!("{}", Range::try_from(temp).unwrap()), temp);
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn time<T: fmt::Display>(f: &mut fmt::Formatter<'_>, v: &Option<T>) -> fmt::Result {
+            match v {
+                Some(v) => write!(f, "{}", v),
+                None => Ok(()),
+            }
+        }
+
+        let (start, end) = match self {
+            Self::Npt { start, end } => {
+                write!(f, "npt=")?;
+                time(f, start)?;
+                write!(f, "-")?;
+                return time(f, end)
+            },
+            Self::Clock { start, end } => {
+                write!(f, "clock=")?;
+                (start, end)
+            },
+            Self::Smpte { format, start, end } => {
+                write!(f, "{}=", format)?;
+                (start, end)
+            },
+        };
+
+        time(f, start)?;
+        write!(f, "-")?;
+        time(f, end)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Range<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let range: Range = Range::try_from("npt=0-34.357").unwrap();
+    /// assert_eq!(range, Range::Npt {
+    ///     start: Some(NptTime::Seconds(0.0)),
+    ///     end: Some(NptTime::Seconds(34.357))
+    /// });
+    ///
+    /// let range: Range = Range::try_from("clock=19961108T142300Z-").unwrap();
+    /// assert!(matches!(range, Range::Clock { end: None, .. }));
+    ///
+    /// assert!(Range::try_from("npt=10").is_err());
+    /// assert!(Range::try_from("utc=10-").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (unit, spec) = value
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid range!"))?;
+        let (start, end) = spec
+            .split_once('-')
+            .ok_or_else(|| anyhow!("invalid range!"))?;
+        let time = |v: &'a str| match v.is_empty() {
+            false => Some(Cow::Borrowed(v)),
+            true => None,
+        };
+
+        Ok(match unit {
+            "npt" => Self::Npt {
+                start: time(start).map(|v| NptTime::try_from(&*v)).transpose()?,
+                end: time(end).map(|v| NptTime::try_from(&*v)).transpose()?,
+            },
+            "clock" => Self::Clock {
+                start: time(start),
+                end: time(end),
+            },
+            _ if unit.starts_with("smpte") => Self::Smpte {
+                format: Cow::Borrowed(unit),
+                start: time(start),
+                end: time(end),
+            },
+            _ => return Err(anyhow!("invalid range!"))
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use crate::scanner::Scanner;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// the silence suppression method of "a=silenceSupp:".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppPref {
+    /// the silence suppression of the codec standard.
+    Standard,
+    /// a proprietary silence suppression.
+    Custom,
+}
+
+/// the silence insertion descriptors of "a=silenceSupp:".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidUse {
+    /// no SID packets are sent.
+    NoSid,
+    /// SID packets with the fixed noise level of the fxnslevel field.
+    FixedNoise,
+    /// SID packets with the sampled background noise.
+    SampledNoise,
+}
+
+/// Name:  silenceSupp
+/// Value:  silenceSuppEnable SP silenceTimer SP suppPref SP sidUse SP
+///     fxnslevel
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=silenceSupp:on 200 standard FixedNoise 60
+///
+/// The silence suppression of ATM and PSTN gateways
+/// [RFC3108](https://datatracker.ietf.org/doc/html/rfc3108#section-5.6.3.2).
+/// Every field but the first may be "-", which leaves it unspecified.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "on 200 standard FixedNoise 60";
+/// let silence = SilenceSupp::try_from(temp).unwrap();
+/// assert_eq!(silence, SilenceSupp {
+///     enable: true,
+///     timer: Some(200),
+///     pref: Some(SuppPref::Standard),
+///     sid_use: Some(SidUse::FixedNoise),
+///     fxnslevel: Some(60),
+/// });
+/// assert_eq!(format!("{}", silence), temp);
+///
+/// let silence = SilenceSupp::try_from("off - - - -").unwrap();
+/// assert!(!silence.enable);
+/// assert_eq!(silence.timer, None);
+/// assert_eq!(format!("{}", silence), "off - - - -");
+///
+/// assert!(SilenceSupp::try_from("on 200 standard FixedNoise 128").is_err());
+/// assert!(SilenceSupp::try_from("yes - - - -").is_err());
+/// assert!(SilenceSupp::try_from("on - - -").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SilenceSupp {
+    /// "on" or "off".
+    pub enable: bool,
+    /// the time in milliseconds of silence before suppression starts.
+    pub timer: Option<u32>,
+    pub pref: Option<SuppPref>,
+    pub sid_use: Option<SidUse>,
+    /// the fixed noise level in -dBm0, 0-127.
+    pub fxnslevel: Option<u8>,
+}
+
+/// write an optional field, "-" when unspecified.
+fn field<T: fmt::Display>(f: &mut fmt::Formatter<'_>, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(v) => write!(f, " {}", v),
+        None => write!(f, " -"),
+    }
+}
+
+impl fmt::Display for SuppPref {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Standard => "standard",
+            Self::Custom =>   "custom",
+        })
+    }
+}
+
+impl fmt::Display for SidUse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::NoSid =>        "NoSID",
+            Self::FixedNoise =>   "FixedNoise",
+            Self::SampledNoise => "SampledNoise",
+        })
+    }
+}
+
+impl fmt::Display for SilenceSupp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.enable { "on" } else { "off" })?;
+        field(f, self.timer)?;
+        field(f, self.pref)?;
+        field(f, self.sid_use)?;
+        field(f, self.fxnslevel)
+    }
+}
+
+impl TryFrom<&str> for SuppPref {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "standard" => Ok(Self::Standard),
+            "custom" =>   Ok(Self::Custom),
+            _ => Err(anyhow!("invalid suppPref!")),
+        }
+    }
+}
+
+impl TryFrom<&str> for SidUse {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "NoSID" =>        Ok(Self::NoSid),
+            "FixedNoise" =>   Ok(Self::FixedNoise),
+            "SampledNoise" => Ok(Self::SampledNoise),
+            _ => Err(anyhow!("invalid sidUse!")),
+        }
+    }
+}
+
+impl TryFrom<&str> for SilenceSupp {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "silenceSupp");
+        let mut next = || scanner.field().map(|v| Some(v).filter(|v| *v != "-"));
+
+        let enable = match next()? {
+            Some("on") => true,
+            Some("off") => false,
+            _ => return Err(anyhow!("invalid silenceSupp!")),
+        };
+
+        let timer = next()?.map(str::parse).transpose()?;
+        let pref = next()?.map(SuppPref::try_from).transpose()?;
+        let sid_use = next()?.map(SidUse::try_from).transpose()?;
+        let fxnslevel: Option<u8> = next()?.map(str::parse).transpose()?;
+        ensure!(fxnslevel.map_or(true, |v| v <= 127), "invalid fxnslevel!");
+        scanner.finish()?;
+
+        Ok(Self {
+            enable,
+            timer,
+            pref,
+            sid_use,
+            fxnslevel,
+        })
+    }
+}
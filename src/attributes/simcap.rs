@@ -0,0 +1,210 @@
+//! Simple capability declaration
+//! [RFC3407](https://datatracker.ietf.org/doc/html/rfc3407).
+//!
+//! The capability set starts with a sequence number ("a=sqn:"), each
+//! "a=cdsc:" declares a numbered capability per format, and the
+//! "a=cpar:", "a=cparmin:" and "a=cparmax:" lines following it give the
+//! parameters of those capabilities.
+
+use crate::scanner::Scanner;
+use super::Attributes;
+use crate::Sdp;
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  cdsc
+/// Value:  cap-num SP media SP proto 1*(SP fmt)
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=cdsc: 1 audio RTP/AVP 0 18
+///
+/// Capability description.  The formats are numbered consecutively
+/// from the capability number.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = " 1 audio RTP/AVP 0 18";
+/// let cdsc = Cdsc::try_from(temp).unwrap();
+/// assert_eq!(cdsc.number, 1);
+/// assert_eq!(cdsc.media, "audio");
+/// assert_eq!(cdsc.proto, "RTP/AVP");
+/// assert_eq!(cdsc.fmts, vec!["0", "18"]);
+/// assert_eq!(format!("{}", cdsc), temp);
+///
+/// assert!(Cdsc::try_from(" 0 audio RTP/AVP 0").is_err());
+/// assert!(Cdsc::try_from(" 1 audio RTP/AVP").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cdsc<'a> {
+    /// the number of the first format, 1 to 255.
+    pub number: u8,
+    /// e.g. "audio" or "image".
+    pub media: Cow<'a, str>,
+    /// e.g. "RTP/AVP" or "udptl".
+    pub proto: Cow<'a, str>,
+    pub fmts: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Cdsc<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Cdsc<'b> {
+        Cdsc {
+            number: self.number,
+            media: Cow::Owned(self.media.into_owned()),
+            proto: Cow::Owned(self.proto.into_owned()),
+            fmts: self.fmts
+                .into_iter()
+                .map(|v| Cow::Owned(v.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Cdsc<'_> {
+    /// The value starts with a space, as in the examples of RFC3407.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " {} {} {}", self.number, self.media, self.proto)?;
+        for v in &self.fmts {
+            write!(f, " {}", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Cdsc<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "cdsc");
+        let number = scanner.field()?.parse()?;
+        let media = Cow::Borrowed(scanner.field()?);
+        let proto = Cow::Borrowed(scanner.field()?);
+        let fmts: Vec<_> = scanner.fields().map(Cow::Borrowed).collect();
+        ensure!(number > 0 && !fmts.is_empty(), "invalid cdsc!");
+        Ok(Self {
+            number,
+            media,
+            proto,
+            fmts,
+        })
+    }
+}
+
+/// capability parameter kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CparKind {
+    /// "a=cpar:", the parameter value.
+    Exact,
+    /// "a=cparmin:", the minimum of a numeric parameter.
+    Min,
+    /// "a=cparmax:", the maximum of a numeric parameter.
+    Max,
+}
+
+/// one capability of a simple capability set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capability<'b> {
+    pub number: u8,
+    pub media: &'b str,
+    pub proto: &'b str,
+    pub fmt: &'b str,
+    /// the parameter lines, e.g. "a=fmtp:18 annexb=yes" or "b=AS:64".
+    pub params: Vec<(CparKind, &'b str)>,
+}
+
+/// The simple capability set of a session description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimpleCapabilities<'b> {
+    /// the sequence number of the set, "a=sqn:".
+    pub sqn: Option<u8>,
+    pub capabilities: Vec<Capability<'b>>,
+}
+
+impl<'a> Sdp<'a> {
+    /// the simple capability set declared at session level.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=sqn: 0\r\n\
+    ///     a=cdsc: 1 audio RTP/AVP 0 18\r\n\
+    ///     a=cpar: a=fmtp:18 annexb=yes\r\n\
+    ///     a=cdsc: 3 image udptl t38\r\n\
+    ///     a=cparmax: b=AS:64\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let caps = sdp.simple_capabilities();
+    /// assert_eq!(caps.sqn, Some(0));
+    /// assert_eq!(caps.capabilities.len(), 3);
+    ///
+    /// let g729 = &caps.capabilities[1];
+    /// assert_eq!((g729.number, g729.media, g729.fmt), (2, "audio", "18"));
+    /// assert_eq!(g729.params, vec![(CparKind::Exact, "a=fmtp:18 annexb=yes")]);
+    ///
+    /// let t38 = &caps.capabilities[2];
+    /// assert_eq!((t38.number, t38.proto, t38.fmt), (3, "udptl", "t38"));
+    /// assert_eq!(t38.params, vec![(CparKind::Max, "b=AS:64")]);
+    /// ```
+    pub fn simple_capabilities(&self) -> SimpleCapabilities<'_> {
+        let mut caps = SimpleCapabilities::default();
+        // the capabilities of the last "a=cdsc:", the parameters apply
+        // to all of them.
+        let mut start = 0;
+        for attribute in &self.attributes {
+            let (kind, value) = match &attribute.value {
+                Attributes::Sqn(v) => {
+                    caps.sqn = Some(*v);
+                    continue
+                },
+                Attributes::Cdsc(v) => {
+                    start = caps.capabilities.len();
+                    for (i, fmt) in v.fmts.iter().enumerate() {
+                        caps.capabilities.push(Capability {
+                            number: v.number.saturating_add(i as u8),
+                            media: &v.media,
+                            proto: &v.proto,
+                            fmt,
+                            params: Vec::new(),
+                        });
+                    }
+
+                    continue
+                },
+                Attributes::Cpar(v) => (CparKind::Exact, v),
+                Attributes::CparMin(v) => (CparKind::Min, v),
+                Attributes::CparMax(v) => (CparKind::Max, v),
+                _ => continue,
+            };
+
+            for cap in &mut caps.capabilities[start..] {
+                cap.params.push((kind, value.trim_start()));
+            }
+        }
+
+        caps
+    }
+}
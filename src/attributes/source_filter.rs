@@ -0,0 +1,163 @@
+use crate::scanner::Scanner;
+use crate::{
+    NetKind,
+    AddrKind
+};
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// source filter mode.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    /// only the listed sources are accepted ("incl").
+    Include,
+    /// the listed sources are rejected ("excl").
+    Exclude,
+}
+
+impl fmt::Display for FilterMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Include => "incl",
+            Self::Exclude => "excl",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FilterMode {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "incl" => Ok(Self::Include),
+            "excl" => Ok(Self::Exclude),
+            _ => Err(anyhow!("invalid filter mode!"))
+        }
+    }
+}
+
+/// Name:  source-filter
+/// Value:  filter-mode SP filter-spec
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=source-filter: incl IN IP4 232.3.4.5 192.0.2.10 192.0.2.11
+///
+/// Restricts the sources of the packets sent to the destination address
+/// [RFC4570](https://datatracker.ietf.org/doc/html/rfc4570#section-3),
+/// e.g. the source of a source-specific multicast (SSM) group.  The
+/// destination can be "*" for all connection addresses, and the
+/// addresses can be fully qualified domain names, so they are kept as
+/// text.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFilter<'a> {
+    pub mode: FilterMode,
+    pub nettype: NetKind,
+    /// `None` for "*", i.e. all address types.
+    pub addrtype: Option<AddrKind>,
+    pub dest_address: Cow<'a, str>,
+    pub src_list: Vec<Cow<'a, str>>,
+}
+
+impl<'a> SourceFilter<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> SourceFilter<'b> {
+        SourceFilter {
+            mode: self.mode,
+            nettype: self.nettype,
+            addrtype: self.addrtype,
+            dest_address: Cow::Owned(self.dest_address.into_owned()),
+            src_list: self.src_list
+                .into_iter()
+                .map(|v| Cow::Owned(v.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for SourceFilter<'_> {
+    /// The value starts with a space, as in the examples of RFC4570.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = " incl IN IP4 232.3.4.5 192.0.2.10 192.0.2.11";
+    /// assert_eq!(format!("{}", SourceFilter::try_from(temp).unwrap()), temp);
+    ///
+    /// let temp = " excl IN * * 192.0.2.10";
+    /// assert_eq!(format!("{}", SourceFilter::try_from(temp).unwrap()), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " {} {} ", self.mode, self.nettype)?;
+        match &self.addrtype {
+            Some(v) => write!(f, "{}", v)?,
+            None => write!(f, "*")?,
+        }
+
+        write!(f, " {}", self.dest_address)?;
+        for src in &self.src_list {
+            write!(f, " {}", src)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SourceFilter<'a> {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let filter = SourceFilter::try_from(" incl IN IP4 232.3.4.5 192.0.2.10").unwrap();
+    /// assert_eq!(filter.mode, FilterMode::Include);
+    /// assert_eq!(filter.addrtype, Some(AddrKind::IP4));
+    /// assert_eq!(filter.dest_address, "232.3.4.5");
+    /// assert_eq!(filter.src_list, vec!["192.0.2.10"]);
+    ///
+    /// assert!(SourceFilter::try_from("incl IN IP4 232.3.4.5").is_err());
+    /// assert!(SourceFilter::try_from("only IN IP4 232.3.4.5 192.0.2.10").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "source filter");
+        let mode = FilterMode::try_from(scanner.field()?)?;
+        let nettype = NetKind::try_from(scanner.field()?)?;
+        let addrtype = match scanner.field()? {
+            "*" => None,
+            v => Some(AddrKind::try_from(v)?),
+        };
+
+        let dest_address = Cow::Borrowed(scanner.field()?);
+        let src_list: Vec<_> = scanner.fields().map(Cow::Borrowed).collect();
+        ensure!(!src_list.is_empty(), "invalid source filter!");
+
+        Ok(Self {
+            mode,
+            nettype,
+            addrtype,
+            dest_address,
+            src_list,
+        })
+    }
+}
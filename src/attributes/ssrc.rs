@@ -1,18 +1,35 @@
-use crate::util::tuple2_from_split;
+use super::Attributes;
+use crate::media::Media;
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use alloc::string::String;
 use anyhow::{
     Result,
     anyhow,
 };
 
-use std::{
+use core::{
     convert::TryFrom, 
+    fmt::Write,
     fmt
 };
 
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MsId<'a> {
-    pub id: &'a str,
-    pub appdata: &'a str,
+    pub id: Cow<'a, str>,
+    pub appdata: Cow<'a, str>,
+}
+
+impl<'a> MsId<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> MsId<'b> {
+        MsId {
+            id: Cow::Owned(self.id.into_owned()),
+            appdata: Cow::Owned(self.appdata.into_owned()),
+        }
+    }
 }
 
 impl<'a> fmt::Display for MsId<'a> {
@@ -22,8 +39,8 @@ impl<'a> fmt::Display for MsId<'a> {
     /// use sdp::attributes::*;
     ///
     /// assert_eq!(format!("{}", MsId {
-    ///     id: "6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG",
-    ///     appdata: "43d2eec3-7116-4b29-ad33-466c9358bfb3",
+    ///     id: "6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG".into(),
+    ///     appdata: "43d2eec3-7116-4b29-ad33-466c9358bfb3".into(),
     /// }), "6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG 43d2eec3-7116-4b29-ad33-466c9358bfb3");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -45,21 +62,40 @@ impl<'a> TryFrom<&'a str> for MsId<'a> {
     /// assert_eq!(value.appdata, "43d2eec3-7116-4b29-ad33-466c9358bfb3");
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (k, v) = tuple2_from_split(value, ' ', "invalid msid!")?;
+        let mut scanner = Scanner::new(value, "msid");
+        let k = scanner.field()?;
+        let v = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
-            id: k,
-            appdata: v,
+            id: Cow::Borrowed(k),
+            appdata: Cow::Borrowed(v),
         })
     }
 }
 
-#[derive(Debug)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SsrcAttr<'a> {
-    Cname(&'a str),
+    Cname(Cow<'a, str>),
     PreviousSsrc(u32),
     MsId(MsId<'a>),
-    MsLabel(&'a str),
-    Label(&'a str),
+    MsLabel(Cow<'a, str>),
+    Label(Cow<'a, str>),
+}
+
+impl<'a> SsrcAttr<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> SsrcAttr<'b> {
+        match self {
+            Self::Cname(v) =>           SsrcAttr::Cname(Cow::Owned(v.into_owned())),
+            Self::PreviousSsrc(v) =>    SsrcAttr::PreviousSsrc(v),
+            Self::MsId(v) =>            SsrcAttr::MsId(v.into_owned()),
+            Self::MsLabel(v) =>         SsrcAttr::MsLabel(Cow::Owned(v.into_owned())),
+            Self::Label(v) =>           SsrcAttr::Label(Cow::Owned(v.into_owned())),
+        }
+    }
 }
 
 impl<'a> fmt::Display for SsrcAttr<'a> {
@@ -68,18 +104,18 @@ impl<'a> fmt::Display for SsrcAttr<'a> {
     /// ```
     /// use sdp::attributes::*;
     ///
-    /// assert_eq!(format!("{}", SsrcAttr::Cname("v1SBHP7c76XqYcWx")), "cname:v1SBHP7c76XqYcWx");
-    /// assert_eq!(format!("{}", SsrcAttr::MsLabel("6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG")), "mslabel:6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG");
-    /// assert_eq!(format!("{}", SsrcAttr::Label("43d2eec3-7116-4b29-ad33-466c9358bfb3")), "label:43d2eec3-7116-4b29-ad33-466c9358bfb3");
+    /// assert_eq!(format!("{}", SsrcAttr::Cname("v1SBHP7c76XqYcWx".into())), "cname:v1SBHP7c76XqYcWx");
+    /// assert_eq!(format!("{}", SsrcAttr::MsLabel("6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG".into())), "mslabel:6x9ZxQZqpo19FRr3Q0xsWC2JJ1lVsk2JE0sG");
+    /// assert_eq!(format!("{}", SsrcAttr::Label("43d2eec3-7116-4b29-ad33-466c9358bfb3".into())), "label:43d2eec3-7116-4b29-ad33-466c9358bfb3");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Ok(match self {
-            Self::PreviousSsrc(v) =>    write!(f, "{}:{}", "previous-ssrc", v),
-            Self::Cname(v) =>           write!(f, "{}:{}", "cname", v),
-            Self::MsId(v) =>            write!(f, "{}:{}", "msid", v),
-            Self::MsLabel(v) =>         write!(f, "{}:{}", "mslabel", v),
-            Self::Label(v) =>           write!(f, "{}:{}", "label", v),
-        }?)
+        match self {
+            Self::PreviousSsrc(v) =>    write!(f, "previous-ssrc:{}", v),
+            Self::Cname(v) =>           write!(f, "cname:{}", v),
+            Self::MsId(v) =>            write!(f, "msid:{}", v),
+            Self::MsLabel(v) =>         write!(f, "mslabel:{}", v),
+            Self::Label(v) =>           write!(f, "label:{}", v),
+        }
     }
 }
 
@@ -97,11 +133,13 @@ impl<'a> TryFrom<&'a str> for SsrcAttr<'a> {
     /// }
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (k, v) = tuple2_from_split(value, ':', "invalid ssrc attr!")?;
+        let mut scanner = Scanner::new(value, "ssrc attr");
+        let k = scanner.until(':')?;
+        let v = scanner.rest()?;
         match k {
-            "cname" =>          Ok(Self::Cname(v)),
-            "mslabel" =>        Ok(Self::MsLabel(v)),
-            "label" =>          Ok(Self::Label(v)),
+            "cname" =>          Ok(Self::Cname(Cow::Borrowed(v))),
+            "mslabel" =>        Ok(Self::MsLabel(Cow::Borrowed(v))),
+            "label" =>          Ok(Self::Label(Cow::Borrowed(v))),
             "msid" =>           Ok(Self::MsId(MsId::try_from(v)?)),
             "previous-ssrc" =>  Ok(Self::PreviousSsrc(v.parse()?)),
             _ =>                Err(anyhow!("invalid ssrc attr!")),
@@ -109,12 +147,39 @@ impl<'a> TryFrom<&'a str> for SsrcAttr<'a> {
     }
 }
 
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Ssrc<'a> {
     pub key: u32, 
     pub value: SsrcAttr<'a>,
 }
 
+impl<'a> Ssrc<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Ssrc<'b> {
+        Ssrc {
+            key: self.key,
+            value: self.value.into_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Ssrc<'_> {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "1175220440 cname:v1SBHP7c76XqYcWx";
+    /// assert_eq!(format!("{}", Ssrc::try_from(temp).unwrap()), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.key, self.value)
+    }
+}
+
 impl<'a> TryFrom<&'a str> for Ssrc<'a> {
     type Error = anyhow::Error;
     /// # Unit Test
@@ -129,20 +194,80 @@ impl<'a> TryFrom<&'a str> for Ssrc<'a> {
     /// assert!(Ssrc::try_from("1175220440 name:v1SBHP7c76XqYcWx").is_err());
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let mut iter = value.splitn(2, ' ');
-        let k = iter.next().ok_or_else(|| {
-            anyhow!("invalid ssrc!")
-        })?;
-        
-        let v = iter.next().ok_or_else(|| {
-            anyhow!("invalid ssrc!")
-        })?;
-        
-        println!("{}", value);
-        
+        let mut scanner = Scanner::new(value, "ssrc");
+        let key = scanner.field()?.parse()?;
         Ok(Self {
-            key: k.parse()?, 
-            value: SsrcAttr::try_from(v)?,
+            key,
+            value: SsrcAttr::try_from(scanner.rest()?)?,
         })
     }
 }
+
+impl<'a> Media<'a> {
+    /// rewrite the SSRCs of the media with a mapping of old to new
+    /// SSRC, e.g. when a forwarding server re-originates the streams.
+    ///
+    /// The keys of "a=ssrc:", the "previous-ssrc:" values and the SSRCs
+    /// of "a=ssrc-group:" are rewritten together, so that groups and the
+    /// cname and msid of every stream stay consistent.  SSRCs without a
+    /// mapping are kept.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 RTP/AVP 96 97\r\n\
+    ///     a=ssrc-group:FID 1 2\r\n\
+    ///     a=ssrc:1 cname:a\r\n\
+    ///     a=ssrc:1 msid:stream track\r\n\
+    ///     a=ssrc:2 cname:a\r\n\
+    ///     a=ssrc:3 cname:a\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].rewrite_ssrcs(&[(1, 1001), (2, 1002)]);
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 RTP/AVP 96 97\r\n\
+    ///     a=ssrc-group:FID 1001 1002\r\n\
+    ///     a=ssrc:1001 cname:a\r\n\
+    ///     a=ssrc:1001 msid:stream track\r\n\
+    ///     a=ssrc:1002 cname:a\r\n\
+    ///     a=ssrc:3 cname:a\r\n");
+    /// ```
+    pub fn rewrite_ssrcs(&mut self, mapping: &[(u32, u32)]) {
+        let map = |ssrc: u32| {
+            mapping
+                .iter()
+                .find(|(v, _)| *v == ssrc)
+                .map_or(ssrc, |(_, v)| *v)
+        };
+
+        for attribute in &mut self.attributes {
+            match &mut attribute.value {
+                Attributes::Ssrc(v) => {
+                    v.key = map(v.key);
+                    if let SsrcAttr::PreviousSsrc(previous) = &mut v.value {
+                        *previous = map(*previous);
+                    }
+                }
+                Attributes::Other(k, Some(v)) if k == "ssrc-group" => {
+                    let mut fields = v.split(' ');
+                    let mut group = String::from(fields.next().unwrap_or_default());
+                    for field in fields {
+                        let _ = match field.parse() {
+                            Ok(ssrc) => write!(group, " {}", map(ssrc)),
+                            Err(_) => write!(group, " {}", field),
+                        };
+                    }
+
+                    *v = Cow::Owned(group);
+                }
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use super::Fmtp;
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// ST 2110-20 packing mode ("PM=").
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackingMode {
+    /// "2110GPM", general packing mode.
+    General,
+    /// "2110BPM", block packing mode.
+    Block,
+}
+
+impl fmt::Display for PackingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::General => "2110GPM",
+            Self::Block =>   "2110BPM",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PackingMode {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "2110GPM" => Ok(Self::General),
+            "2110BPM" => Ok(Self::Block),
+            _ => Err(anyhow!("invalid packing mode!"))
+        }
+    }
+}
+
+/// ST 2110-21 sender type ("TP=").
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SenderType {
+    /// "2110TPN", narrow gapped sender.
+    Narrow,
+    /// "2110TPNL", narrow linear sender.
+    NarrowLinear,
+    /// "2110TPW", wide sender.
+    Wide,
+}
+
+impl fmt::Display for SenderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Narrow =>       "2110TPN",
+            Self::NarrowLinear => "2110TPNL",
+            Self::Wide =>         "2110TPW",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SenderType {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "2110TPN" =>  Ok(Self::Narrow),
+            "2110TPNL" => Ok(Self::NarrowLinear),
+            "2110TPW" =>  Ok(Self::Wide),
+            _ => Err(anyhow!("invalid sender type!"))
+        }
+    }
+}
+
+/// SMPTE ST 2110-20 uncompressed video format parameters.
+///
+/// Typed view of the "a=fmtp:" parameters of a "raw" video stream, as
+/// specified in ST 2110-20 section 7.  Parameters this crate does not
+/// type stay available in the underlying [`Fmtp`].
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let fmtp = Fmtp::try_from(
+///     "112 sampling=YCbCr-4:2:2; width=1920; height=1080; exactframerate=30000/1001; \
+///     depth=10; TCS=SDR; colorimetry=BT709; PM=2110GPM; SSN=ST2110-20:2017; TP=2110TPN; interlace;"
+/// ).unwrap();
+///
+/// let video = St2110Video::try_from(&fmtp).unwrap();
+/// assert_eq!(video.sampling, "YCbCr-4:2:2");
+/// assert_eq!((video.width, video.height), (1920, 1080));
+/// assert_eq!(video.exactframerate, (30000, 1001));
+/// assert_eq!(video.depth, 10);
+/// assert_eq!(video.colorimetry, "BT709");
+/// assert_eq!(video.pm, PackingMode::General);
+/// assert_eq!(video.tp, SenderType::Narrow);
+/// assert_eq!(video.ssn.as_deref(), Some("ST2110-20:2017"));
+/// assert_eq!(video.tcs.as_deref(), Some("SDR"));
+/// assert!(video.interlace);
+/// assert!(!video.segmented);
+///
+/// let fmtp = Fmtp::try_from("112 sampling=YCbCr-4:2:2; width=1920").unwrap();
+/// assert!(St2110Video::try_from(&fmtp).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct St2110Video<'a> {
+    /// color difference sampling, e.g. "YCbCr-4:2:2".
+    pub sampling: Cow<'a, str>,
+    pub width: u32,
+    pub height: u32,
+    /// exact frame rate as numerator and denominator, "25" is 25/1.
+    pub exactframerate: (u32, u32),
+    /// bit depth of the samples.
+    pub depth: u8,
+    /// e.g. "BT709" or "BT2020".
+    pub colorimetry: Cow<'a, str>,
+    pub pm: PackingMode,
+    pub tp: SenderType,
+    /// SMPTE standard number, e.g. "ST2110-20:2017".
+    pub ssn: Option<Cow<'a, str>>,
+    /// transfer characteristic system, e.g. "SDR" or "PQ".
+    pub tcs: Option<Cow<'a, str>>,
+    /// signal range, e.g. "NARROW" or "FULL".
+    pub range: Option<Cow<'a, str>>,
+    pub interlace: bool,
+    /// progressive segmented frames, only with interlace.
+    pub segmented: bool,
+}
+
+impl<'a> St2110Video<'a> {
+    /// the format parameters required by ST 2110-20.
+    pub const REQUIRED: [&'static str; 8] = [
+        "sampling",
+        "width",
+        "height",
+        "exactframerate",
+        "depth",
+        "colorimetry",
+        "PM",
+        "TP",
+    ];
+}
+
+impl<'a, 'b> TryFrom<&'a Fmtp<'b>> for St2110Video<'a> {
+    type Error = anyhow::Error;
+    fn try_from(fmtp: &'a Fmtp<'b>) -> Result<Self, Self::Error> {
+        let get = |name: &str| fmtp
+            .get(name)
+            .flatten()
+            .ok_or_else(|| anyhow!("missing st2110 parameter {}!", name));
+        let exactframerate = get("exactframerate")?;
+        let exactframerate = match exactframerate.split_once('/') {
+            Some((n, d)) => (n.parse()?, d.parse()?),
+            None => (exactframerate.parse()?, 1),
+        };
+
+        Ok(Self {
+            sampling: Cow::Borrowed(get("sampling")?),
+            width: get("width")?.parse()?,
+            height: get("height")?.parse()?,
+            exactframerate,
+            depth: get("depth")?.parse()?,
+            colorimetry: Cow::Borrowed(get("colorimetry")?),
+            pm: PackingMode::try_from(get("PM")?)?,
+            tp: SenderType::try_from(get("TP")?)?,
+            ssn: get("SSN").ok().map(Cow::Borrowed),
+            tcs: get("TCS").ok().map(Cow::Borrowed),
+            range: get("RANGE").ok().map(Cow::Borrowed),
+            interlace: fmtp.get("interlace").is_some(),
+            segmented: fmtp.get("segmented").is_some(),
+        })
+    }
+}
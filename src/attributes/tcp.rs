@@ -0,0 +1,136 @@
+use anyhow::{
+    Result,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  setup
+/// Value:  role
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=setup:actpass
+///
+/// Which endpoint initiates the connection
+/// [RFC4145](https://datatracker.ietf.org/doc/html/rfc4145#section-4),
+/// of TCP media, or the DTLS association of DTLS-SRTP media.  The
+/// default is "active".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setup {
+    /// the endpoint initiates the connection.
+    Active,
+    /// the endpoint accepts the connection.
+    Passive,
+    /// the endpoint is willing to do either, offers only.
+    Actpass,
+    /// the endpoint does not want the connection established for now.
+    Holdconn,
+}
+
+impl fmt::Display for Setup {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert_eq!(format!("{}", Setup::Actpass), "actpass");
+    /// assert_eq!(format!("{}", Setup::Holdconn), "holdconn");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Active =>   "active",
+            Self::Passive =>  "passive",
+            Self::Actpass =>  "actpass",
+            Self::Holdconn => "holdconn",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Setup {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(Setup::try_from("active").unwrap(), Setup::Active);
+    /// assert_eq!(Setup::try_from("passive").unwrap(), Setup::Passive);
+    /// assert!(Setup::try_from("Active").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "active" =>   Ok(Self::Active),
+            "passive" =>  Ok(Self::Passive),
+            "actpass" =>  Ok(Self::Actpass),
+            "holdconn" => Ok(Self::Holdconn),
+            _ => Err(anyhow!("invalid setup!"))
+        }
+    }
+}
+
+/// Name:  connection
+/// Value:  conn-value
+/// Usage Level:  session, media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=connection:new
+///
+/// Whether the TCP media uses a new connection or reuses the existing
+/// one
+/// [RFC4145](https://datatracker.ietf.org/doc/html/rfc4145#section-5),
+/// e.g. after a re-offer that only changes the codecs.  The default is
+/// "new".
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TcpConnection {
+    New,
+    Existing,
+}
+
+impl fmt::Display for TcpConnection {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert_eq!(format!("{}", TcpConnection::New), "new");
+    /// assert_eq!(format!("{}", TcpConnection::Existing), "existing");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::New =>      "new",
+            Self::Existing => "existing",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TcpConnection {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(TcpConnection::try_from("new").unwrap(), TcpConnection::New);
+    /// assert_eq!(TcpConnection::try_from("existing").unwrap(), TcpConnection::Existing);
+    /// assert!(TcpConnection::try_from("old").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "new" =>      Ok(Self::New),
+            "existing" => Ok(Self::Existing),
+            _ => Err(anyhow!("invalid connection!"))
+        }
+    }
+}
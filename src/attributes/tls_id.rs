@@ -0,0 +1,84 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  tls-id
+/// Value:  tls-id-value
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=tls-id:abc3de65cddef001be82
+///
+/// Identifies the DTLS or TLS association of the media
+/// [RFC8842](https://datatracker.ietf.org/doc/html/rfc8842#section-4),
+/// a new value in a re-offer requests a new association, while the same
+/// value keeps the existing one.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let id = TlsId::try_from("abc3de65cddef001be82").unwrap();
+/// assert_eq!(format!("{}", id), "abc3de65cddef001be82");
+///
+/// assert!(TlsId::try_from("abc3de65").is_err());
+/// assert!(TlsId::try_from("abc3de65cddef001be82!").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsId<'a>(pub Cow<'a, str>);
+
+impl<'a> TlsId<'a> {
+    /// whether a new DTLS association is required when this identifier
+    /// replaces the previous one.  An endpoint that did not send a
+    /// tls-id before gets a new association as well.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let old = TlsId::try_from("abc3de65cddef001be82").unwrap();
+    /// let new = TlsId::try_from("def3de65cddef001be82").unwrap();
+    /// assert!(!old.requires_new_association(Some(&old)));
+    /// assert!(new.requires_new_association(Some(&old)));
+    /// assert!(new.requires_new_association(None));
+    /// ```
+    pub fn requires_new_association(&self, previous: Option<&TlsId<'_>>) -> bool {
+        previous.map(|v| v.0 != self.0).unwrap_or(true)
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> TlsId<'b> {
+        TlsId(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl fmt::Display for TlsId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TlsId<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        ensure!((20..=120).contains(&value.len()), "invalid tls id!");
+        ensure!(value.bytes().all(|c| {
+            c.is_ascii_alphanumeric() || matches!(c, b'+' | b'/' | b'-' | b'_')
+        }), "invalid tls id!");
+        Ok(Self(Cow::Borrowed(value)))
+    }
+}
@@ -0,0 +1,89 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  tool
+/// Value:  tool-value
+/// Usage Level:  session
+/// Charset Dependent:  no
+///
+/// Syntax:
+/// tool-value = tool-name-and-version
+/// tool-name-and-version = byte-string
+///
+/// Example:
+/// a=tool:foo 1.2
+///
+/// The name and version number of the tool used to create the session
+/// description.  The version is the last word of the value if it starts
+/// with a digit, names can contain spaces.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let tool = Tool::try_from("GStreamer SDP 1.22.0").unwrap();
+/// assert_eq!(tool.name, "GStreamer SDP");
+/// assert_eq!(tool.version.as_deref(), Some("1.22.0"));
+/// assert_eq!(format!("{}", tool), "GStreamer SDP 1.22.0");
+///
+/// let tool = Tool::try_from("libavformat").unwrap();
+/// assert_eq!(tool.name, "libavformat");
+/// assert_eq!(tool.version, None);
+///
+/// assert!(Tool::try_from("").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tool<'a> {
+    pub name: Cow<'a, str>,
+    pub version: Option<Cow<'a, str>>,
+}
+
+impl<'a> Tool<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Tool<'b> {
+        Tool {
+            name: Cow::Owned(self.name.into_owned()),
+            version: self.version.map(|v| Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Tool<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(v) = &self.version {
+            write!(f, " {}", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Tool<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        ensure!(!value.is_empty(), "invalid tool!");
+        Ok(match value.rsplit_once(' ') {
+            Some((name, version)) if version.starts_with(|c: char| c.is_ascii_digit()) => Self {
+                name: Cow::Borrowed(name),
+                version: Some(Cow::Borrowed(version)),
+            },
+            _ => Self {
+                name: Cow::Borrowed(value),
+                version: None,
+            }
+        })
+    }
+}
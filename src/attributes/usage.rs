@@ -0,0 +1,151 @@
+use super::Attributes;
+use core::fmt;
+
+/// Attribute usage level.
+///
+/// Where an attribute may appear, as given by the "Usage Level" of its
+/// registration
+/// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-6).
+/// Source level attributes such as "a=ssrc:" are media level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageLevel {
+    /// only before the first "m=" line.
+    Session,
+    /// only in media descriptions.
+    Media,
+    /// at session and media level.
+    Both,
+}
+
+impl UsageLevel {
+    /// whether the attribute may appear in a media description
+    /// (`media`), or at session level.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    ///
+    /// assert!(UsageLevel::Session.allows(false));
+    /// assert!(!UsageLevel::Session.allows(true));
+    /// assert!(UsageLevel::Both.allows(true));
+    /// ```
+    pub fn allows(&self, media: bool) -> bool {
+        match self {
+            Self::Session => !media,
+            Self::Media => media,
+            Self::Both => true,
+        }
+    }
+}
+
+impl fmt::Display for UsageLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Session => "session",
+            Self::Media =>   "media",
+            Self::Both =>    "session and media",
+        })
+    }
+}
+
+impl Attributes<'_> {
+    /// the usage level of the attribute, unknown attributes can appear
+    /// at both levels.
+    ///
+    /// The match is exhaustive on purpose, so that every new typed
+    /// attribute is given a level.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// assert_eq!(Attributes::try_from("orient:portrait").unwrap().usage(), UsageLevel::Media);
+    /// assert_eq!(Attributes::try_from("group:BUNDLE 0").unwrap().usage(), UsageLevel::Session);
+    /// assert_eq!(Attributes::try_from("setup:actpass").unwrap().usage(), UsageLevel::Both);
+    /// assert_eq!(Attributes::try_from("x-panda:1").unwrap().usage(), UsageLevel::Both);
+    /// ```
+    pub fn usage(&self) -> UsageLevel {
+        match self {
+            Self::Charset(_)
+            | Self::Kind(_)
+            | Self::Identity(_)
+            | Self::Group(_)
+            | Self::Tool(_)
+            | Self::IcePacing(_) => UsageLevel::Session,
+            #[cfg(feature = "legacy")]
+            Self::Sqn(_)
+            | Self::Keywds(_)
+            | Self::Cat(_) => UsageLevel::Session,
+            Self::Ptime(_)
+            | Self::MaxPtime(_)
+            | Self::Rtpmap(_)
+            | Self::Fmtp(_)
+            | Self::Orient(_)
+            | Self::Framerate(_)
+            | Self::Quality(_)
+            | Self::Mid(_)
+            | Self::Ssrc(_)
+            | Self::ZrtpHash(_)
+            | Self::Pcfg(_)
+            | Self::Acfg(_)
+            | Self::Label(_)
+            | Self::Content(_)
+            | Self::TlsId(_)
+            | Self::BundleOnly
+            | Self::RtcpMuxOnly
+            | Self::IceMismatch => UsageLevel::Media,
+            #[cfg(feature = "legacy")]
+            Self::SilenceSupp(_) => UsageLevel::Media,
+            #[cfg(feature = "rtsp")]
+            Self::RtpTime(_)
+            | Self::Framesize(_)
+            | Self::AdaptationSupport(_)
+            | Self::PreDecBufSize(_)
+            | Self::InitPreDecBufPeriod(_)
+            | Self::InitPostDecBufPeriod(_)
+            | Self::DecByteRate(_) => UsageLevel::Media,
+            #[cfg(feature = "msrp")]
+            Self::Path(_)
+            | Self::AcceptTypes(_)
+            | Self::AcceptWrappedTypes(_)
+            | Self::MaxSize(_)
+            | Self::FileSelector(_)
+            | Self::FileTransferId(_)
+            | Self::FileDisposition(_)
+            | Self::FileDate(_)
+            | Self::FileIcon(_) => UsageLevel::Media,
+            #[cfg(feature = "webrtc")]
+            Self::XGoogleFlag(_) => UsageLevel::Media,
+            Self::SdpLang(_)
+            | Self::Lang(_)
+            | Self::Recvonly(_)
+            | Self::Sendrecv(_)
+            | Self::Sendonly(_)
+            | Self::Inactive(_)
+            | Self::Extmap(_)
+            | Self::RtcpXr(_)
+            | Self::Tcap(_)
+            | Self::Acap(_)
+            | Self::Setup(_)
+            | Self::Connection(_)
+            | Self::Fingerprint(_)
+            | Self::MaxPrate(_)
+            | Self::Other(..) => UsageLevel::Both,
+            #[cfg(feature = "legacy")]
+            Self::Cdsc(_)
+            | Self::Cpar(_)
+            | Self::CparMin(_)
+            | Self::CparMax(_) => UsageLevel::Both,
+            #[cfg(feature = "rtsp")]
+            Self::Control(_)
+            | Self::Range(_) => UsageLevel::Both,
+            #[cfg(feature = "broadcast")]
+            Self::TsRefClk(_)
+            | Self::MediaClk(_)
+            | Self::SourceFilter(_) => UsageLevel::Both,
+        }
+    }
+}
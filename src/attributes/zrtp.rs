@@ -0,0 +1,91 @@
+use crate::scanner::Scanner;
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Name:  zrtp-hash
+/// Value:  zrtp-version SP zrtp-hash-value
+/// Usage Level:  media
+/// Charset Dependent:  no
+///
+/// Example:
+/// a=zrtp-hash:1.10 fe30efd02423cb054e50efd0248742ac7a52c8f91bc2df881ae642c371ba46df
+///
+/// The ZRTP protocol version and the SHA-256 hash of the Hello message
+/// of the endpoint
+/// [RFC6189](https://datatracker.ietf.org/doc/html/rfc6189#section-8.1),
+/// which binds the ZRTP key agreement to the signaling.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::*;
+/// use std::convert::*;
+///
+/// let temp = "1.10 fe30efd02423cb054e50efd0248742ac7a52c8f91bc2df881ae642c371ba46df";
+/// let zrtp = ZrtpHash::try_from(temp).unwrap();
+/// assert_eq!(zrtp.version, "1.10");
+/// assert_eq!(zrtp.hash[..4], [0xfe, 0x30, 0xef, 0xd0]);
+/// assert_eq!(format!("{}", zrtp), temp);
+///
+/// assert!(ZrtpHash::try_from("1.10 fe30").is_err());
+/// assert!(ZrtpHash::try_from("1.10").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZrtpHash<'a> {
+    /// e.g. "1.10".
+    pub version: Cow<'a, str>,
+    /// SHA-256 hash of the Hello message.
+    pub hash: [u8; 32],
+}
+
+impl<'a> ZrtpHash<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> ZrtpHash<'b> {
+        ZrtpHash {
+            version: Cow::Owned(self.version.into_owned()),
+            hash: self.hash,
+        }
+    }
+}
+
+impl fmt::Display for ZrtpHash<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.version)?;
+        for b in self.hash {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ZrtpHash<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "zrtp hash");
+        let version = scanner.field()?;
+        let hex = scanner.field()?;
+        scanner.finish()?;
+        ensure!(hex.len() == 64 && hex.is_ascii(), "invalid zrtp hash!");
+
+        let mut hash = [0u8; 32];
+        for (i, b) in hash.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)?;
+        }
+
+        Ok(Self {
+            version: Cow::Borrowed(version),
+            hash,
+        })
+    }
+}
@@ -1,15 +1,25 @@
-use super::util::tuple2_from_split;
+use super::attributes::Attributes;
+use super::media::Media;
+use super::scanner::Scanner;
 use anyhow::anyhow;
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
 /// Bandwidth Kind
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BwKind {
     CT,
-    AS
+    AS,
+    /// Transport Independent Application Specific maximum, the bitrate
+    /// of the media alone in bits per second
+    /// [RFC3890](https://datatracker.ietf.org/doc/html/rfc3890#section-6.2).
+    TIAS
 }
 
 /// Bandwidth
@@ -18,7 +28,9 @@ pub enum BwKind {
 /// session or media.  The <bwtype> is an alphanumeric modifier giving
 /// the meaning of the <bandwidth> figure.  Two values are defined in
 /// this specification
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bandwidth {
     /// CT If the bandwidth of a session or media in a session is different
     /// from the bandwidth implicit from the scope, a "b=CT:..." line
@@ -39,6 +51,106 @@ pub struct Bandwidth {
     pub bandwidth: usize
 }
 
+/// IPv4, UDP and RTP header bytes of a packet.
+pub const IPV4_UDP_RTP_OVERHEAD: u64 = 40;
+/// IPv6, UDP and RTP header bytes of a packet.
+pub const IPV6_UDP_RTP_OVERHEAD: u64 = 60;
+/// packets per second assumed when the packet rate is unknown, one
+/// packet every 20 ms.
+pub const DEFAULT_PACKET_RATE: f64 = 50.0;
+
+/// the header bits per second of a packet rate, rounded up.
+fn overhead_bps(packet_rate: f64, overhead: u64) -> u64 {
+    let bits = (overhead * 8) as f64 * packet_rate;
+    let whole = bits as u64;
+    match (whole as f64) < bits {
+        true => whole + 1,
+        false => whole,
+    }
+}
+
+/// the "b=AS:" kilobits per second of a "b=TIAS:" bitrate, adding the
+/// header overhead of every packet
+/// [RFC3890](https://datatracker.ietf.org/doc/html/rfc3890#section-6.5).
+///
+/// `packet_rate` is in packets per second, e.g. "a=maxprate:" or
+/// [`DEFAULT_PACKET_RATE`], and `overhead` the header bytes of a packet.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::bandwidth::*;
+///
+/// // 64 kbps with 50 packets of 40 header bytes, 16 kbps overhead.
+/// assert_eq!(tias_to_as(64000, 50.0, IPV4_UDP_RTP_OVERHEAD), 80);
+/// assert_eq!(tias_to_as(64001, 50.0, IPV4_UDP_RTP_OVERHEAD), 81);
+/// assert_eq!(as_to_tias(80, 50.0, IPV4_UDP_RTP_OVERHEAD), 64000);
+/// assert_eq!(as_to_tias(10, 50.0, IPV6_UDP_RTP_OVERHEAD), 0);
+/// ```
+pub fn tias_to_as(tias: u64, packet_rate: f64, overhead: u64) -> u64 {
+    tias.saturating_add(overhead_bps(packet_rate, overhead)).div_ceil(1000)
+}
+
+/// the "b=TIAS:" bits per second of a "b=AS:" bandwidth, removing the
+/// header overhead of every packet, see [`tias_to_as`].
+pub fn as_to_tias(bandwidth: u64, packet_rate: f64, overhead: u64) -> u64 {
+    bandwidth
+        .saturating_mul(1000)
+        .saturating_sub(overhead_bps(packet_rate, overhead))
+}
+
+impl<'a> Media<'a> {
+    /// the maximum media bitrate in bits per second, from "b=TIAS:" and
+    /// "b=AS:", the lower one if both are given.
+    ///
+    /// "b=AS:" includes the IP, UDP and RTP headers, which are removed
+    /// with the IPv4 overhead at the "a=maxprate:" packet rate of the
+    /// media, or [`DEFAULT_PACKET_RATE`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     b=AS:2000\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     b=AS:512\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     b=TIAS:256000\r\n\
+    ///     b=AS:512\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     b=AS:80\r\n\
+    ///     a=maxprate:12.5\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.bandwidth.len(), 1);
+    /// let bitrates = sdp.medias.iter().map(|m| m.max_bitrate()).collect::<Vec<_>>();
+    /// assert_eq!(bitrates, vec![Some(496000), Some(256000), Some(76000), None]);
+    /// assert_eq!(sdp.to_string(), temp);
+    /// ```
+    pub fn max_bitrate(&self) -> Option<u64> {
+        let packet_rate = self.attributes
+            .iter()
+            .find_map(|a| match a.value {
+                Attributes::MaxPrate(v) => Some(v),
+                _ => None,
+            })
+            .unwrap_or(DEFAULT_PACKET_RATE);
+        self.bandwidth
+            .iter()
+            .filter_map(|v| match v.bwtype {
+                BwKind::TIAS => Some(v.bandwidth as u64),
+                BwKind::AS => Some(as_to_tias(v.bandwidth as u64, packet_rate, IPV4_UDP_RTP_OVERHEAD)),
+                _ => None,
+            })
+            .min()
+    }
+}
+
 impl fmt::Display for Bandwidth {
     /// # Unit Test
     ///
@@ -78,7 +190,10 @@ impl<'a> TryFrom<&'a str> for Bandwidth {
     /// assert_eq!(instance.bandwidth, 128);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (t, w) = tuple2_from_split(value, ':', "invalid band width!")?;
+        let mut scanner = Scanner::new(value, "bandwidth");
+        let t = scanner.until(':')?;
+        let w = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
             bwtype: BwKind::try_from(t)?,
             bandwidth: w.parse()?,
@@ -86,6 +201,13 @@ impl<'a> TryFrom<&'a str> for Bandwidth {
     }
 }
 
+impl FromStr for Bandwidth {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
+
 impl fmt::Display for BwKind {
     /// # Unit Test
     ///
@@ -97,7 +219,8 @@ impl fmt::Display for BwKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", match self {
             Self::CT => "CT",
-            Self::AS => "AS"
+            Self::AS => "AS",
+            Self::TIAS => "TIAS"
         })
     }
 }
@@ -117,6 +240,7 @@ impl<'a> TryFrom<&'a str> for BwKind {
         match value {
             "CT" => Ok(Self::CT),
             "AS" => Ok(Self::AS),
+            "TIAS" => Ok(Self::TIAS),
             _ => Err(anyhow!("invalid band width type!"))
         }
     }
@@ -0,0 +1,111 @@
+//! BUNDLE transport resolution.
+//!
+//! The media of a BUNDLE group share the transport of one media
+//! description, the one tagged by the first identification tag of the
+//! group
+//! ([RFC9143](https://datatracker.ietf.org/doc/html/rfc9143#section-7.2)),
+//! so the ICE and DTLS attributes of a bundled media are looked up in
+//! that media description.
+
+use super::attributes::{
+    Attributes,
+    Group,
+    Mid,
+    Semantics
+};
+
+use super::Sdp;
+use alloc::vec::Vec;
+
+/// the transport of a bundled media.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleTransport<'b, 'a> {
+    pub mid: &'b Mid<'a>,
+    /// index of the media description.
+    pub media: usize,
+    /// index of the media description that owns the transport.
+    pub transport: usize,
+}
+
+impl<'a> Sdp<'a> {
+    /// the "a=group:BUNDLE" groups.
+    fn bundle_groups(&self) -> impl Iterator<Item = &Group<'a>> {
+        self.attributes.iter().filter_map(|a| match &a.value {
+            Attributes::Group(v) if v.semantics == Semantics::Bundle => Some(v),
+            _ => None,
+        })
+    }
+
+    /// the transport of every bundled media.
+    ///
+    /// The transport is owned by the first media of the group that is
+    /// neither rejected nor "a=bundle-only".  Mids without a media
+    /// description are skipped.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE a v d\r\n\
+    ///     m=audio 0 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:a\r\n\
+    ///     a=bundle-only\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:v\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=mid:x\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let transports = sdp.bundle_transports()
+    ///     .iter()
+    ///     .map(|t| (t.mid.to_string(), t.media, t.transport))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(transports, vec![
+    ///     ("a".to_string(), 0, 1),
+    ///     ("v".to_string(), 1, 1),
+    /// ]);
+    ///
+    /// assert_eq!(sdp.transport_media(0), 1);
+    /// assert_eq!(sdp.transport_media(2), 2);
+    /// ```
+    pub fn bundle_transports(&self) -> Vec<BundleTransport<'_, 'a>> {
+        let mut transports = Vec::new();
+        for group in self.bundle_groups() {
+            let medias = group.mids
+                .iter()
+                .filter_map(|mid| Some((mid, self.media_index(mid)?)));
+            let owner = medias.clone().find(|(_, i)| {
+                let media = &self.medias[*i];
+                media.port.num != 0 && !media.attributes
+                    .iter()
+                    .any(|a| matches!(a.value, Attributes::BundleOnly))
+            });
+
+            if let Some((_, transport)) = owner {
+                transports.extend(medias.map(|(mid, media)| BundleTransport {
+                    mid,
+                    media,
+                    transport,
+                }));
+            }
+        }
+
+        transports
+    }
+
+    /// the index of the media description that owns the transport of
+    /// the given media, the media itself when it is not bundled.
+    pub fn transport_media(&self, index: usize) -> usize {
+        self.bundle_transports()
+            .iter()
+            .find(|t| t.media == index)
+            .map(|t| t.transport)
+            .unwrap_or(index)
+    }
+}
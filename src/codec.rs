@@ -0,0 +1,412 @@
+//! Per payload type codec views.
+//!
+//! A codec is described by up to three kinds of media attributes,
+//! "a=rtpmap:", "a=fmtp:" and "a=rtcp-fb:", all keyed by the payload
+//! type.  [`Media::codec_infos`] joins them into one [`CodecInfo`] per
+//! payload type of the "m=" line, and [`Media::prefer_codecs`] moves them
+//! together when the payload types are reordered.
+
+use super::attributes::{
+    Attribute,
+    Attributes,
+    RtpValue
+};
+
+use super::media::Media;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::format;
+use alloc::vec::Vec;
+
+/// the encoding, format parameters and RTCP feedback of a payload type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecInfo<'s> {
+    pub pt: u8,
+    /// the encoding from "a=rtpmap:", or of the static payload type,
+    /// see [`Media::codec`].
+    pub codec: Option<RtpValue<'s>>,
+    /// the "a=fmtp:" parameters in order, names and values are trimmed.
+    pub params: Vec<(&'s str, Option<&'s str>)>,
+    /// the "a=rtcp-fb:" values of the payload type and of the "*"
+    /// wildcard, e.g. "nack pli".
+    pub feedback: Vec<&'s str>,
+}
+
+impl<'s> CodecInfo<'s> {
+    /// get format parameter by name.
+    pub fn param(&self, name: &str) -> Option<Option<&'s str>> {
+        self.params
+            .iter()
+            .find(|(k, _)| *k == name)
+            .map(|(_, v)| *v)
+    }
+
+    /// whether the payload type has the given RTCP feedback, e.g.
+    /// "nack" or "nack pli".
+    pub fn has_feedback(&self, feedback: &str) -> bool {
+        self.feedback.contains(&feedback)
+    }
+}
+
+/// the payload type of a codec attribute, "a=rtpmap:", "a=fmtp:" or
+/// "a=rtcp-fb:" with a payload type.
+fn payload_type(attribute: &Attributes<'_>) -> Option<u8> {
+    match attribute {
+        Attributes::Rtpmap(v) => Some(v.key),
+        Attributes::Fmtp(v) => Some(v.key),
+        Attributes::Other(k, Some(v)) if k == "rtcp-fb" => v.split(' ').next()?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// the payload type, or "*", and the value of an "a=rtcp-fb:" attribute.
+fn feedback<'s>(attribute: &'s Attributes<'_>) -> Option<(&'s str, &'s str)> {
+    match attribute {
+        Attributes::Other(k, Some(v)) if k == "rtcp-fb" => v.split_once(' '),
+        _ => None,
+    }
+}
+
+/// an "a=rtcp-fb:" attribute for a payload type, or "*".
+fn rtcp_fb<'a>(key: &str, value: &str) -> Attribute<'a> {
+    let value = format!("{} {}", key, value);
+    Attributes::Other(Cow::Borrowed("rtcp-fb"), Some(Cow::Owned(value))).into()
+}
+
+impl<'a> Media<'a> {
+    /// the codec view of a payload type, `None` if the payload type is
+    /// not in the "m=" line.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtcp-fb:* nack\r\n\
+    ///     a=rtcp-fb:96 nack pli\r\n\
+    ///     a=rtcp-fb:96 goog-remb\r\n\
+    ///     a=rtpmap:97 rtx/90000\r\n\
+    ///     a=fmtp:97 apt=96\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &sdp.medias[0];
+    ///
+    /// let vp8 = media.codec_info(96).unwrap();
+    /// assert_eq!(vp8.codec.as_ref().unwrap().codec, "VP8");
+    /// assert!(vp8.params.is_empty());
+    /// assert_eq!(vp8.feedback, vec!["nack", "nack pli", "goog-remb"]);
+    /// assert!(vp8.has_feedback("nack pli"));
+    ///
+    /// let rtx = media.codec_info(97).unwrap();
+    /// assert_eq!(rtx.param("apt"), Some(Some("96")));
+    /// assert_eq!(rtx.feedback, vec!["nack"]);
+    ///
+    /// assert!(media.codec_info(98).is_none());
+    /// assert_eq!(media.codec_infos().len(), 2);
+    /// ```
+    pub fn codec_info(&self, pt: u8) -> Option<CodecInfo<'_>> {
+        if !self.fmts.contains(&pt) {
+            return None;
+        }
+
+        let mut info = CodecInfo {
+            pt,
+            codec: self.codec(pt),
+            params: Vec::new(),
+            feedback: Vec::new(),
+        };
+
+        for attribute in &self.attributes {
+            match &attribute.value {
+                Attributes::Fmtp(v) if v.key == pt => info.params.extend(
+                    v.values
+                        .iter()
+                        .filter(|(k, _)| !k.trim().is_empty())
+                        .map(|(k, v)| (k.trim(), v.as_deref().map(str::trim))),
+                ),
+                Attributes::Other(k, Some(v)) if k == "rtcp-fb" => {
+                    let Some((key, value)) = v.split_once(' ') else {
+                        continue;
+                    };
+
+                    if key == "*" || key.parse() == Ok(pt) {
+                        info.feedback.push(value);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Some(info)
+    }
+
+    /// the codec views of the payload types of the "m=" line, in order.
+    pub fn codec_infos(&self) -> Vec<CodecInfo<'_>> {
+        self.fmts
+            .iter()
+            .filter_map(|pt| self.codec_info(*pt))
+            .collect()
+    }
+
+    /// replace every "a=rtcp-fb:*" wildcard with one attribute per
+    /// payload type of the "m=" line, unless the payload type already
+    /// has the same feedback.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 98\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtpmap:98 VP9/90000\r\n\
+    ///     a=rtcp-fb:98 nack\r\n\
+    ///     a=rtcp-fb:* nack\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].expand_feedback_wildcards();
+    ///
+    /// let feedback = sdp.medias[0].attributes[2..].iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(feedback, ["rtcp-fb:98 nack", "rtcp-fb:96 nack"]);
+    /// ```
+    pub fn expand_feedback_wildcards(&mut self) {
+        let attributes = core::mem::take(&mut self.attributes);
+        let exists = |pt: u8, value: &str| attributes
+            .iter()
+            .filter_map(|a| feedback(&a.value))
+            .any(|(k, v)| v == value && k.parse() == Ok(pt));
+        for attribute in &attributes {
+            match feedback(&attribute.value) {
+                Some(("*", value)) => self.attributes.extend(
+                    self.fmts
+                        .iter()
+                        .filter(|pt| !exists(**pt, value))
+                        .map(|pt| rtcp_fb(&pt.to_string(), value)),
+                ),
+                _ => self.attributes.push(attribute.clone()),
+            }
+        }
+    }
+
+    /// replace the "a=rtcp-fb:" attributes of a feedback that every
+    /// payload type of the "m=" line has with one "a=rtcp-fb:*"
+    /// wildcard, at the position of the first of them.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 98\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtcp-fb:96 nack\r\n\
+    ///     a=rtcp-fb:96 goog-remb\r\n\
+    ///     a=rtpmap:98 VP9/90000\r\n\
+    ///     a=rtcp-fb:98 nack\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].collapse_feedback_wildcards();
+    ///
+    /// let attributes = sdp.medias[0].attributes.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(attributes, [
+    ///     "rtpmap:96 VP8/90000",
+    ///     "rtcp-fb:* nack",
+    ///     "rtcp-fb:96 goog-remb",
+    ///     "rtpmap:98 VP9/90000",
+    /// ]);
+    /// ```
+    pub fn collapse_feedback_wildcards(&mut self) {
+        if self.fmts.is_empty() {
+            return;
+        }
+
+        let fmts = &self.fmts;
+        let listed = |key: &str| key.parse().is_ok_and(|pt| fmts.contains(&pt));
+        let mut i = 0;
+        while i < self.attributes.len() {
+            let value = match feedback(&self.attributes[i].value) {
+                Some((k, v)) if listed(k) => String::from(v),
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+
+            let has = |pt: u8| self.attributes
+                .iter()
+                .filter_map(|a| feedback(&a.value))
+                .any(|(k, v)| v == value && k.parse() == Ok(pt));
+            if fmts.iter().all(|pt| has(*pt)) {
+                self.attributes[i] = rtcp_fb("*", &value);
+                let mut index = 0;
+                self.attributes.retain(|a| {
+                    index += 1;
+                    index <= i + 1 || !feedback(&a.value).is_some_and(|(k, v)| v == value && listed(k))
+                });
+            }
+
+            i += 1;
+        }
+    }
+
+    /// the payload types that repair or carry the given payload type:
+    /// rtx and fec payload types with "apt=" pointing at it, and red
+    /// payload types that list it.
+    fn dependents(&self, pt: u8) -> Vec<u8> {
+        self.codec_infos()
+            .into_iter()
+            .filter(|info| info.pt != pt)
+            .filter(|info| {
+                info.param("apt").flatten().and_then(|v| v.parse().ok()) == Some(pt)
+                    || info.codec.as_ref().is_some_and(|v| v.codec.eq_ignore_ascii_case("red"))
+                        && info.params
+                            .iter()
+                            .any(|(k, _)| k.split('/').any(|v| v.parse() == Ok(pt)))
+            })
+            .map(|info| info.pt)
+            .collect()
+    }
+
+    /// remove a payload type from the "m=" line with its "a=rtpmap:",
+    /// "a=fmtp:" and "a=rtcp-fb:" attributes, and the payload types that
+    /// depend on it, e.g. its rtx payload type.  Returns the removed
+    /// payload types, the given one first.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 97 98 99 100\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtcp-fb:96 nack\r\n\
+    ///     a=rtpmap:97 rtx/90000\r\n\
+    ///     a=fmtp:97 apt=96\r\n\
+    ///     a=rtpmap:98 VP9/90000\r\n\
+    ///     a=rtpmap:99 red/90000\r\n\
+    ///     a=fmtp:99 96/98\r\n\
+    ///     a=rtpmap:100 rtx/90000\r\n\
+    ///     a=fmtp:100 apt=99\r\n\
+    ///     a=rtcp-fb:* nack\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].remove_codec(96), vec![96, 97, 99, 100]);
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 98\r\n\
+    ///     a=rtpmap:98 VP9/90000\r\n\
+    ///     a=rtcp-fb:* nack\r\n");
+    /// ```
+    pub fn remove_codec(&mut self, pt: u8) -> Vec<u8> {
+        let mut removed = Vec::from([pt]);
+        let mut i = 0;
+        while i < removed.len() {
+            for v in self.dependents(removed[i]) {
+                if !removed.contains(&v) {
+                    removed.push(v);
+                }
+            }
+
+            i += 1;
+        }
+
+        self.fmts.retain(|v| !removed.contains(v));
+        self.attributes.retain(|a| match payload_type(&a.value) {
+            Some(v) => !removed.contains(&v),
+            None => true,
+        });
+
+        removed
+    }
+
+    /// move the payload types of the given encodings to the front of the
+    /// "m=" line, in the given order.
+    ///
+    /// Encoding names are compared case insensitively, payload types of
+    /// the same encoding and the other payload types keep their order.
+    /// The "a=rtpmap:", "a=fmtp:" and "a=rtcp-fb:" attributes of the
+    /// payload types are moved with them, into one block per payload
+    /// type, in the positions the codec attributes had in the list.
+    /// Other attributes do not move.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 0 111 63 8\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=fmtp:111 minptime=10\r\n\
+    ///     a=rtpmap:63 red/48000/2\r\n\
+    ///     a=fmtp:63 111/111\r\n\
+    ///     a=rtcp-mux\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].prefer_codecs(&["RED", "opus"]);
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 63 111 0 8\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtpmap:63 red/48000/2\r\n\
+    ///     a=fmtp:63 111/111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=fmtp:111 minptime=10\r\n\
+    ///     a=rtcp-mux\r\n");
+    /// ```
+    pub fn prefer_codecs(&mut self, names: &[&str]) {
+        // the preference of a payload type, unlisted encodings go last.
+        let rank = |media: &Self, pt: u8| {
+            let codec = media.codec(pt);
+            let name = codec.as_ref().map(|v| v.codec.as_ref());
+            names
+                .iter()
+                .position(|v| name.is_some_and(|n| n.eq_ignore_ascii_case(v)))
+                .unwrap_or(names.len())
+        };
+
+        let mut fmts = self.fmts.iter().map(|pt| (rank(self, *pt), *pt)).collect::<Vec<_>>();
+        fmts.sort_by_key(|(rank, _)| *rank);
+        self.fmts = fmts.into_iter().map(|(_, pt)| pt).collect();
+
+        // sort the codec attributes of the payload types among the
+        // positions they take.
+        let position = |a: &Attribute<'_>| payload_type(&a.value)
+            .and_then(|pt| self.fmts.iter().position(|v| *v == pt));
+        let slots = self.attributes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| position(a).map(|_| i))
+            .collect::<Vec<_>>();
+        let mut codecs = slots
+            .iter()
+            .map(|i| self.attributes[*i].clone())
+            .collect::<Vec<_>>();
+        codecs.sort_by_key(|a| position(a));
+        for (i, attribute) in slots.into_iter().zip(codecs) {
+            self.attributes[i] = attribute;
+        }
+    }
+}
@@ -1,31 +1,101 @@
-use super::util::tuple3_from_split;
-use std::net::IpAddr;
-use anyhow::ensure;
+use super::scanner::Scanner;
+use anyhow::anyhow;
 use super::{
     NetKind,
     AddrKind
 };
 
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
-#[derive(Debug)]
+use core::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr
+};
+
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Addr {
     pub ip: IpAddr,
     /// IPv6 multicast does not use TTL scoping, and hence the TTL value MUST
     /// NOT be present for IPv6 multicast.  It is expected that IPv6 scoped
     /// addresses will be used to limit the scope of conferences.
     pub ttl: Option<u16>,
+    /// number of consecutive multicast addresses starting at `ip`, e.g.
+    /// the layers of a hierarchically encoded stream.
     pub count: Option<u8>
 }
 
+impl Addr {
+    /// the addresses of the range, `ip` alone without a count.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::connection::*;
+    /// use std::convert::*;
+    ///
+    /// let addr = Addr::try_from("233.252.0.255/127/3").unwrap();
+    /// let ips = addr.addresses().map(|v| v.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(ips, vec!["233.252.0.255", "233.252.1.0", "233.252.1.1"]);
+    ///
+    /// let addr = Addr::try_from("ff00::db8:0:101/2").unwrap();
+    /// let ips = addr.addresses().map(|v| v.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(ips, vec!["ff00::db8:0:101", "ff00::db8:0:102"]);
+    ///
+    /// let addr = Addr::try_from("192.0.2.1").unwrap();
+    /// assert_eq!(addr.addresses().count(), 1);
+    ///
+    /// // the range ends at the last address.
+    /// let addr = Addr::try_from("255.255.255.254/1/4").unwrap();
+    /// assert_eq!(addr.addresses().count(), 2);
+    /// ```
+    pub fn addresses(&self) -> Addresses {
+        Addresses {
+            next: Some(self.ip),
+            remaining: self.count.unwrap_or(1),
+        }
+    }
+}
+
+/// Iterator over the addresses of a connection address range, see
+/// [`Addr::addresses`].
+#[derive(Debug, Clone)]
+pub struct Addresses {
+    next: Option<IpAddr>,
+    remaining: u8,
+}
+
+impl Iterator for Addresses {
+    type Item = IpAddr;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None
+        }
+
+        let ip = self.next?;
+        self.remaining -= 1;
+        self.next = match ip {
+            IpAddr::V4(v) => u32::from(v).checked_add(1).map(|v| IpAddr::V4(Ipv4Addr::from(v))),
+            IpAddr::V6(v) => u128::from(v).checked_add(1).map(|v| IpAddr::V6(Ipv6Addr::from(v))),
+        };
+
+        Some(ip)
+    }
+}
+
 /// Connection Information
 ///
 /// The "c=" line (connection-field) contains information necessary to
 /// establish a network connection.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Connection {
     /// <nettype>  is a text string giving the type of network.  Initially,
     /// "IN" is defined to have the meaning "Internet".
@@ -39,6 +109,23 @@ pub struct Connection {
     pub connection_address: Addr,
 }
 
+impl Connection {
+    /// the connection addresses, with the address range expanded.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::connection::*;
+    /// use std::convert::*;
+    ///
+    /// let connection = Connection::try_from("IN IP4 233.252.0.1/127/2").unwrap();
+    /// assert_eq!(connection.addresses().count(), 2);
+    /// ```
+    pub fn addresses(&self) -> Addresses {
+        self.connection_address.addresses()
+    }
+}
+
 impl fmt::Display for Connection {
     /// # Unit Test
     ///
@@ -78,7 +165,7 @@ impl<'a> TryFrom<&'a str> for Connection {
     /// use sdp::*;
     /// use sdp::connection::*;
     /// use std::convert::*;
-    /// use std::net::IpAddr;
+    /// use core::net::IpAddr;
     ///
     /// let temp = "IN IP4 0.0.0.0";
     /// let addr: IpAddr = "0.0.0.0".parse().unwrap();
@@ -91,7 +178,11 @@ impl<'a> TryFrom<&'a str> for Connection {
     /// assert_eq!(instance.connection_address.count, None);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (n, a, c) = tuple3_from_split(value, ' ', "invalid connection information!")?;
+        let mut scanner = Scanner::new(value, "connection information");
+        let n = scanner.field()?;
+        let a = scanner.field()?;
+        let c = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
             nettype: NetKind::try_from(n)?,
             addrtype: AddrKind::try_from(a)?,
@@ -100,6 +191,13 @@ impl<'a> TryFrom<&'a str> for Connection {
     }
 }
 
+impl FromStr for Connection {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
+
 impl fmt::Display for Addr {
     /// # Unit Test
     ///
@@ -139,7 +237,7 @@ impl<'a> TryFrom<&'a str> for Addr {
     /// use sdp::*;
     /// use sdp::connection::*;
     /// use std::convert::*;
-    /// use std::net::IpAddr;
+    /// use core::net::IpAddr;
     ///
     /// let temp = "0.0.0.0/127/2";
     /// let addr: IpAddr = "0.0.0.0".parse().unwrap();
@@ -148,14 +246,43 @@ impl<'a> TryFrom<&'a str> for Addr {
     /// assert_eq!(instance.ip, addr);
     /// assert_eq!(instance.ttl, Some(127));
     /// assert_eq!(instance.count, Some(2));
+    ///
+    /// // IPv6 has no TTL, the only subfield is the address count.
+    /// let instance = Addr::try_from("ff00::db8:0:101/3").unwrap();
+    /// assert_eq!(instance.ttl, None);
+    /// assert_eq!(instance.count, Some(3));
+    /// assert!(Addr::try_from("ff00::db8:0:101/127/3").is_err());
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split('/').collect::<Vec<&str>>();
-        ensure!(!values.is_empty(), "invalid connection information!");
+        let mut values = value.split('/');
+        let ip: IpAddr = values.next().ok_or_else(|| {
+            anyhow!("invalid connection information!")
+        })?.parse()?;
+
+        if ip.is_ipv6() {
+            let count = values.next().map(str::parse).transpose()?;
+            if values.next().is_some() {
+                return Err(anyhow!("invalid connection information!"))
+            }
+
+            return Ok(Self {
+                ip,
+                ttl: None,
+                count
+            })
+        }
+
         Ok(Self {
-            ip: values[0].parse()?,
-            ttl: if let Some(t) = values.get(1) { Some(t.parse()?) } else { None },
-            count: if let Some(c) =  values.get(2) { Some(c.parse()?) } else { None}
+            ip,
+            ttl: values.next().map(str::parse).transpose()?,
+            count: values.next().map(str::parse).transpose()?
         })
     }
 }
+
+impl FromStr for Addr {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
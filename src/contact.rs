@@ -0,0 +1,125 @@
+use alloc::borrow::Cow;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use core::{
+    convert::TryFrom,
+    str::FromStr,
+    fmt
+};
+
+/// how the name of a contact is written.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContactForm {
+    /// "j.doe@example.com (Jane Doe)"
+    #[default]
+    Comment,
+    /// "Jane Doe <j.doe@example.com>"
+    Angle,
+}
+
+/// Email Address and Phone Number ("e=" and "p=")
+///
+/// e=j.doe@example.com (Jane Doe)
+/// e=Jane Doe <j.doe@example.com>
+/// p=+1 617 555-6011
+///
+/// The email address or phone number, with an optional free text name of
+/// the person responsible for the session.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::contact::*;
+/// use std::convert::*;
+///
+/// let contact = Contact::try_from("j.doe@example.com (Jane Doe)").unwrap();
+/// assert_eq!(contact.address, "j.doe@example.com");
+/// assert_eq!(contact.name.as_deref(), Some("Jane Doe"));
+/// assert_eq!(contact.form, ContactForm::Comment);
+///
+/// let contact = Contact::try_from("Jane Doe <+1 617 555-6011>").unwrap();
+/// assert_eq!(contact.address, "+1 617 555-6011");
+/// assert_eq!(contact.name.as_deref(), Some("Jane Doe"));
+/// assert_eq!(contact.form, ContactForm::Angle);
+///
+/// let contact = Contact::try_from("+1 617 555-6011").unwrap();
+/// assert_eq!(contact.address, "+1 617 555-6011");
+/// assert_eq!(contact.name, None);
+///
+/// for temp in [
+///     "j.doe@example.com (Jane Doe)",
+///     "Jane Doe <j.doe@example.com>",
+///     "j.doe@example.com",
+/// ] {
+///     assert_eq!(format!("{}", Contact::try_from(temp).unwrap()), temp);
+/// }
+///
+/// assert!(Contact::try_from("").is_err());
+/// assert!(Contact::try_from(" (Jane Doe)").is_err());
+/// assert!(Contact::try_from("Jane Doe <>").is_err());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contact<'a> {
+    /// the email address or phone number.
+    pub address: Cow<'a, str>,
+    /// the name of the person, charset dependent.
+    pub name: Option<Cow<'a, str>>,
+    /// how the name is written, unused without a name.
+    pub form: ContactForm,
+}
+
+impl<'a> Contact<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Contact<'b> {
+        Contact {
+            address: Cow::Owned(self.address.into_owned()),
+            name: self.name.map(|v| Cow::Owned(v.into_owned())),
+            form: self.form,
+        }
+    }
+}
+
+impl fmt::Display for Contact<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.name, self.form) {
+            (None, _) => write!(f, "{}", self.address),
+            (Some(name), ContactForm::Comment) => write!(f, "{} ({})", self.address, name),
+            (Some(name), ContactForm::Angle) => write!(f, "{} <{}>", name, self.address),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Contact<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (address, name, form) = match value.strip_suffix(')').and_then(|v| v.rsplit_once('(')) {
+            Some((address, name)) => (address.trim_end(), Some(name), ContactForm::Comment),
+            None => match value.strip_suffix('>').and_then(|v| v.rsplit_once('<')) {
+                Some((name, address)) => (address, Some(name.trim_end()), ContactForm::Angle),
+                None => (value, None, ContactForm::Comment),
+            }
+        };
+
+        ensure!(!address.is_empty(), "invalid contact!");
+        Ok(Self {
+            address: Cow::Borrowed(address),
+            name: name.map(Cow::Borrowed),
+            form,
+        })
+    }
+}
+
+impl FromStr for Contact<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned contact, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Contact::try_from(value).map(Contact::into_owned)
+    }
+}
@@ -0,0 +1,335 @@
+//! Structural edits of a session description.
+//!
+//! Adding or removing a media description also changes the session
+//! level attributes that refer to the media by mid, "a=group:" and the
+//! stream list of "a=msid-semantic:".  The helpers here keep them
+//! consistent.
+
+use super::attributes::{
+    Attributes,
+    Mid,
+    MidGenerator,
+    Semantics,
+    SsrcAttr
+};
+
+use super::media::Media;
+use super::Sdp;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+/// the stream ids of the "a=msid:" and "a=ssrc:<ssrc> msid:" attributes
+/// of a media description, without the "-" of a track without stream.
+fn stream_ids<'s>(media: &'s Media<'_>) -> Vec<&'s str> {
+    media.attributes.iter().filter_map(|a| match &a.value {
+        Attributes::Other(k, Some(v)) if k == "msid" => v.split(' ').next(),
+        Attributes::Ssrc(v) => match &v.value {
+            SsrcAttr::MsId(v) => Some(v.id.as_ref()),
+            _ => None,
+        },
+        _ => None,
+    }).filter(|v| *v != "-").collect()
+}
+
+/// split an "a=msid-semantic:" value into the semantic and the stream
+/// ids.
+fn semantic_ids(value: &str) -> (&str, Vec<&str>) {
+    // " WMS a b", the leading space of the common form is kept.
+    let start = value.len() - value.trim_start().len();
+    let mut fields = value[start..].split(' ');
+    let end = start + fields.next().unwrap_or_default().len();
+    (&value[..end], fields.filter(|v| !v.is_empty()).collect())
+}
+
+impl<'a> Sdp<'a> {
+    /// add a media description at the end of the session, and return
+    /// its index.
+    ///
+    /// A media without "a=mid:" is given the next free mid, unless the
+    /// other media descriptions have no mids.  A media that is not
+    /// rejected joins the first "a=group:BUNDLE", and its streams are
+    /// added to "a=msid-semantic:" when that follows the streams of the
+    /// media descriptions.  Fails if the mid is already used.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0\r\n\
+    ///     a=msid-semantic: WMS a\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=msid:a audio\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// let video = Sdp::try_from("v=0\r\ns=-\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\na=msid:b video\r\n").unwrap();
+    /// let media = video.medias[0].clone();
+    /// assert_eq!(sdp.add_media(media.clone()).unwrap(), 1);
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0 1\r\n\
+    ///     a=msid-semantic: WMS a b\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=msid:a audio\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=msid:b video\r\n\
+    ///     a=mid:1\r\n");
+    ///
+    /// let mut media = media;
+    /// media.attributes.push(Attributes::Mid(Mid::Ref(1)).into());
+    /// assert!(sdp.add_media(media).is_err());
+    /// ```
+    pub fn add_media(&mut self, mut media: Media<'a>) -> Result<usize> {
+        let managed = self.msid_semantic_managed();
+        let mid = match media.mid() {
+            Some(mid) => {
+                ensure!(self.media_index(mid).is_none(), "mid {} is already used!", mid);
+                Some(mid.clone())
+            }
+            None if self.medias.is_empty() || self.medias.iter().any(|m| m.mid().is_some()) => {
+                let mid = MidGenerator::new()
+                    .resume(self)
+                    .find(|v| self.media_index(v).is_none())
+                    .ok_or_else(|| anyhow!("no free mid!"))?;
+                media.attributes.push(Attributes::Mid(mid.clone()).into());
+                Some(mid)
+            }
+            None => None,
+        };
+
+        if let Some(mid) = mid.filter(|_| !media.is_rejected()) {
+            let bundle = self.attributes.iter_mut().find_map(|a| match &mut a.value {
+                Attributes::Group(v) if v.semantics == Semantics::Bundle => Some(v),
+                _ => None,
+            });
+
+            if let Some(group) = bundle {
+                group.mids.push(mid);
+            }
+        }
+
+        self.medias.push(media);
+        if managed {
+            self.sync_msid_semantic();
+        }
+
+        Ok(self.medias.len() - 1)
+    }
+
+    /// remove the media description with the given mid, and return it.
+    ///
+    /// The mid is removed from every "a=group:", groups that become
+    /// empty are removed, and the streams that are no longer used are
+    /// removed from "a=msid-semantic:".  Once the last stream is gone,
+    /// streams of media descriptions added later are listed again.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0 1\r\n\
+    ///     a=group:LS 1\r\n\
+    ///     a=msid-semantic: WMS a b\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=msid:a audio\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=msid:b video\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// let media = sdp.remove_media(&Mid::Ref(1)).unwrap();
+    /// assert_eq!(media.mid(), Some(&Mid::Ref(1)));
+    /// assert!(sdp.remove_media(&Mid::Ref(1)).is_none());
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0\r\n\
+    ///     a=msid-semantic: WMS a\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=msid:a audio\r\n");
+    ///
+    /// // the list follows the media descriptions again after the last
+    /// // stream is removed, a track without stream is not listed.
+    /// sdp.remove_media(&Mid::Ref(0)).unwrap();
+    /// assert_eq!(sdp.to_string(), "v=0\r\ns=-\r\na=msid-semantic: WMS\r\n");
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=msid:c audio\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=msid:- video\r\n";
+    ///
+    /// let other = Sdp::try_from(temp).unwrap();
+    /// sdp.add_media(other.medias[0].clone()).unwrap();
+    /// sdp.add_media(other.medias[1].clone()).unwrap();
+    /// assert_eq!(sdp.attributes[0].to_string(), "msid-semantic: WMS c");
+    /// ```
+    pub fn remove_media(&mut self, mid: &Mid<'_>) -> Option<Media<'a>> {
+        let index = self.media_index(mid)?;
+        let managed = self.msid_semantic_managed();
+        let media = self.medias.remove(index);
+
+        for attribute in &mut self.attributes {
+            if let Attributes::Group(group) = &mut attribute.value {
+                group.mids.retain(|v| &*v != mid);
+            }
+        }
+
+        self.attributes.retain(|a| !matches!(&a.value, Attributes::Group(v) if v.mids.is_empty()));
+        if managed {
+            self.sync_msid_semantic();
+        }
+
+        Some(media)
+    }
+
+    /// align the media descriptions to a previous description of the
+    /// same endpoint, for a renegotiation
+    /// ([RFC8829](https://datatracker.ietf.org/doc/html/rfc8829#section-5.2.2)).
+    ///
+    /// The media descriptions of the previous description keep their
+    /// position, matched by mid.  A media that is no longer in this
+    /// description is added back rejected, see [`Media::reject`], and
+    /// removed from "a=group:BUNDLE".  New media descriptions follow in
+    /// their order, as do media descriptions without "a=mid:".
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let previous = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0 1\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n";
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 2 0 1\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 97\r\n\
+    ///     a=mid:2\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n";
+    ///
+    /// let previous = Sdp::try_from(previous).unwrap();
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.align_to(&previous);
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 2 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 97\r\n\
+    ///     a=mid:2\r\n");
+    /// ```
+    pub fn align_to(&mut self, previous: &Sdp<'a>) {
+        let mut medias = Vec::with_capacity(self.medias.len().max(previous.medias.len()));
+        let mut rejected = Vec::new();
+        for media in &previous.medias {
+            let Some(mid) = media.mid() else {
+                continue;
+            };
+
+            match self.media_index(mid) {
+                Some(index) => medias.push(self.medias.remove(index)),
+                None => {
+                    let mut media = media.clone();
+                    media.reject();
+                    rejected.push(mid.clone());
+                    medias.push(media);
+                }
+            }
+        }
+
+        medias.append(&mut self.medias);
+        self.medias = medias;
+
+        for attribute in &mut self.attributes {
+            if let Attributes::Group(group) = &mut attribute.value {
+                if group.semantics == Semantics::Bundle {
+                    group.mids.retain(|v| !rejected.contains(v));
+                }
+            }
+        }
+    }
+
+    /// the streams of the media descriptions, in order.
+    fn streams(&self) -> Vec<&str> {
+        let mut streams: Vec<&str> = Vec::new();
+        for id in self.medias.iter().flat_map(stream_ids) {
+            if !streams.contains(&id) {
+                streams.push(id);
+            }
+        }
+
+        streams
+    }
+
+    /// the index of "a=msid-semantic:" and its value.
+    fn msid_semantic(&self) -> Option<(usize, &str)> {
+        self.attributes.iter().enumerate().find_map(|(i, a)| match &a.value {
+            Attributes::Other(k, Some(v)) if k == "msid-semantic" => Some((i, v.as_ref())),
+            _ => None,
+        })
+    }
+
+    /// whether the stream list of "a=msid-semantic:" follows the media
+    /// descriptions: it lists stream ids, or it is empty and no media
+    /// has streams.  A list of "*" is never rewritten.
+    fn msid_semantic_managed(&self) -> bool {
+        let Some((_, value)) = self.msid_semantic() else {
+            return false;
+        };
+
+        match semantic_ids(value).1.as_slice() {
+            ["*"] => false,
+            [] => self.streams().is_empty(),
+            _ => true,
+        }
+    }
+
+    /// rewrite the stream list of "a=msid-semantic:" to the streams of
+    /// the media descriptions.
+    fn sync_msid_semantic(&mut self) {
+        let Some((index, value)) = self.msid_semantic() else {
+            return;
+        };
+
+        let mut list = String::from(semantic_ids(value).0);
+        for id in self.streams() {
+            list.push(' ');
+            list.push_str(id);
+        }
+
+        self.attributes[index].value = Attributes::Other(Cow::Borrowed("msid-semantic"), Some(Cow::Owned(list)));
+    }
+}
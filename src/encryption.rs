@@ -1,14 +1,16 @@
-use anyhow::{
-    anyhow,
-    ensure
-};
+use alloc::borrow::Cow;
+use anyhow::anyhow;
 
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
-#[derive(Debug)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum EncryptionMethod {
     Clear,
     Base64,
@@ -142,10 +144,22 @@ impl fmt::Display for EncryptionMethod {
 /// session, not an intermediary: if a caching proxy server is used, it
 /// is important to ensure that the proxy is either trusted or unable to
 /// access the SDP.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EncryptionKey<'a> {
     method: EncryptionMethod,
-    key: Option<&'a str>,
+    key: Option<Cow<'a, str>>,
+}
+
+impl<'a> EncryptionKey<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> EncryptionKey<'b> {
+        EncryptionKey {
+            method: self.method,
+            key: self.key.map(|k| Cow::Owned(k.into_owned()))
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a str> for EncryptionKey<'a> {
@@ -166,15 +180,26 @@ impl<'a> TryFrom<&'a str> for EncryptionKey<'a> {
     /// assert_eq!(format!("{}", connection), temp);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split(':').collect::<Vec<&'a str>>();
-        ensure!(!values.is_empty(), "invalid encryption key!");
+        let mut values = value.splitn(2, ':');
+        let method = values.next().ok_or_else(|| {
+            anyhow!("invalid encryption key!")
+        })?;
+
         Ok(Self {
-            method: EncryptionMethod::try_from(values[0])?,
-            key: if let Some(k) = values.get(1) { Some(*k) } else { None }
+            method: EncryptionMethod::try_from(method)?,
+            key: values.next().map(Cow::Borrowed)
         })
     }
 }
 
+impl FromStr for EncryptionKey<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned encryption key, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        EncryptionKey::try_from(value).map(EncryptionKey::into_owned)
+    }
+}
+
 impl<'a> fmt::Display for EncryptionKey<'a> {
     /// # Unit Test
     ///
@@ -193,7 +218,7 @@ impl<'a> fmt::Display for EncryptionKey<'a> {
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.method)?;
-        if let Some(key) = self.key {
+        if let Some(key) = &self.key {
             write!(f, ":{}", key)?;
         }
         
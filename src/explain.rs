@@ -0,0 +1,199 @@
+//! Human readable session reports.
+//!
+//! [`Sdp::explain`] writes an indented summary of a description, the
+//! media sections with their negotiated codecs, header extensions, SSRCs
+//! and transports, for logs and bug reports where the raw text is hard
+//! to read.
+
+use super::attributes::{
+    Attribute,
+    Attributes
+};
+
+use super::media::Media;
+use super::Sdp;
+use core::fmt;
+
+/// indented report of a session description, see [`Sdp::explain`].
+#[derive(Debug, Clone, Copy)]
+pub struct Explain<'s, 'a>(&'s Sdp<'a>);
+
+impl<'a> Sdp<'a> {
+    /// an indented human readable report of the description.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     a=group:BUNDLE 0 1\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111 0\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=setup:actpass\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=fmtp:111 minptime=10;useinbandfec=1\r\n\
+    ///     a=ssrc-group:FID 1 2\r\n\
+    ///     a=ssrc:1 cname:panda\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=bundle-only\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.explain().to_string(), "\
+    /// session \"-\"
+    ///   origin: - 4611731400430051336 2 IN IP4 127.0.0.1
+    ///   group: BUNDLE 0 1
+    /// media 0: audio, mid 0, sendrecv
+    ///   transport: 9 UDP/TLS/RTP/SAVPF, c=IN IP4 0.0.0.0, ice-ufrag Oyef, setup actpass, rtcp-mux
+    ///   codec 111: opus/48000/2 minptime=10;useinbandfec=1 [transport-cc]
+    ///   codec 0: PCMU/8000/1
+    ///   extension 1: urn:ietf:params:rtp-hdrext:sdes:mid
+    ///   ssrc-group: FID 1 2
+    ///   ssrc 1: cname:panda
+    /// media 1: video, mid 1, bundle-only
+    ///   transport: bundled with media 0
+    ///   codec 96
+    /// ");
+    /// ```
+    pub fn explain(&self) -> Explain<'_, 'a> {
+        Explain(self)
+    }
+}
+
+impl fmt::Display for Explain<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sdp = self.0;
+        writeln!(f, "session \"{}\"", sdp.session_name.as_deref().unwrap_or("-"))?;
+        if let Some(origin) = &sdp.origin {
+            writeln!(f, "  origin: {}", origin)?;
+        }
+
+        for attribute in &sdp.attributes {
+            match &attribute.value {
+                Attributes::Group(v) => writeln!(f, "  group: {}", v)?,
+                Attributes::Extmap(v) => writeln!(f, "  extension {}: {}", v.key, v.value)?,
+                _ => (),
+            }
+        }
+
+        for (index, media) in sdp.medias.iter().enumerate() {
+            write!(f, "media {}: {}", index, media.encoding)?;
+            if let Some(mid) = media.mid() {
+                write!(f, ", mid {}", mid)?;
+            }
+
+            if media.is_rejected() {
+                writeln!(f, ", rejected")?;
+                continue;
+            }
+
+            match has(&media.attributes, |v| matches!(v, Attributes::BundleOnly)) {
+                true => writeln!(f, ", bundle-only")?,
+                false => writeln!(f, ", {}", sdp.direction_for(index).unwrap_or_default())?,
+            }
+
+            explain_transport(f, sdp, index)?;
+            explain_media(f, media)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn has(attributes: &[Attribute<'_>], predicate: impl Fn(&Attributes<'_>) -> bool) -> bool {
+    attributes.iter().any(|a| predicate(&a.value))
+}
+
+/// the value of an unknown attribute with the given name.
+fn other<'s>(attribute: &'s Attribute<'_>, name: &str) -> Option<&'s str> {
+    match &attribute.value {
+        Attributes::Other(k, Some(v)) if k == name => Some(v),
+        _ => None,
+    }
+}
+
+fn explain_transport(f: &mut fmt::Formatter<'_>, sdp: &Sdp<'_>, index: usize) -> fmt::Result {
+    let transport = sdp.transport_media(index);
+    if transport != index {
+        return writeln!(f, "  transport: bundled with media {}", transport);
+    }
+
+    let media = &sdp.medias[index];
+    write!(f, "  transport: {}", media.port)?;
+    for (i, proto) in media.protos.iter().enumerate() {
+        write!(f, "{}{}", if i == 0 { " " } else { "/" }, proto)?;
+    }
+
+    for connection in sdp.connection_for(index).unwrap_or_default() {
+        write!(f, ", c={}", connection)?;
+    }
+
+    for ufrag in media.attributes.iter().filter_map(|a| other(a, "ice-ufrag")) {
+        write!(f, ", ice-ufrag {}", ufrag)?;
+    }
+
+    for attribute in &media.attributes {
+        match &attribute.value {
+            Attributes::Setup(v) => write!(f, ", setup {}", v)?,
+            Attributes::Fingerprint(v) => write!(f, ", fingerprint {}", v.hash)?,
+            _ => (),
+        }
+    }
+
+    if has(&media.attributes, |v| matches!(v, Attributes::Other(k, None) if k == "rtcp-mux")) {
+        write!(f, ", rtcp-mux")?;
+    }
+
+    writeln!(f)
+}
+
+fn explain_media(f: &mut fmt::Formatter<'_>, media: &Media<'_>) -> fmt::Result {
+    for info in media.codec_infos() {
+        write!(f, "  codec {}", info.pt)?;
+        if let Some(codec) = &info.codec {
+            write!(f, ": {}", codec)?;
+        }
+
+        for (i, (k, v)) in info.params.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ";" }, k)?;
+            if let Some(v) = v {
+                write!(f, "={}", v)?;
+            }
+        }
+
+        if !info.feedback.is_empty() {
+            write!(f, " [{}]", info.feedback.join(", "))?;
+        }
+
+        writeln!(f)?;
+    }
+
+    for attribute in &media.attributes {
+        if let Attributes::Extmap(v) = &attribute.value {
+            writeln!(f, "  extension {}: {}", v.key, v.value)?;
+        }
+    }
+
+    for group in media.attributes.iter().filter_map(|a| other(a, "ssrc-group")) {
+        writeln!(f, "  ssrc-group: {}", group)?;
+    }
+
+    for attribute in &media.attributes {
+        if let Attributes::Ssrc(v) = &attribute.value {
+            writeln!(f, "  ssrc {}: {}", v.key, v.value)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,304 @@
+//! RTP header extension identifier allocation.
+//!
+//! Identifiers 1-14 fit the one-byte header form and are allocated
+//! first, then 16-255 which require the two-byte form
+//! [RFC8285](https://datatracker.ietf.org/doc/html/rfc8285#section-5).
+//! 15 is reserved in the one-byte form and never allocated.
+
+use crate::attributes::{
+    Attribute,
+    Attributes,
+    ExtMap,
+    KnownExtension
+};
+
+use crate::media::Media;
+use crate::Sdp;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+/// Set of used header extension identifiers.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::extmap::*;
+///
+/// let mut ids = ExtensionIds::default();
+/// ids.reserve(1).unwrap();
+/// assert!(ids.reserve(1).is_err());
+/// assert!(ids.reserve(0).is_err());
+/// assert_eq!(ids.allocate().unwrap(), 2);
+///
+/// for id in 3..=14 {
+///     ids.reserve(id).unwrap();
+/// }
+///
+/// assert_eq!(ids.allocate().unwrap(), 16);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExtensionIds([u128; 2]);
+
+impl ExtensionIds {
+    /// the identifiers used by the given attributes.
+    pub fn from_attributes(attributes: &[Attribute<'_>]) -> Self {
+        let mut ids = Self::default();
+        for v in extmaps(attributes) {
+            ids.insert(v.key);
+        }
+
+        ids
+    }
+
+    /// the identifiers used anywhere in a session description.
+    pub fn from_session(sdp: &Sdp<'_>) -> Self {
+        let mut ids = Self::from_attributes(&sdp.attributes);
+        for media in &sdp.medias {
+            for v in extmaps(&media.attributes) {
+                ids.insert(v.key);
+            }
+        }
+
+        ids
+    }
+
+    pub fn contains(&self, id: u8) -> bool {
+        self.0[usize::from(id >> 7)] & (1 << (id & 127)) != 0
+    }
+
+    /// mark an identifier as used, fails if it is already used.
+    pub fn reserve(&mut self, id: u8) -> Result<()> {
+        ensure!(id != 0, "invalid extension id 0!");
+        ensure!(!self.contains(id), "extension id {} is already used!", id);
+        self.insert(id);
+        Ok(())
+    }
+
+    /// allocate a free identifier.
+    pub fn allocate(&mut self) -> Result<u8> {
+        let id = (1..=14)
+            .chain(16..=255)
+            .find(|id| !self.contains(*id))
+            .ok_or_else(|| anyhow!("no free extension id!"))?;
+        self.insert(id);
+        Ok(id)
+    }
+
+    fn insert(&mut self, id: u8) {
+        self.0[usize::from(id >> 7)] |= 1 << (id & 127);
+    }
+}
+
+impl<'a> Media<'a> {
+    /// the identifier of a header extension of the media description.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=extmap:3 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n";
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].extension(KnownExtension::SsrcAudioLevel), Some(3));
+    /// assert_eq!(sdp.medias[0].extension(KnownExtension::CsrcAudioLevel), None);
+    /// ```
+    pub fn extension(&self, uri: KnownExtension<'_>) -> Option<u8> {
+        extmaps(&self.attributes).find(|v| v.uri() == uri).map(|v| v.key)
+    }
+
+    /// add a header extension with a free identifier of the media
+    /// description, and return the identifier.  An extension that is
+    /// already mapped keeps its identifier.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n";
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &mut sdp.medias[0];
+    /// assert_eq!(media.add_extension("urn:ietf:params:rtp-hdrext:sdes:mid").unwrap(), 2);
+    /// assert_eq!(media.add_extension("urn:ietf:params:rtp-hdrext:ssrc-audio-level").unwrap(), 1);
+    /// assert_eq!(media.attributes.len(), 2);
+    /// ```
+    pub fn add_extension(&mut self, uri: impl Into<Cow<'a, str>>) -> Result<u8> {
+        let uri = uri.into();
+        if let Some(v) = extmaps(&self.attributes).find(|v| v.value == uri) {
+            return Ok(v.key)
+        }
+
+        let id = ExtensionIds::from_attributes(&self.attributes).allocate()?;
+        self.attributes.push(Attributes::Extmap(ExtMap {
+            key: id,
+            value: uri,
+        }).into());
+        Ok(id)
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// add a header extension to a media description, and return the
+    /// identifier.
+    ///
+    /// Unlike [`Media::add_extension`], the identifier is unique across
+    /// the session, and an extension that is mapped by another media
+    /// description reuses its identifier, so that bundled media
+    /// descriptions agree on the numbering.  Fails if there is no media
+    /// with the index.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     a=extmap:2 urn:ietf:params:rtp-hdrext:toffset\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.add_extension(1, "urn:ietf:params:rtp-hdrext:sdes:mid").unwrap(), 1);
+    /// assert_eq!(sdp.add_extension(1, "urn:3gpp:video-orientation").unwrap(), 3);
+    /// assert_eq!(sdp.add_extension(0, "urn:3gpp:video-orientation").unwrap(), 3);
+    /// assert!(sdp.add_extension(2, "urn:3gpp:video-orientation").is_err());
+    /// ```
+    pub fn add_extension(&mut self, index: usize, uri: impl Into<Cow<'a, str>>) -> Result<u8> {
+        let uri = uri.into();
+        let media = self.medias
+            .get(index)
+            .ok_or_else(|| anyhow!("no media description {}!", index))?;
+        if let Some(v) = extmaps(&media.attributes).find(|v| v.value == uri) {
+            return Ok(v.key)
+        }
+
+        let existing = extmaps(&self.attributes)
+            .chain(self.medias.iter().flat_map(|m| extmaps(&m.attributes)))
+            .find(|v| v.value == uri)
+            .map(|v| v.key);
+        let id = match existing {
+            Some(id) if !ExtensionIds::from_attributes(&media.attributes).contains(id) => id,
+            _ => ExtensionIds::from_session(self).allocate()?,
+        };
+
+        self.medias[index].attributes.push(Attributes::Extmap(ExtMap {
+            key: id,
+            value: uri,
+        }).into());
+        Ok(id)
+    }
+
+    /// rewrite the header extension identifiers to match the numbering
+    /// of the peer, e.g. of the offer when generating an answer.
+    ///
+    /// Extensions the peer maps take the identifier of the peer.  Other
+    /// extensions keep their identifier, unless it is now taken, then
+    /// they are given a free one.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let offer = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=extmap:3 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n";
+    ///
+    /// let answer = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     a=extmap:2 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+    ///     a=extmap:3 urn:ietf:params:rtp-hdrext:toffset\r\n";
+    ///
+    /// let offer = Sdp::try_from(offer).unwrap();
+    /// let mut answer = Sdp::try_from(answer).unwrap();
+    /// answer.remap_extensions(&offer).unwrap();
+    ///
+    /// let ids = answer.medias[0].attributes.iter().filter_map(|a| match &a.value {
+    ///     Attributes::Extmap(v) => Some((v.key, v.value.as_ref())),
+    ///     _ => None,
+    /// }).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ids, vec![
+    ///     (3, "urn:ietf:params:rtp-hdrext:sdes:mid"),
+    ///     (1, "urn:ietf:params:rtp-hdrext:ssrc-audio-level"),
+    ///     (2, "urn:ietf:params:rtp-hdrext:toffset"),
+    /// ]);
+    /// ```
+    pub fn remap_extensions(&mut self, peer: &Sdp<'_>) -> Result<()> {
+        let peer_id = |uri: &str| extmaps(&peer.attributes)
+            .chain(peer.medias.iter().flat_map(|m| extmaps(&m.attributes)))
+            .find(|v| v.value == uri)
+            .map(|v| v.key);
+
+        // (uri, old id, new id), so that every media description is
+        // rewritten the same way.
+        let mut mapping: Vec<(String, u8, Option<u8>)> = Vec::new();
+        let mut used = ExtensionIds::default();
+        for v in extmaps(&self.attributes).chain(self.medias.iter().flat_map(|m| extmaps(&m.attributes))) {
+            if mapping.iter().any(|(uri, key, _)| *uri == v.value && *key == v.key) {
+                continue
+            }
+
+            let id = peer_id(&v.value);
+            if let Some(id) = id {
+                used.insert(id);
+            }
+
+            mapping.push((String::from(v.value.as_ref()), v.key, id));
+        }
+
+        // keep the identifiers of the other extensions where possible.
+        for (_, key, id) in mapping.iter_mut().filter(|(_, _, id)| id.is_none()) {
+            *id = match used.contains(*key) {
+                true => None,
+                false => Some(*key),
+            };
+
+            if let Some(id) = id {
+                used.insert(*id);
+            }
+        }
+
+        for (_, _, id) in mapping.iter_mut().filter(|(_, _, id)| id.is_none()) {
+            *id = Some(used.allocate()?);
+        }
+
+        let attributes = self.attributes
+            .iter_mut()
+            .chain(self.medias.iter_mut().flat_map(|m| m.attributes.iter_mut()));
+        for attribute in attributes {
+            if let Attributes::Extmap(v) = &mut attribute.value {
+                if let Some((_, _, Some(id))) = mapping.iter().find(|(uri, key, _)| *uri == v.value && *key == v.key) {
+                    v.key = *id;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn extmaps<'b, 'a>(attributes: &'b [Attribute<'a>]) -> impl Iterator<Item = &'b ExtMap<'a>> + Clone {
+    attributes.iter().filter_map(|a| match &a.value {
+        Attributes::Extmap(v) => Some(v),
+        _ => None,
+    })
+}
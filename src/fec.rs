@@ -0,0 +1,318 @@
+//! Forward error correction.
+//!
+//! FEC is negotiated as payload types of the "m=" line, "red"
+//! [RFC2198](https://datatracker.ietf.org/doc/html/rfc2198),
+//! "ulpfec" [RFC5109](https://datatracker.ietf.org/doc/html/rfc5109)
+//! and "flexfec" [RFC8627](https://datatracker.ietf.org/doc/html/rfc8627).
+//! The repair stream of a source stream is given by
+//! "a=ssrc-group:FEC-FR"
+//! [RFC5956](https://datatracker.ietf.org/doc/html/rfc5956#section-4.3).
+
+use super::attributes::{
+    Attributes,
+    Fmtp,
+    RtpValue
+};
+
+use super::media::Media;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec::Vec;
+use smallvec::smallvec;
+use anyhow::{
+    Result,
+    anyhow
+};
+
+/// FEC scheme of a payload type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FecKind {
+    /// redundant audio data, "red".
+    Red,
+    /// generic FEC, "ulpfec".
+    Ulpfec,
+    /// flexible FEC, "flexfec", or "flexfec-03" of the draft used by
+    /// browsers.
+    Flexfec,
+}
+
+impl FecKind {
+    /// the FEC scheme of an encoding name, `None` for media encodings.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::fec::*;
+    ///
+    /// assert_eq!(FecKind::from_codec("red"), Some(FecKind::Red));
+    /// assert_eq!(FecKind::from_codec("ULPFEC"), Some(FecKind::Ulpfec));
+    /// assert_eq!(FecKind::from_codec("flexfec-03"), Some(FecKind::Flexfec));
+    /// assert_eq!(FecKind::from_codec("VP8"), None);
+    /// ```
+    pub fn from_codec(name: &str) -> Option<Self> {
+        let name = name.split_once('-').map_or(name, |(v, _)| v);
+        if name.eq_ignore_ascii_case("red") {
+            Some(Self::Red)
+        } else if name.eq_ignore_ascii_case("ulpfec") {
+            Some(Self::Ulpfec)
+        } else if name.eq_ignore_ascii_case("flexfec") {
+            Some(Self::Flexfec)
+        } else {
+            None
+        }
+    }
+}
+
+/// a FEC payload type of the "m=" line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FecPayload {
+    pub pt: u8,
+    pub kind: FecKind,
+    /// the "repair-window" of flexfec, in microseconds.
+    pub repair_window: Option<u64>,
+}
+
+/// the FEC payload types and flows of a media description.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fec {
+    pub payloads: Vec<FecPayload>,
+    /// the source and repair SSRCs of "a=ssrc-group:FEC-FR".
+    pub flows: Vec<(u32, u32)>,
+}
+
+impl Fec {
+    /// the source SSRC protected by a repair SSRC.
+    pub fn source(&self, repair: u32) -> Option<u32> {
+        self.flows
+            .iter()
+            .find(|(_, v)| *v == repair)
+            .map(|(v, _)| *v)
+    }
+
+    /// the repair SSRC of a source SSRC.
+    pub fn repair(&self, source: u32) -> Option<u32> {
+        self.flows
+            .iter()
+            .find(|(v, _)| *v == source)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// a "red" payload type and the payload types its blocks carry.
+///
+/// The "a=fmtp:" of a red payload type lists the payload type of the
+/// primary block and of the redundant blocks, e.g. "111/111" for Opus
+/// with one redundant Opus block
+/// [RFC2198](https://datatracker.ietf.org/doc/html/rfc2198#section-5).
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redundancy {
+    pub pt: u8,
+    /// the payload types of the blocks, the primary block first.
+    pub payloads: Vec<u8>,
+}
+
+impl Redundancy {
+    /// the payload type of the primary block.
+    pub fn primary(&self) -> Option<u8> {
+        self.payloads.first().copied()
+    }
+}
+
+impl Fmtp<'_> {
+    /// the flexfec "repair-window" parameter, in microseconds.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use std::convert::*;
+    ///
+    /// let fmtp = Fmtp::try_from("100 repair-window=10000000").unwrap();
+    /// assert_eq!(fmtp.repair_window(), Some(10000000));
+    /// ```
+    pub fn repair_window(&self) -> Option<u64> {
+        self.get("repair-window").flatten()?.parse().ok()
+    }
+}
+
+impl<'a> Media<'a> {
+    /// the FEC payload types of the "m=" line and the FEC flows of the
+    /// media description.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::fec::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 100 101 102\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtpmap:100 red/90000\r\n\
+    ///     a=rtpmap:101 ulpfec/90000\r\n\
+    ///     a=rtpmap:102 flexfec-03/90000\r\n\
+    ///     a=fmtp:102 repair-window=10000000\r\n\
+    ///     a=ssrc-group:FEC-FR 1234 5678\r\n\
+    ///     a=ssrc:1234 cname:a\r\n\
+    ///     a=ssrc:5678 cname:a\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let fec = sdp.medias[0].fec();
+    /// assert_eq!(fec.payloads, vec![
+    ///     FecPayload { pt: 100, kind: FecKind::Red, repair_window: None },
+    ///     FecPayload { pt: 101, kind: FecKind::Ulpfec, repair_window: None },
+    ///     FecPayload { pt: 102, kind: FecKind::Flexfec, repair_window: Some(10000000) },
+    /// ]);
+    ///
+    /// assert_eq!(fec.flows, vec![(1234, 5678)]);
+    /// assert_eq!(fec.source(5678), Some(1234));
+    /// assert_eq!(fec.repair(1234), Some(5678));
+    /// ```
+    pub fn fec(&self) -> Fec {
+        let mut fec = Fec::default();
+        for (pt, codec) in self.codecs() {
+            let Some(kind) = FecKind::from_codec(&codec.codec) else {
+                continue;
+            };
+
+            let repair_window = self.attributes.iter().find_map(|a| match &a.value {
+                Attributes::Fmtp(v) if v.key == pt => v.repair_window(),
+                _ => None,
+            });
+
+            fec.payloads.push(FecPayload {
+                pt,
+                kind,
+                repair_window,
+            });
+        }
+
+        for attribute in &self.attributes {
+            let Attributes::Other(k, Some(v)) = &attribute.value else {
+                continue;
+            };
+
+            let Some(ssrcs) = v.strip_prefix("FEC-FR ").filter(|_| k == "ssrc-group") else {
+                continue;
+            };
+
+            let mut ssrcs = ssrcs.split(' ').filter_map(|v| v.parse().ok());
+            if let (Some(source), Some(repair)) = (ssrcs.next(), ssrcs.next()) {
+                fec.flows.push((source, repair));
+            }
+        }
+
+        fec
+    }
+
+    /// the red payload types of the "m=" line with the payload types
+    /// they carry.  Red payload types without "a=fmtp:" have no
+    /// payloads.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111 63\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtpmap:63 red/48000/2\r\n\
+    ///     a=fmtp:63 111/111\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &sdp.medias[0];
+    /// let red = media.redundancy();
+    /// assert_eq!(red[0].pt, 63);
+    /// assert_eq!(red[0].payloads, vec![111, 111]);
+    /// assert_eq!(red[0].primary(), Some(111));
+    ///
+    /// assert_eq!(media.red_for(111), Some(63));
+    /// assert_eq!(media.red_for(63), None);
+    /// ```
+    pub fn redundancy(&self) -> Vec<Redundancy> {
+        self.codecs()
+            .into_iter()
+            .filter(|(_, v)| FecKind::from_codec(&v.codec) == Some(FecKind::Red))
+            .map(|(pt, _)| Redundancy {
+                pt,
+                payloads: self.attributes
+                    .iter()
+                    .find_map(|a| match &a.value {
+                        Attributes::Fmtp(v) if v.key == pt => v.values.first(),
+                        _ => None,
+                    })
+                    .map(|(k, _)| k.split('/').filter_map(|v| v.trim().parse().ok()).collect())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// the red payload type whose primary block is the given payload
+    /// type.
+    pub fn red_for(&self, pt: u8) -> Option<u8> {
+        self.redundancy()
+            .into_iter()
+            .find(|v| v.primary() == Some(pt))
+            .map(|v| v.pt)
+    }
+
+    /// add a red payload type for a codec of the "m=" line with one
+    /// redundant block, e.g. "a=rtpmap:63 red/48000/2" and
+    /// "a=fmtp:63 111/111" for Opus, and return its payload type.
+    ///
+    /// The red payload type has the clock rate and channels of the
+    /// codec, and is appended to the "m=" line, see
+    /// [`Media::prefer_codecs`] to prefer it.  Fails if the codec has no
+    /// known encoding or no payload type is free.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=rtpmap:111 opus/48000/2\r\n";
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &mut sdp.medias[0];
+    ///
+    /// let pt = media.add_red(111).unwrap();
+    /// assert_eq!(pt, 96);
+    /// assert_eq!(media.red_for(111), Some(96));
+    /// assert!(media.add_red(0).is_err());
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111 96\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtpmap:96 red/48000/2\r\n\
+    ///     a=fmtp:96 111/111\r\n");
+    /// ```
+    pub fn add_red(&mut self, primary: u8) -> Result<u8> {
+        let codec = self.fmts
+            .contains(&primary)
+            .then(|| self.codec(primary))
+            .flatten()
+            .ok_or_else(|| anyhow!("payload type {} is not a known codec!", primary))?;
+        let red = RtpValue {
+            codec: Cow::Borrowed("red"),
+            frequency: codec.frequency,
+            channels: codec.channels,
+        };
+
+        let pt = self.add_codec(red)?;
+        self.attributes.push(Attributes::Fmtp(Fmtp {
+            key: pt,
+            values: smallvec![(Cow::Owned(format!("{}/{}", primary, primary)), None)],
+        }).into());
+        Ok(pt)
+    }
+}
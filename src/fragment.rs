@@ -0,0 +1,180 @@
+//! Session description fragments.
+//!
+//! Trickle ICE and partial renegotiation send parts of a description
+//! instead of all of it, e.g. the new candidates of one media in a SIP
+//! INFO with an "application/sdpfrag" body
+//! ([RFC8840](https://datatracker.ietf.org/doc/html/rfc8840#section-4.4)).
+//! A [`Fragment`] writes such a part as a standalone string.
+
+use super::attributes::{
+    Attribute,
+    Attributes,
+    Mid
+};
+
+use super::options::WriteOptions;
+use super::{
+    write_attribute,
+    write_media,
+    Sdp
+};
+
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Media,
+    Ice,
+}
+
+/// a part of a session description, see [`Sdp::media_fragment`] and
+/// [`Sdp::ice_fragment`].
+#[derive(Debug, Clone, Copy)]
+pub struct Fragment<'s, 'a> {
+    sdp: &'s Sdp<'a>,
+    index: usize,
+    kind: Kind,
+    options: &'s WriteOptions,
+}
+
+/// the position of an ICE attribute in a fragment, `None` for other
+/// attributes.
+fn ice_rank(attribute: &Attribute<'_>) -> Option<u8> {
+    match &attribute.value {
+        Attributes::Mid(_) => Some(0),
+        Attributes::Other(k, _) => match k.as_ref() {
+            "ice-options" => Some(1),
+            "ice-ufrag" => Some(2),
+            "ice-pwd" => Some(3),
+            "candidate" => Some(4),
+            "end-of-candidates" => Some(5),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// one media description, from its "m=" line to its last attribute,
+    /// written with the given options.  `None` if there is no media
+    /// with the index.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::options::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=mid:1\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let options = WriteOptions {
+    ///     order: AttributeOrder::WebRtc,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(sdp.media_fragment(1, &options).unwrap().to_string(), "m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n");
+    ///
+    /// assert!(sdp.media_fragment(2, &options).is_none());
+    /// ```
+    pub fn media_fragment<'s>(&'s self, index: usize, options: &'s WriteOptions) -> Option<Fragment<'s, 'a>> {
+        (index < self.medias.len()).then_some(Fragment {
+            sdp: self,
+            index,
+            kind: Kind::Media,
+            options,
+        })
+    }
+
+    /// the ICE attributes of the media with the given mid, as the body
+    /// of a trickle ICE update.
+    ///
+    /// The fragment has the session level "a=ice-options:",
+    /// "a=ice-ufrag:" and "a=ice-pwd:" lines, the "m=" line of the
+    /// media, its "a=mid:", its ICE credentials if they are at media
+    /// level, its "a=candidate:" lines and "a=end-of-candidates", in this
+    /// order.  `None` if no media has the mid.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::options::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=ice-ufrag:8hhY\r\n\
+    ///     a=ice-pwd:asd88fgpdd777uzjYhagZg\r\n\
+    ///     a=group:BUNDLE 1\r\n\
+    ///     m=audio 5000 RTP/AVP 0\r\n\
+    ///     a=candidate:1 1 UDP 1658497328 192.0.2.3 5000 typ host\r\n\
+    ///     a=rtpmap:0 PCMU/8000\r\n\
+    ///     a=end-of-candidates\r\n\
+    ///     a=mid:1\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let options = WriteOptions::default();
+    /// let fragment = sdp.ice_fragment(&Mid::Ref(1), &options).unwrap();
+    /// assert_eq!(fragment.to_string(), "a=ice-ufrag:8hhY\r\n\
+    ///     a=ice-pwd:asd88fgpdd777uzjYhagZg\r\n\
+    ///     m=audio 5000 RTP/AVP 0\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=candidate:1 1 UDP 1658497328 192.0.2.3 5000 typ host\r\n\
+    ///     a=end-of-candidates\r\n");
+    ///
+    /// assert!(sdp.ice_fragment(&Mid::Ref(2), &options).is_none());
+    /// ```
+    pub fn ice_fragment<'s>(&'s self, mid: &Mid<'_>, options: &'s WriteOptions) -> Option<Fragment<'s, 'a>> {
+        Some(Fragment {
+            sdp: self,
+            index: self.media_index(mid)?,
+            kind: Kind::Ice,
+            options,
+        })
+    }
+}
+
+impl fmt::Display for Fragment<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let media = &self.sdp.medias[self.index];
+        match self.kind {
+            Kind::Media => write_media(f, media, self.options),
+            Kind::Ice => {
+                let session = self.sdp.attributes
+                    .iter()
+                    .filter(|a| ice_rank(a).is_some_and(|v| (1..=3).contains(&v)));
+                let mut attributes = session.collect::<Vec<_>>();
+                attributes.sort_by_key(|a| ice_rank(a));
+                for v in attributes {
+                    write_attribute(f, v, self.options)?;
+                }
+
+                write!(f, "m={}\r\n", media)?;
+                let mut attributes = media.attributes
+                    .iter()
+                    .filter(|a| ice_rank(a).is_some())
+                    .collect::<Vec<_>>();
+                attributes.sort_by_key(|a| ice_rank(a));
+                for v in attributes {
+                    write_attribute(f, v, self.options)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
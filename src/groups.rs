@@ -0,0 +1,103 @@
+//! Media grouping.
+//!
+//! "a=group:" lines group media descriptions by mid with a semantics
+//! [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888), e.g. "LS"
+//! for media that are played in sync, "FID" for media that carry the
+//! same flow, or "BUNDLE" for media that share a transport, see
+//! [`Sdp::bundle_transports`].
+
+use super::attributes::{
+    Attributes,
+    Semantics
+};
+
+use super::Sdp;
+use alloc::vec::Vec;
+
+/// a "a=group:" line resolved to media descriptions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaGroup<'b, 'a> {
+    pub semantics: &'b Semantics<'a>,
+    /// indices of the media descriptions, in the order of the group.
+    pub medias: Vec<usize>,
+}
+
+impl MediaGroup<'_, '_> {
+    /// whether the group contains the media description.
+    pub fn contains(&self, index: usize) -> bool {
+        self.medias.contains(&index)
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// the "a=group:" lines of the session, with the indices of their
+    /// media descriptions.  Mids without a media description are
+    /// skipped.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:LS a v\r\n\
+    ///     a=group:FID v x r\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=mid:a\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n\
+    ///     a=mid:v\r\n\
+    ///     m=video 9 RTP/AVP 97\r\n\
+    ///     a=mid:r\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let groups = sdp.groups();
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups[0].semantics, &Semantics::Ls);
+    /// assert_eq!(groups[0].medias, vec![0, 1]);
+    /// assert_eq!(groups[1].semantics, &Semantics::Fid);
+    /// assert_eq!(groups[1].medias, vec![1, 2]);
+    ///
+    /// let synced = sdp.grouped_with(&Semantics::Ls, 1);
+    /// assert_eq!(synced, vec![0]);
+    /// assert!(sdp.grouped_with(&Semantics::Ls, 2).is_empty());
+    /// ```
+    pub fn groups(&self) -> Vec<MediaGroup<'_, 'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|a| match &a.value {
+                Attributes::Group(v) => Some(v),
+                _ => None,
+            })
+            .map(|group| MediaGroup {
+                semantics: &group.semantics,
+                medias: group.mids
+                    .iter()
+                    .filter_map(|mid| self.media_index(mid))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// the other media descriptions that share a group of the given
+    /// semantics with the media description, e.g. the media to play in
+    /// sync with it for "LS".
+    pub fn grouped_with(&self, semantics: &Semantics<'_>, index: usize) -> Vec<usize> {
+        let mut medias = Vec::new();
+        for group in self.groups() {
+            if group.semantics != semantics || !group.contains(index) {
+                continue;
+            }
+
+            for i in group.medias {
+                if i != index && !medias.contains(&i) {
+                    medias.push(i);
+                }
+            }
+        }
+
+        medias
+    }
+}
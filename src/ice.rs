@@ -0,0 +1,215 @@
+//! Interactive Connectivity Establishment.
+//!
+//! ICE candidates are kept as "a=candidate:" attributes, the helpers
+//! here read the fields they need from the attribute value
+//! [RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5.1).
+
+use super::attributes::{
+    Attribute,
+    Attributes
+};
+
+use super::scanner::Scanner;
+use super::Sdp;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+/// the attributes of the ICE agent of a transport, which are replaced
+/// together by [`Sdp::replace_ice`].
+const ICE_ATTRIBUTES: [&str; 5] = [
+    "ice-ufrag",
+    "ice-pwd",
+    "candidate",
+    "end-of-candidates",
+    "remote-candidates",
+];
+
+fn is_ice(attribute: &Attribute<'_>) -> bool {
+    match &attribute.value {
+        Attributes::IceMismatch => true,
+        Attributes::Other(k, _) => ICE_ATTRIBUTES.contains(&k.as_ref()),
+        _ => false,
+    }
+}
+
+fn other<'a>(name: &'static str, value: &Cow<'a, str>) -> Attribute<'a> {
+    Attributes::Other(Cow::Borrowed(name), Some(value.clone())).into()
+}
+
+/// the component, connection address and port of a "a=candidate:"
+/// value, `None` when the value is malformed.
+///
+/// candidate-attribute = "candidate" ":" foundation SP component-id SP
+///     transport SP priority SP connection-address SP port SP
+///     cand-type ...
+pub(crate) fn candidate(value: &str) -> Option<(u16, IpAddr, u16)> {
+    let mut fields = Scanner::new(value, "candidate");
+    let _foundation = fields.field().ok()?;
+    let component = fields.field().ok()?.parse().ok()?;
+    let _transport = fields.field().ok()?;
+    let _priority = fields.field().ok()?;
+    let address = fields.field().ok()?.parse().ok()?;
+    let port = fields.field().ok()?.parse().ok()?;
+    Some((component, address, port))
+}
+
+impl<'a> Sdp<'a> {
+    /// whether the default destination of the media does not match any
+    /// of its RTP candidates, the ICE mismatch condition
+    /// [RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5.5).
+    ///
+    /// The default destination is the connection address of the media,
+    /// or of the session, and the port of the "m=" line.  Media without
+    /// candidates, rejected media and media with the "0.0.0.0" / "::"
+    /// and port 9 placeholder of a trickle offer never mismatch.  An
+    /// answerer that detects the condition answers with
+    /// "a=ice-mismatch".  `None` if there is no media with the index.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     c=IN IP4 192.0.2.1\r\n\
+    ///     m=audio 45664 RTP/AVP 0\r\n\
+    ///     a=candidate:1 1 UDP 2130706431 192.0.2.1 45664 typ host\r\n\
+    ///     m=audio 45666 RTP/AVP 0\r\n\
+    ///     c=IN IP4 198.51.100.1\r\n\
+    ///     a=candidate:1 1 UDP 2130706431 192.0.2.1 45666 typ host\r\n\
+    ///     a=candidate:1 2 UDP 2130706430 198.51.100.1 45666 typ host\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=candidate:1 1 UDP 2130706431 192.0.2.1 45668 typ host\r\n\
+    ///     m=audio 45670 RTP/AVP 0\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let mismatch = (0..5).map(|i| sdp.ice_mismatch(i)).collect::<Vec<_>>();
+    /// assert_eq!(mismatch, vec![Some(false), Some(true), Some(false), Some(false), None]);
+    /// ```
+    pub fn ice_mismatch(&self, index: usize) -> Option<bool> {
+        let media = self.medias.get(index)?;
+        let port = media.port.num;
+        if port == 0 {
+            return Some(false);
+        }
+
+        let connections = self.connection_for(index)?;
+        let placeholder = port == 9 && connections.iter().all(|c| c.connection_address.ip.is_unspecified());
+        if placeholder {
+            return Some(false);
+        }
+
+        let mut candidates = media.attributes.iter().filter_map(|a| match &a.value {
+            Attributes::Other(k, Some(v)) if k == "candidate" => candidate(v),
+            _ => None,
+        });
+
+        let mut found = false;
+        let matched = candidates.any(|(component, address, candidate_port)| {
+            found = true;
+            component == 1
+                && candidate_port == port
+                && connections.iter().any(|c| c.connection_address.ip == address)
+        });
+
+        Some(found && !matched)
+    }
+
+    /// replace the ICE credentials and candidates of the session, for
+    /// back-to-back user agents and border controllers that terminate
+    /// ICE on both sides.
+    ///
+    /// Every "a=ice-ufrag:", "a=ice-pwd:", "a=candidate:",
+    /// "a=end-of-candidates", "a=remote-candidates:" and "a=ice-mismatch"
+    /// is removed.  The credentials are added at the session level if
+    /// the description had them there, and to every media that is not
+    /// rejected otherwise.  The candidates are added to the media that
+    /// own a transport, once per BUNDLE group.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0 1 3\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=ice-pwd:7Jc2gUuIeA4T5tLOP0+lW9sP\r\n\
+    ///     a=candidate:1 1 udp 2122260223 192.0.2.1 54400 typ host\r\n\
+    ///     a=end-of-candidates\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=ice-pwd:7Jc2gUuIeA4T5tLOP0+lW9sP\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:2\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:3\r\n\
+    ///     a=bundle-only\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.replace_ice(
+    ///     "b2bu".into(),
+    ///     "ZyuHU2a9rs7gBEnOCmEXBpqq".into(),
+    ///     &["1 1 udp 2122260223 198.51.100.1 40000 typ host".into()],
+    /// );
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     a=group:BUNDLE 0 1 3\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=ice-ufrag:b2bu\r\n\
+    ///     a=ice-pwd:ZyuHU2a9rs7gBEnOCmEXBpqq\r\n\
+    ///     a=candidate:1 1 udp 2122260223 198.51.100.1 40000 typ host\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=ice-ufrag:b2bu\r\n\
+    ///     a=ice-pwd:ZyuHU2a9rs7gBEnOCmEXBpqq\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:2\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:3\r\n\
+    ///     a=bundle-only\r\n\
+    ///     a=ice-ufrag:b2bu\r\n\
+    ///     a=ice-pwd:ZyuHU2a9rs7gBEnOCmEXBpqq\r\n");
+    /// ```
+    pub fn replace_ice(&mut self, ufrag: Cow<'a, str>, pwd: Cow<'a, str>, candidates: &[Cow<'a, str>]) {
+        let session = self.attributes
+            .iter()
+            .any(|a| matches!(&a.value, Attributes::Other(k, Some(_)) if k == "ice-ufrag"));
+        let transports = (0..self.medias.len())
+            .map(|i| self.transport_media(i) == i)
+            .collect::<Vec<_>>();
+
+        self.attributes.retain(|a| !is_ice(a));
+        if session {
+            self.attributes.push(other("ice-ufrag", &ufrag));
+            self.attributes.push(other("ice-pwd", &pwd));
+        }
+
+        for (media, transport) in self.medias.iter_mut().zip(transports) {
+            media.attributes.retain(|a| !is_ice(a));
+            if media.is_rejected() {
+                continue;
+            }
+
+            if !session {
+                media.attributes.push(other("ice-ufrag", &ufrag));
+                media.attributes.push(other("ice-pwd", &pwd));
+            }
+
+            if transport {
+                media.attributes.extend(candidates.iter().map(|v| other("candidate", v)));
+            }
+        }
+    }
+}
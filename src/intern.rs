@@ -0,0 +1,209 @@
+use super::attributes::{
+    KNOWN_EXTENSIONS,
+    Attributes,
+    Attribute,
+    SsrcAttr
+};
+
+use super::media::Media;
+use super::Sdp;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::format;
+
+/// codec names of the RTP payload format registry that are common in
+/// WebRTC and SIP descriptions.
+const CODECS: &[&str] = &[
+    "opus", "PCMU", "PCMA", "G722", "ISAC", "ILBC", "CN", "telephone-event",
+    "VP8", "VP9", "H264", "H265", "AV1", "rtx", "red", "ulpfec", "flexfec-03",
+];
+
+/// names of the attributes kept as [`Attributes::Other`].
+const ATTRIBUTES: &[&str] = &[
+    "ice-ufrag", "ice-pwd", "ice-options", "ice-lite", "candidate",
+    "end-of-candidates", "rtcp-mux", "rtcp-rsize", "rtcp-fb", "crypto",
+];
+
+/// Token interner.
+///
+/// Parsed owned descriptions hold a copy of every string, so a server
+/// that keeps many descriptions stores the same codec names, header
+/// extension URIs and attribute names many times.  The interner replaces
+/// owned strings that are equal to one of its tokens with a string
+/// borrowed from the interner, see [`Sdp::intern`], so the descriptions
+/// share one copy for as long as the interner lives.
+///
+/// The default interner knows the common codec names, the known header
+/// extension URIs and the attribute names kept as [`Attributes::Other`].
+/// Applications can add their own tokens, e.g. the cnames of long lived
+/// sources, which the interner owns.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::intern::*;
+///
+/// let mut interner = Interner::default();
+/// assert_eq!(interner.get("opus"), Some("opus"));
+/// assert_eq!(interner.get("urn:ietf:params:rtp-hdrext:sdes:mid"), Some("urn:ietf:params:rtp-hdrext:sdes:mid"));
+/// assert_eq!(interner.get("panda"), None);
+///
+/// interner.insert("panda");
+/// interner.insert(String::from("cname-1"));
+/// assert_eq!(interner.get("panda"), Some("panda"));
+/// assert_eq!(interner.get("cname-1"), Some("cname-1"));
+/// assert_eq!(Interner::new().get("opus"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interner {
+    tokens: BTreeSet<Cow<'static, str>>,
+}
+
+impl Interner {
+    /// an interner without tokens.
+    pub fn new() -> Self {
+        Self {
+            tokens: BTreeSet::new(),
+        }
+    }
+
+    /// add a token, owned strings are kept by the interner.
+    pub fn insert(&mut self, token: impl Into<Cow<'static, str>>) {
+        self.tokens.insert(token.into());
+    }
+
+    /// the shared token equal to the string.
+    pub fn get(&self, value: &str) -> Option<&str> {
+        self.tokens.get(value).map(|v| v.as_ref())
+    }
+
+    /// share an owned string if it is a token, and return whether it
+    /// was replaced.
+    fn share<'i>(&'i self, value: &mut Cow<'i, str>) -> bool {
+        let token = match value {
+            Cow::Owned(v) => self.get(v),
+            Cow::Borrowed(_) => None,
+        };
+
+        if let Some(token) = token {
+            *value = Cow::Borrowed(token);
+        }
+
+        token.is_some()
+    }
+
+    /// share the strings of the attributes, and drop the raw text of
+    /// attributes that are written as they appeared in the input.
+    fn attributes<'i>(&'i self, attributes: &mut [Attribute<'i>]) -> usize {
+        let mut count = 0;
+        for attribute in attributes {
+            if let Some(raw @ Cow::Owned(_)) = &mut attribute.raw {
+                if self.share(raw) {
+                    count += 1;
+                } else if format!("{}", attribute.value) == **raw {
+                    attribute.raw = None;
+                    count += 1;
+                }
+            }
+
+            count += match &mut attribute.value {
+                Attributes::Rtpmap(v) => self.share(&mut v.value.codec) as usize,
+                Attributes::Extmap(v) => self.share(&mut v.value) as usize,
+                Attributes::Ssrc(v) => match &mut v.value {
+                    SsrcAttr::Cname(v)
+                    | SsrcAttr::MsLabel(v)
+                    | SsrcAttr::Label(v) => self.share(v) as usize,
+                    _ => 0,
+                },
+                Attributes::Other(k, v) => {
+                    self.share(k) as usize + v.as_mut().map_or(0, |v| self.share(v) as usize)
+                }
+                _ => 0,
+            };
+        }
+
+        count
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        let mut interner = Self::new();
+        let extensions = KNOWN_EXTENSIONS.iter().map(|(_, uri)| *uri);
+        for token in CODECS.iter().copied().chain(ATTRIBUTES.iter().copied()).chain(extensions) {
+            interner.insert(token);
+        }
+
+        interner
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// replace the owned strings that are tokens of the interner with
+    /// strings borrowed from it, and return the number of replaced or
+    /// dropped strings.
+    ///
+    /// Only codec names, header extension URIs, "a=ssrc:" cnames and
+    /// labels, the names and values of [`Attributes::Other`] and the raw
+    /// text of attributes are interned.  The raw text of an attribute
+    /// that is written exactly as it appeared in the input is dropped,
+    /// as the value already holds it.  Borrowed strings are left as they
+    /// are.
+    ///
+    /// The description borrows the interner afterwards, owned
+    /// descriptions take its lifetime with [`Sdp::into_owned`], which
+    /// does not copy strings that are already owned.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::intern::*;
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::borrow::Cow;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=ssrc:1 cname:panda\r\n";
+    ///
+    /// let interner = Interner::default();
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.intern(&interner), 0);
+    ///
+    /// // 3 tokens, and the raw text of the 4 attributes.
+    /// let mut sdp: Sdp = Sdp::try_from(temp).unwrap().into_owned();
+    /// assert_eq!(sdp.intern(&interner), 7);
+    ///
+    /// let attribute = &sdp.medias[0].attributes[0];
+    /// let Attributes::Rtpmap(rtpmap) = &attribute.value else { panic!() };
+    /// assert!(matches!(rtpmap.value.codec, Cow::Borrowed("opus")));
+    /// assert!(attribute.raw.is_none());
+    ///
+    /// let mut cnames = Interner::new();
+    /// cnames.insert(String::from("panda"));
+    /// let mut sdp: Sdp = Sdp::try_from(temp).unwrap().into_owned();
+    /// assert_eq!(sdp.intern(&cnames), 4 + 1);
+    /// assert_eq!(sdp.to_string(), temp);
+    ///
+    /// // raw text that is not written back as it is, is kept.
+    /// let mut sdp: Sdp = Sdp::try_from("v=0\r\ns=-\r\na=ptime:020\r\n").unwrap().into_owned();
+    /// assert_eq!(sdp.intern(&interner), 0);
+    /// assert!(sdp.attributes[0].raw.is_some());
+    /// ```
+    pub fn intern(&mut self, interner: &'a Interner) -> usize {
+        interner.attributes(&mut self.attributes)
+            + self.medias.iter_mut().map(|m| m.intern(interner)).sum::<usize>()
+    }
+}
+
+impl<'a> Media<'a> {
+    /// see [`Sdp::intern`].
+    pub fn intern(&mut self, interner: &'a Interner) -> usize {
+        interner.attributes(&mut self.attributes)
+    }
+}
@@ -1,37 +1,118 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod attributes;
 pub mod encryption;
 pub mod repeat_times;
 pub mod time_zones;
 pub mod connection;
+pub mod contact;
 pub mod bandwidth;
 pub mod origin;
+pub mod version;
 pub mod timing;
+pub mod options;
+pub mod lines;
 pub mod media;
+#[cfg(feature = "rtsp")]
+pub mod rtsp;
+pub mod bundle;
+pub mod groups;
+pub mod payload;
+pub mod codec;
+pub mod fec;
+pub mod extmap;
+#[cfg(feature = "webrtc")]
+pub mod webrtc;
+pub mod sap;
+pub mod multipart;
+pub mod explain;
+pub mod summary;
+pub mod normalize;
+pub mod negotiate;
+pub mod ice;
+pub mod edit;
+pub mod fragment;
+pub mod intern;
+pub mod validate;
 pub mod util;
+pub mod scanner;
+pub mod span;
+
+#[cfg(feature = "std")]
+pub mod reader;
+
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+
+#[cfg(feature = "webrtc-rs")]
+pub mod webrtc_rs;
 
 use encryption::EncryptionKey;
-use repeat_times::RepeatTimes;
 use attributes::Attributes;
+use options::{
+    AttributeOrder,
+    DuplicatePolicy,
+    FeedbackWildcards,
+    ParseOptions,
+    WriteOptions,
+    Limit
+};
+use lines::{
+    Lines,
+    Line,
+    RawLine
+};
+use repeat_times::RepeatTimes;
+use attributes::{
+    Attribute,
+    Kind,
+    Mid,
+    Orient
+};
 use connection::Connection;
+use contact::Contact;
 use time_zones::TimeZones;
 use bandwidth::Bandwidth;
 use timing::Timing;
 use origin::Origin;
-use media::Media;
+use version::Version;
+use media::{
+    Media,
+    Section
+};
+use span::{
+    Span,
+    Spans
+};
+use normalize::webrtc_rank;
 
 use anyhow::{
+    Context,
     ensure,
     anyhow
 };
 
-use std::{
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{
     convert::TryFrom,
-    fmt
+    str::FromStr,
+    fmt::{
+        self,
+        Write
+    }
 };
 
 /// Sdp keys.
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Key {
+    Version,
     Origin,
     SessionName,
     SessionInfo,
@@ -49,14 +130,20 @@ pub enum Key {
 }
 
 /// Network type.
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NetKind {
     /// Internet
     IN,
 }
 
 /// Address type.
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddrKind {
     /// Ipv4
     IP4,
@@ -101,8 +188,12 @@ pub enum AddrKind {
 /// either side of the "=" sign, however, the value can contain a leading
 /// whitespace as part of its syntax, i.e., that whitespace is part of
 /// the value.
-#[derive(Debug, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Sdp<'a> {
+    /// Protocol Version ("v=")
+    pub version: Version,
     /// Origin ("o=")
     pub origin: Option<Origin<'a>>,
     /// Session Name ("s=")
@@ -114,7 +205,7 @@ pub struct Sdp<'a> {
     /// attribute is present, it specifies the character set used in the "s="
     /// field.  If a session-level "a=charset:" attribute is not present, the
     /// "s=" field MUST contain ISO 10646 characters in UTF-8 encoding.
-    pub session_name: Option<&'a str>,
+    pub session_name: Option<Cow<'a, str>>,
     /// Session Information ("i=")
     /// The "i=" line (information-field) provides textual information about
     /// the session.  There can be at most one session-level "i=" line per
@@ -135,22 +226,23 @@ pub struct Sdp<'a> {
     /// The "i=" line is intended to provide a free-form human-readable
     /// description of the session or the purpose of a media stream.  It is
     /// not suitable for parsing by automata.
-    pub session_info: Option<&'a str>,
+    pub session_info: Option<Cow<'a, str>>,
     /// URI ("u=")
     /// The "u=" line (uri-field) provides a URI (Uniform Resource
     /// Identifier) [RFC3986](https://datatracker.ietf.org/doc/html/rfc3986).  
     /// The URI should be a pointer to additional human readable information 
     /// about the session.  This line is OPTIONAL. No more than one "u=" 
     /// line is allowed per session description.
-    pub uri: Option<&'a str>,
+    pub uri: Option<Cow<'a, str>>,
     /// Email Address and Phone Number ("e=" and "p=")
     /// The "e=" line (email-field) and "p=" line (phone-field) specify
     /// contact information for the person responsible for the session.  This
     /// is not necessarily the same person that created the session
-    /// description.
-    pub email: Option<&'a str>,
+    /// description.  Multiple lines of both kinds are allowed, in input
+    /// order.
+    pub emails: Vec<Contact<'a>>,
     /// Phone number ("p=")
-    pub phone: Option<&'a str>,
+    pub phones: Vec<Contact<'a>>,
     /// Connection Information ("c=")
     pub connection: Option<Connection>,
     /// Bandwidth ("b=")
@@ -164,59 +256,812 @@ pub struct Sdp<'a> {
     /// encryption key ("k=")
     pub encryption_key: Option<EncryptionKey<'a>>,
     /// Attributes ("a=")
-    pub attributes: Vec<Attributes<'a>>,
+    pub attributes: Vec<Attribute<'a>>,
     /// Media ("m=")
     pub medias: Vec<Media<'a>>,
+    /// Lines of unknown type that appeared before the first media
+    /// description, in input order.
+    pub unknown_lines: Vec<RawLine<'a>>,
+    /// The positions of the lines in the input, except for the "a="
+    /// lines, by session and media description.
+    pub spans: Spans,
 }
 
 impl<'a> Sdp<'a> {
-    fn handle_line(&mut self, key: Key, data: &'a str, in_media: &mut bool) -> anyhow::Result<()> {
-        Ok(match key {
-            Key::Origin => self.origin = Some(Origin::try_from(data)?),
-            Key::SessionName => self.session_name = util::placeholder(data),
-            Key::SessionInfo => self.session_info = util::placeholder(data),
-            Key::Uri => self.uri = util::placeholder(data),
-            Key::Email => self.email = util::placeholder(data),
-            Key::Phone => self.phone = util::placeholder(data),
-            Key::Connection => self.connection = Some(Connection::try_from(data)?),
-            Key::Bandwidth => self.bandwidth.push(Bandwidth::try_from(data)?),
-            Key::Timing => self.timing = Some(Timing::try_from(data)?),
-            Key::RepeatTimes => self.repeat_times = Some(RepeatTimes::try_from(data)?),
-            Key::TimeZones => self.time_zones.push(TimeZones::try_from(data)?),
-            Key::EncryptionKey => self.encryption_key = Some(EncryptionKey::try_from(data)?),
-            Key::Attributes => {
-                if *in_media {
-                    if let Some(medias) = self.medias.last_mut() {
-                        medias.push(data)?;
+    /// parse a session description with the given options.
+    ///
+    /// Errors of a line carry its [`span::Span`] as context, which
+    /// `{:#}` writes before the error and `downcast_ref` returns.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use sdp::options::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nt=0 0\r\n";
+    /// assert!(Sdp::parse(temp, &ParseOptions::strict()).is_ok());
+    ///
+    /// let temp = "v=0\ns=-\nt=0 0";
+    /// assert!(Sdp::parse(temp, &ParseOptions::strict()).is_err());
+    ///
+    /// let sdp = Sdp::parse(temp, &ParseOptions::default()).unwrap();
+    /// assert_eq!(sdp.timing.unwrap().start, 0);
+    ///
+    /// // unknown versions are only accepted in lenient mode.
+    /// let temp = "v=1\r\ns=-\r\nt=0 0\r\n";
+    /// let err = Sdp::parse(temp, &ParseOptions::strict()).unwrap_err();
+    /// assert_eq!(format!("{:#}", err), "line 1: unsupported version 1!");
+    ///
+    /// let sdp = Sdp::parse(temp, &ParseOptions::default()).unwrap();
+    /// assert_eq!(sdp.version, version::Version(1));
+    /// assert_eq!(sdp.to_string(), temp);
+    ///
+    /// // so are values of "a=type:" and "a=orient:" that are not one of
+    /// // their case-sensitive names.
+    /// let temp = "v=0\r\ns=-\r\nt=0 0\r\na=type:Meeting\r\n";
+    /// let err = Sdp::parse(temp, &ParseOptions::strict()).unwrap_err();
+    /// assert_eq!(format!("{:#}", err), "line 4: invalid type!");
+    /// assert_eq!(&temp[err.downcast_ref::<span::Span>().unwrap().range()], "a=type:Meeting");
+    ///
+    /// let sdp = Sdp::parse(temp, &ParseOptions::default()).unwrap();
+    /// assert_eq!(sdp.to_string(), temp);
+    /// ```
+    pub fn parse(value: &'a str, options: &ParseOptions) -> anyhow::Result<Self> {
+        let mut sdp = Self::default();
+        let mut in_media = false;
+        let mut section = Span::default();
+        for (span, line) in Lines::new(value, options).spanned() {
+            match &line {
+                Ok(Line::Media(_)) => {
+                    sdp.end_section(value, section);
+                    section = span;
+                }
+                _ if in_media => section.end = span.end,
+                _ => (),
+            }
+
+            let line = match line.context(span)? {
+                Line::Attribute(v) => Line::Attribute(Attribute {
+                    span: Some(span),
+                    ..v
+                }),
+                Line::Unknown(v) => {
+                    sdp.handle_unknown(RawLine {
+                        text: Cow::Borrowed(v),
+                        span: Some(span),
+                    }, in_media);
+                    continue;
+                }
+                line => line,
+            };
+
+            let key = line.key();
+            sdp.handle_line(line, &mut in_media, options).context(span)?;
+            if let Some(key) = key.filter(|k| *k != Key::Attributes) {
+                sdp.spans.push(key, span);
+            }
+        }
+
+        sdp.end_section(value, section);
+        Ok(sdp)
+    }
+
+    /// parse a session description into an arena.
+    ///
+    /// All vectors of the parsed description are allocated in the given
+    /// arena instead of the global allocator.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let temp = "v=0\r\ns=-\r\nt=0 0\r\na=type:meeting\r\nm=audio 9 RTP/AVP 0\r\na=ptime:20\r\n";
+    /// let sdp = Sdp::parse_in(&bump, temp).unwrap();
+    ///
+    /// assert_eq!(sdp.lines.len(), 4);
+    /// assert_eq!(sdp.attributes().count(), 1);
+    /// assert_eq!(sdp.medias.len(), 1);
+    /// assert_eq!(sdp.medias[0].attributes().count(), 1);
+    /// ```
+    #[cfg(feature = "bumpalo")]
+    pub fn parse_in<'bump>(
+        bump: &'bump bumpalo::Bump, 
+        value: &'a str
+    ) -> anyhow::Result<arena::ArenaSdp<'bump, 'a>> {
+        arena::ArenaSdp::parse(bump, value, &ParseOptions::default())
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = String::from("v=0\r\ns=panda\r\nt=0 0\r\n");
+    /// let sdp: Sdp<'static> = Sdp::try_from(temp.as_str()).unwrap().into_owned();
+    /// drop(temp);
+    ///
+    /// assert_eq!(sdp.session_name.as_deref(), Some("panda"));
+    ///
+    /// // borrowed descriptions can be modified in place, only the
+    /// // replaced values are owned.
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=ssrc:1 cname:panda\r\n";
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// if let attributes::Attributes::Ssrc(ssrc) = &mut sdp.medias[0].attributes[0].value {
+    ///     ssrc.value = attributes::SsrcAttr::Cname(format!("{}-1", "panda").into());
+    /// }
+    ///
+    /// assert_eq!(
+    ///     sdp.to_string(),
+    ///     "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=ssrc:1 cname:panda-1\r\n"
+    /// );
+    /// ```
+    pub fn into_owned<'b>(self) -> Sdp<'b> {
+        let owned = |v: Option<Cow<'a, str>>| v.map(|v| Cow::Owned(v.into_owned()));
+        Sdp {
+            version: self.version,
+            origin: self.origin.map(Origin::into_owned),
+            session_name: owned(self.session_name),
+            session_info: owned(self.session_info),
+            uri: owned(self.uri),
+            emails: self.emails
+                .into_iter()
+                .map(Contact::into_owned)
+                .collect(),
+            phones: self.phones
+                .into_iter()
+                .map(Contact::into_owned)
+                .collect(),
+            connection: self.connection,
+            bandwidth: self.bandwidth,
+            timing: self.timing,
+            repeat_times: self.repeat_times,
+            time_zones: self.time_zones,
+            encryption_key: self.encryption_key.map(EncryptionKey::into_owned),
+            attributes: self.attributes
+                .into_iter()
+                .map(Attribute::into_owned)
+                .collect(),
+            medias: self.medias
+                .into_iter()
+                .map(Media::into_owned)
+                .collect(),
+            unknown_lines: self.unknown_lines
+                .into_iter()
+                .map(RawLine::into_owned)
+                .collect(),
+            spans: self.spans,
+        }
+    }
+
+    /// a JSON tree of the description, for debugging and test snapshots.
+    ///
+    /// Every line and typed attribute is a JSON value, enums are written
+    /// as `{"Variant": value}` and flags as `"Variant"`.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-mux\r\n";
+    ///
+    /// let json = Sdp::try_from(temp).unwrap().to_json_value();
+    /// assert_eq!(json["origin"]["sess_id"], "4611731400430051336");
+    /// assert_eq!(json["timing"]["stop"], 0);
+    /// assert_eq!(json["medias"][0]["encoding"], "Audio");
+    /// assert_eq!(json["medias"][0]["connections"][0]["connection_address"]["ip"], "0.0.0.0");
+    ///
+    /// let attribute = &json["medias"][0]["attributes"][0];
+    /// assert_eq!(attribute["raw"], "rtpmap:111 opus/48000/2");
+    /// assert_eq!(attribute["value"]["Rtpmap"]["key"], 111);
+    /// assert_eq!(attribute["value"]["Rtpmap"]["value"]["codec"], "opus");
+    /// assert_eq!(json["medias"][0]["attributes"][1]["value"]["Other"][0], "rtcp-mux");
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        // the description has no maps, serializing it to a value can not
+        // fail.
+        serde_json::to_value(self).expect("serialize session description")
+    }
+
+    /// the effective connection lines of a media description.
+    ///
+    /// The media level "c=" lines if present, else the session level
+    /// one.  Layered multicast sessions have one line per layer, in
+    /// input order.  The result is empty when neither level has a
+    /// connection line, and `None` if there is no media with the index.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     c=IN IP4 192.0.2.1\r\n\
+    ///     m=audio 49170 RTP/AVP 0\r\n\
+    ///     m=video 51372 RTP/AVP 99\r\n\
+    ///     c=IN IP4 233.252.0.1/127\r\n\
+    ///     c=IN IP4 233.252.0.2/127\r\n\
+    ///     a=rtpmap:99 h263-1998/90000\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.connection_for(0), Some(sdp.connection.as_slice()));
+    /// assert_eq!(sdp.medias[0].connections.len(), 0);
+    /// assert_eq!(sdp.connection_for(2), None);
+    ///
+    /// let connections = sdp.connection_for(1).unwrap();
+    /// assert_eq!(connections.len(), 2);
+    /// assert_eq!(connections[1].connection_address.ip.to_string(), "233.252.0.2");
+    /// assert_eq!(connections[1].connection_address.ttl, Some(127));
+    /// assert_eq!(sdp.connection.unwrap().connection_address.ip.to_string(), "192.0.2.1");
+    ///
+    /// let sdp = Sdp::try_from("v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\n").unwrap();
+    /// assert!(sdp.connection_for(0).unwrap().is_empty());
+    /// ```
+    pub fn connection_for(&self, index: usize) -> Option<&[Connection]> {
+        Some(match &self.medias.get(index)?.connections {
+            v if v.is_empty() => self.connection.as_slice(),
+            v => v,
+        })
+    }
+
+    /// the index of the media description with the given mid.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=mid:a0\r\nm=video 9 RTP/AVP 96\r\na=mid:1\r\n";
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.media_index(&Mid::Ref(1)), Some(1));
+    /// assert_eq!(sdp.media_index(&Mid::try_from("a0").unwrap()), Some(0));
+    /// assert_eq!(sdp.media_index(&Mid::Ref(2)), None);
+    /// ```
+    pub fn media_index(&self, mid: &Mid<'_>) -> Option<usize> {
+        self.medias.iter().position(|m| m.mid() == Some(mid))
+    }
+
+    /// whether the "o=" line of this description is a later version
+    /// of the one in `other`, see [`Origin::newer_than`].
+    ///
+    /// A signaling layer uses this to drop stale or reordered
+    /// descriptions.  `false` if either description has no "o=" line.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let old = Sdp::try_from("v=0\r\no=- 4611731400430051336 2 IN IP4 127.0.0.1\r\ns=-\r\n").unwrap();
+    /// let new = Sdp::try_from("v=0\r\no=- 4611731400430051336 3 IN IP4 127.0.0.1\r\ns=-\r\n").unwrap();
+    /// let other = Sdp::try_from("v=0\r\no=- 1 4 IN IP4 127.0.0.1\r\ns=-\r\n").unwrap();
+    ///
+    /// assert!(new.origin_newer_than(&old));
+    /// assert!(!old.origin_newer_than(&new));
+    /// assert!(!new.origin_newer_than(&new));
+    /// assert!(!other.origin_newer_than(&old));
+    ///
+    /// let none = Sdp::try_from("v=0\r\ns=-\r\n").unwrap();
+    /// assert!(!new.origin_newer_than(&none));
+    /// ```
+    pub fn origin_newer_than(&self, other: &Sdp<'_>) -> bool {
+        match (&self.origin, &other.origin) {
+            (Some(a), Some(b)) => a.newer_than(b),
+            _ => false,
+        }
+    }
+
+    /// remember the input text of the last media description.
+    fn end_section(&mut self, value: &'a str, span: Span) {
+        if let Some(media) = self.medias.last_mut() {
+            media.section = Some(Section {
+                text: &value[span.range()],
+                span,
+            });
+        }
+    }
+
+    /// keep a line of unknown type with the current session or media
+    /// description.
+    fn handle_unknown(&mut self, line: RawLine<'a>, in_media: bool) {
+        match self.medias.last_mut() {
+            Some(media) if in_media => media.unknown_lines.push(line),
+            _ => self.unknown_lines.push(line),
+        }
+    }
+
+    /// handle a text line, "s=", "i=", "u=", "e=" or "p=".  The value
+    /// is borrowed from the input, or owned when the input is read
+    /// line by line.
+    fn handle_text(&mut self, key: Key, value: Cow<'a, str>, in_media: bool) -> anyhow::Result<()> {
+        let value = Some(value).filter(|v| v != "-");
+        match key {
+            Key::SessionName => self.session_name = value,
+            Key::SessionInfo => match self.medias.last_mut() {
+                Some(media) if in_media => media.info = value,
+                _ => self.session_info = value,
+            },
+            Key::Uri => self.uri = value,
+            Key::Email | Key::Phone => if let Some(v) = value {
+                let contact = match v {
+                    Cow::Borrowed(v) => Contact::try_from(v)?,
+                    Cow::Owned(v) => Contact::try_from(v.as_str())?.into_owned(),
+                };
+
+                match key {
+                    Key::Email => self.emails.push(contact),
+                    _ => self.phones.push(contact),
+                }
+            },
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    fn handle_line(
+        &mut self, 
+        line: Line<'a>, 
+        in_media: &mut bool, 
+        options: &ParseOptions
+    ) -> anyhow::Result<()> {
+        let limits = &options.limits;
+        match line {
+            Line::Version(v) => {
+                ensure!(!options.strict || v.is_supported(), "unsupported version {}!", v);
+                self.version = v;
+            },
+            Line::Origin(v) => self.origin = Some(v),
+            Line::SessionName(v) => self.handle_text(Key::SessionName, Cow::Borrowed(v), *in_media)?,
+            Line::SessionInfo(v) => self.handle_text(Key::SessionInfo, Cow::Borrowed(v), *in_media)?,
+            Line::Uri(v) => self.handle_text(Key::Uri, Cow::Borrowed(v), *in_media)?,
+            Line::Email(v) => self.handle_text(Key::Email, Cow::Borrowed(v), *in_media)?,
+            Line::Phone(v) => self.handle_text(Key::Phone, Cow::Borrowed(v), *in_media)?,
+            Line::Connection(v) => match self.medias.last_mut() {
+                Some(media) if *in_media => media.connections.push(v),
+                _ => self.connection = Some(v),
+            },
+            Line::Bandwidth(v) => match self.medias.last_mut() {
+                Some(media) if *in_media => media.bandwidth.push(v),
+                _ => self.bandwidth.push(v),
+            },
+            Line::Timing(v) => self.timing = Some(v),
+            Line::RepeatTimes(v) => self.repeat_times = Some(v),
+            Line::TimeZones(v) => self.time_zones.push(v),
+            Line::EncryptionKey(v) => self.encryption_key = Some(v),
+            Line::Attribute(v) => {
+                if let Attributes::Fmtp(fmtp) = &v.value {
+                    limits.check(Limit::FmtpParams, fmtp.values.len())?;
+                }
+
+                let attributes = match self.medias.last_mut() {
+                    Some(media) if *in_media => &mut media.attributes,
+                    _ => &mut self.attributes,
+                };
+
+                limits.check(Limit::Attributes, attributes.len() + 1)?;
+                options.check_usage(&v, *in_media)?;
+                if let (true, Attributes::Mid(mid)) = (options.strict, &v.value) {
+                    mid.check()?;
+                }
+
+                if let (true, Attributes::Other(k, Some(value))) = (options.strict, &v.value) {
+                    match k.as_ref() {
+                        "orient" => Orient::try_from(value.as_ref()).map(|_| ())?,
+                        "type" => Kind::try_from(value.as_ref()).map(|_| ())?,
+                        _ => (),
                     }
-                } else {
-                    self.attributes.push(Attributes::try_from(data)?);
+                }
+
+                match options.find_duplicate(&v, attributes)? {
+                    Some(_) if options.duplicates == DuplicatePolicy::KeepFirst => (),
+                    Some(i) => {
+                        attributes.remove(i);
+                        attributes.push(v);
+                    },
+                    None => attributes.push(v),
                 }
             },
-            Key::Media => {
-                self.medias.push(Media::try_from(data)?);
+            Line::Media(mut v) => {
+                limits.check(Limit::Medias, self.medias.len() + 1)?;
+                v.attributes.reserve(20);
+                self.medias.push(v);
                 *in_media = true;
             },
-        })
+            Line::Unknown(_) => (),
+        }
+
+        Ok(())
+    }
+}
+
+/// a session description written with [`WriteOptions`], see
+/// [`Sdp::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'s, 'a> {
+    sdp: &'s Sdp<'a>,
+    options: &'s WriteOptions,
+}
+
+impl<'a> Sdp<'a> {
+    /// the description written with the given options.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use sdp::options::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=fmtp:111 minptime=10\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=ice-ufrag:Oyef\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let options = WriteOptions {
+    ///     order: AttributeOrder::WebRtc,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(sdp.display(&options).to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=fmtp:111 minptime=10\r\n");
+    ///
+    /// assert_eq!(sdp.display(&WriteOptions::default()).to_string(), temp);
+    /// assert_eq!(sdp.medias[0].attributes[0].to_string(), "rtpmap:111 opus/48000/2");
+    ///
+    /// let sdp = Sdp::try_from("v=0\r\ns=-\r\nm=video 9 RTP/AVP 96\r\na=rtpmap:96  VP8/90000\r\n").unwrap();
+    /// let options = WriteOptions {
+    ///     raw: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(sdp.display(&options).to_string().ends_with("a=rtpmap:96  VP8/90000\r\n"));
+    /// assert!(sdp.to_string().ends_with("a=rtpmap:96 VP8/90000\r\n"));
+    /// ```
+    pub fn display<'s>(&'s self, options: &'s WriteOptions) -> Formatted<'s, 'a> {
+        Formatted {
+            sdp: self,
+            options,
+        }
+    }
+
+    /// the length in bytes of the description written with the default
+    /// options, to pre-size an output buffer.
+    ///
+    /// The description is written to a sink that only counts bytes, so
+    /// the length is exact and nothing is allocated.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.serialized_len(), temp.len());
+    /// ```
+    pub fn serialized_len(&self) -> usize {
+        let mut counter = Counter(0);
+        // the counter never fails, and neither do the writers.
+        let _ = write!(counter, "{}", self);
+        counter.0
+    }
+
+    /// append the description written with the default options to a
+    /// caller buffer.
+    ///
+    /// A buffer without capacity is sized once with
+    /// [`Sdp::serialized_len`], otherwise the description is written
+    /// directly.  Servers that write many descriptions can clear and
+    /// reuse one buffer, which does not allocate once it is large enough.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     a=ptime:20\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let mut buf = String::with_capacity(sdp.serialized_len());
+    /// sdp.serialize_into(&mut buf);
+    /// assert_eq!(buf, temp);
+    ///
+    /// buf.clear();
+    /// sdp.serialize_into(&mut buf);
+    /// sdp.serialize_into(&mut buf);
+    /// assert_eq!(buf, [temp, temp].concat());
+    /// ```
+    pub fn serialize_into(&self, buf: &mut String) {
+        // counting is a full write, only worth it to size a new buffer.
+        if buf.capacity() == 0 {
+            buf.reserve(self.serialized_len());
+        }
+
+        // writing to a string never fails.
+        let _ = write!(buf, "{}", self);
+    }
+
+    /// write the description with the given options.
+    fn write(&self, f: &mut fmt::Formatter<'_>, options: &WriteOptions) -> fmt::Result {
+        write!(f, "v={}\r\n", self.version)?;
+        if let Some(v) = &self.origin {
+            write!(f, "o={}\r\n", v)?;
+        }
+
+        write!(f, "s={}\r\n", self.session_name.as_deref().unwrap_or("-"))?;
+        for (key, value) in [
+            ("i", &self.session_info),
+            ("u", &self.uri),
+        ] {
+            if let Some(v) = value {
+                write!(f, "{}={}\r\n", key, v)?;
+            }
+        }
+
+        for v in &self.emails {
+            write!(f, "e={}\r\n", v)?;
+        }
+
+        for v in &self.phones {
+            write!(f, "p={}\r\n", v)?;
+        }
+
+        if let Some(v) = &self.connection {
+            write!(f, "c={}\r\n", v)?;
+        }
+
+        for v in &self.bandwidth {
+            write!(f, "b={}\r\n", v)?;
+        }
+
+        if let Some(v) = &self.timing {
+            write!(f, "t={}\r\n", v)?;
+        }
+
+        if let Some(v) = &self.repeat_times {
+            write!(f, "r={}\r\n", v)?;
+        }
+
+        for v in &self.time_zones {
+            write!(f, "z={}\r\n", v)?;
+        }
+
+        if let Some(v) = &self.encryption_key {
+            write!(f, "k={}\r\n", v)?;
+        }
+
+        for v in &self.attributes {
+            write_attribute(f, v, options)?;
+        }
+
+        write_unknown_lines(f, &self.unknown_lines, options)?;
+        for media in &self.medias {
+            write_media(f, media, options)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A sink that counts the written bytes, see [`Sdp::serialized_len`].
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// write a media description, from its "m=" line to its last attribute.
+fn write_media(f: &mut fmt::Formatter<'_>, media: &Media<'_>, options: &WriteOptions) -> fmt::Result {
+    let raw = options.raw_sections
+        && options.order == AttributeOrder::Input
+        && options.feedback_wildcards == FeedbackWildcards::Keep
+        && (options.unknown_lines || media.unknown_lines.is_empty());
+    if let Some(text) = raw.then(|| media.original()).flatten() {
+        return write!(f, "{}\r\n", text);
+    }
+
+    let mut copy;
+    let media = match options.feedback_wildcards {
+        FeedbackWildcards::Keep => media,
+        FeedbackWildcards::Expand => {
+            copy = media.clone();
+            copy.expand_feedback_wildcards();
+            &copy
+        }
+        FeedbackWildcards::Collapse => {
+            copy = media.clone();
+            copy.collapse_feedback_wildcards();
+            &copy
+        }
+    };
+
+    write!(f, "m={}\r\n", media)?;
+    if let Some(v) = &media.info {
+        write!(f, "i={}\r\n", v)?;
+    }
+
+    for v in &media.connections {
+        write!(f, "c={}\r\n", v)?;
+    }
+
+    for v in &media.bandwidth {
+        write!(f, "b={}\r\n", v)?;
+    }
+
+    match options.order {
+        AttributeOrder::Input => {
+            for v in &media.attributes {
+                write_attribute(f, v, options)?;
+            }
+        }
+        AttributeOrder::WebRtc => {
+            let mut attributes = media.attributes.iter().collect::<Vec<_>>();
+            attributes.sort_by_key(|a| webrtc_rank(&a.value, &media.fmts));
+            for v in attributes {
+                write_attribute(f, v, options)?;
+            }
+        }
+    }
+
+    write_unknown_lines(f, &media.unknown_lines, options)
+}
+
+/// write the lines of unknown type, if requested.
+fn write_unknown_lines(f: &mut fmt::Formatter<'_>, lines: &[RawLine<'_>], options: &WriteOptions) -> fmt::Result {
+    if options.unknown_lines {
+        for v in lines {
+            write!(f, "{}\r\n", v)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// write an attribute line, as it appeared in the input if requested.
+fn write_attribute(f: &mut fmt::Formatter<'_>, attribute: &Attribute<'_>, options: &WriteOptions) -> fmt::Result {
+    match options.raw.then(|| attribute.original()).flatten() {
+        Some(v) => write!(f, "a={}\r\n", v),
+        None => write!(f, "a={}\r\n", attribute),
+    }
+}
+
+impl fmt::Display for Formatted<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sdp.write(f, self.options)
+    }
+}
+
+impl fmt::Display for Sdp<'_> {
+    /// Lines are written in the order required by
+    /// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-5),
+    /// each terminated by CRLF.  A missing session name is written as
+    /// "s=-".
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     a=group:BUNDLE 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=fmtp:111 minptime=10;useinbandfec=1\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(format!("{}", sdp), temp);
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=jdoe 3724394400 1 IN IP4 198.51.100.1\r\n\
+    ///     s=Call to John Smith\r\n\
+    ///     i=SDP Offer #1\r\n\
+    ///     u=http://www.jdoe.example.com/home.html\r\n\
+    ///     e=Jane Doe <jane@jdoe.example.com>\r\n\
+    ///     e=j.doe@example.com (Jane Doe)\r\n\
+    ///     p=+1 617 555-6011\r\n\
+    ///     c=IN IP4 198.51.100.1\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 49170 RTP/AVP 0\r\n\
+    ///     i=main audio\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.session_info.as_deref(), Some("SDP Offer #1"));
+    /// assert_eq!(sdp.emails.len(), 2);
+    /// assert_eq!(sdp.emails[0].name.as_deref(), Some("Jane Doe"));
+    /// assert_eq!(sdp.phones[0].address, "+1 617 555-6011");
+    /// assert_eq!(sdp.medias[0].info.as_deref(), Some("main audio"));
+    /// assert_eq!(format!("{}", sdp), temp);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &WriteOptions::default())
     }
 }
 
 impl<'a> TryFrom<&'a str> for Sdp<'a> {
     type Error = anyhow::Error;
-    #[rustfmt::skip]
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let mut sdp = Self::default();
-        let mut in_media = false;
-        for line in value.lines() {
-            if !line.is_empty() {
-                let (key, data) = line.split_at(2);
-                if let Ok(k) = Key::try_from(key) {
-                    sdp.handle_line(k, data, &mut in_media)?;
-                }   
-            }
-        }
+        Self::parse(value, &ParseOptions::default())
+    }
+}
 
-        Ok(sdp)
+impl FromStr for Sdp<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned session description, the value does not borrow the input.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::connection::Connection;
+    /// use sdp::origin::Origin;
+    /// use sdp::*;
+    ///
+    /// let temp = String::from("v=0\r\ns=-\r\nt=0 0\r\n");
+    /// let sdp: Sdp<'static> = temp.parse().unwrap();
+    /// drop(temp);
+    /// assert_eq!(sdp.to_string(), "v=0\r\ns=-\r\nt=0 0\r\n");
+    ///
+    /// let origin: Origin = "- 1 2 IN IP4 127.0.0.1".parse().unwrap();
+    /// assert_eq!(origin.sess_version, 2);
+    ///
+    /// let connection = "IN IP4 0.0.0.0".parse::<Connection>().unwrap();
+    /// assert_eq!(connection.to_string(), "IN IP4 0.0.0.0");
+    /// assert!("IN IP4".parse::<Connection>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Sdp::try_from(value).map(Sdp::into_owned)
     }
 }
 
@@ -301,6 +1146,7 @@ impl fmt::Display for Key {
     #[rustfmt::skip]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", match self {
+            Self::Version =>         "v=",
             Self::Origin =>          "o=",
             Self::SessionName =>     "s=",
             Self::SessionInfo =>     "i=",
@@ -339,6 +1185,7 @@ impl<'a> TryFrom<&'a str> for Key {
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         match value {
+            "v=" => Ok(Self::Version),
             "o=" => Ok(Self::Origin),
             "s=" => Ok(Self::SessionName),
             "i=" => Ok(Self::SessionInfo),
@@ -0,0 +1,341 @@
+use super::util::{
+    split_lines,
+    SplitLines
+};
+
+use super::{
+    options::ParseOptions,
+    encryption::EncryptionKey,
+    repeat_times::RepeatTimes,
+    attributes::Attribute,
+    connection::Connection,
+    time_zones::TimeZones,
+    bandwidth::Bandwidth,
+    timing::Timing,
+    origin::Origin,
+    version::Version,
+    media::Media,
+    span::Span,
+    Key
+};
+
+use alloc::borrow::Cow;
+use core::{
+    convert::TryFrom,
+    fmt
+};
+
+/// Typed session description line.
+///
+/// One variant per line type, the value is parsed according to the
+/// line type but is not associated with a session or media section.
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Line<'a> {
+    /// Protocol Version ("v=")
+    Version(Version),
+    /// Origin ("o=")
+    Origin(Origin<'a>),
+    /// Session Name ("s=")
+    SessionName(&'a str),
+    /// Session Information ("i=")
+    SessionInfo(&'a str),
+    /// URI ("u=")
+    Uri(&'a str),
+    /// Email Address ("e=")
+    Email(&'a str),
+    /// Phone Number ("p=")
+    Phone(&'a str),
+    /// Connection Information ("c=")
+    Connection(Connection),
+    /// Bandwidth ("b=")
+    Bandwidth(Bandwidth),
+    /// Timing ("t=")
+    Timing(Timing),
+    /// Repeat Times ("r=")
+    RepeatTimes(RepeatTimes),
+    /// Time Zones ("z=")
+    TimeZones(TimeZones),
+    /// Encryption Keys ("k=")
+    EncryptionKey(EncryptionKey<'a>),
+    /// Attributes ("a=")
+    Attribute(Attribute<'a>),
+    /// Media Descriptions ("m=")
+    Media(Media<'a>),
+    /// The complete line, for line types this crate does not know.
+    Unknown(&'a str),
+}
+
+/// A line of a type this crate does not know, e.g. "x=" lines written
+/// by broken gateways.
+///
+/// The parser keeps these lines with the session or media description
+/// they appeared under, and writes them back after the attributes of
+/// that description with [`crate::options::WriteOptions::unknown_lines`].
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::options::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     x=gateway\r\n\
+///     t=0 0\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     y=1\r\n\
+///     a=ptime:20\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// assert_eq!(sdp.unknown_lines[0].text, "x=gateway");
+/// assert_eq!(sdp.unknown_lines[0].span.unwrap().line, 3);
+/// assert_eq!(sdp.medias[0].unknown_lines[0].text, "y=1");
+/// assert!(!sdp.to_string().contains("x=gateway"));
+///
+/// let options = WriteOptions {
+///     unknown_lines: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(sdp.display(&options).to_string(), "v=0\r\n\
+///     s=-\r\n\
+///     t=0 0\r\n\
+///     x=gateway\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     a=ptime:20\r\n\
+///     y=1\r\n");
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone)]
+pub struct RawLine<'a> {
+    /// The complete line, with the line type prefix.
+    pub text: Cow<'a, str>,
+    /// The position of the line in the input, set by
+    /// [`crate::Sdp::parse`].  It is not compared by `==`.
+    pub span: Option<Span>,
+}
+
+impl PartialEq for RawLine<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl<'a> RawLine<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> RawLine<'b> {
+        RawLine {
+            text: Cow::Owned(self.text.into_owned()),
+            span: self.span,
+        }
+    }
+}
+
+impl fmt::Display for RawLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl<'a> Line<'a> {
+    /// parse a single line with the line type prefix.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::lines::*;
+    /// use sdp::version::Version;
+    ///
+    /// assert!(matches!(Line::parse("v=0").unwrap(), Line::Version(Version(0))));
+    /// assert!(matches!(Line::parse("a=ptime:20").unwrap(), Line::Attribute(_)));
+    /// assert!(matches!(Line::parse("x=panda").unwrap(), Line::Unknown("x=panda")));
+    /// assert!(matches!(Line::parse("x").unwrap(), Line::Unknown("x")));
+    /// assert!(Line::parse("t=panda").is_err());
+    /// assert!(Line::parse("v=panda").is_err());
+    /// ```
+    #[rustfmt::skip]
+    pub fn parse(line: &'a str) -> anyhow::Result<Self> {
+        if line.len() < 2 || !line.is_char_boundary(2) {
+            return Ok(Self::Unknown(line))
+        }
+
+        let (key, data) = line.split_at(2);
+        let key = match Key::try_from(key) {
+            Err(_) => return Ok(Self::Unknown(line)),
+            Ok(k) => k,
+        };
+
+        Ok(match key {
+            Key::Version =>         Self::Version(Version::try_from(data)?),
+            Key::Origin =>          Self::Origin(Origin::try_from(data)?),
+            Key::SessionName =>     Self::SessionName(data),
+            Key::SessionInfo =>     Self::SessionInfo(data),
+            Key::Uri =>             Self::Uri(data),
+            Key::Email =>           Self::Email(data),
+            Key::Phone =>           Self::Phone(data),
+            Key::Connection =>      Self::Connection(Connection::try_from(data)?),
+            Key::Bandwidth =>       Self::Bandwidth(Bandwidth::try_from(data)?),
+            Key::Timing =>          Self::Timing(Timing::try_from(data)?),
+            Key::RepeatTimes =>     Self::RepeatTimes(RepeatTimes::try_from(data)?),
+            Key::TimeZones =>       Self::TimeZones(TimeZones::try_from(data)?),
+            Key::EncryptionKey =>   Self::EncryptionKey(EncryptionKey::try_from(data)?),
+            Key::Attributes =>      Self::Attribute(Attribute::try_from(data)?),
+            Key::Media =>           Self::Media(Media::try_from(data)?),
+        })
+    }
+
+    /// the line type, `None` for a line of unknown type.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::lines::*;
+    /// use sdp::Key;
+    ///
+    /// assert_eq!(Line::parse("b=AS:30").unwrap().key(), Some(Key::Bandwidth));
+    /// assert_eq!(Line::parse("x=panda").unwrap().key(), None);
+    /// ```
+    #[rustfmt::skip]
+    pub fn key(&self) -> Option<Key> {
+        Some(match self {
+            Self::Version(_) =>         Key::Version,
+            Self::Origin(_) =>          Key::Origin,
+            Self::SessionName(_) =>     Key::SessionName,
+            Self::SessionInfo(_) =>     Key::SessionInfo,
+            Self::Uri(_) =>             Key::Uri,
+            Self::Email(_) =>           Key::Email,
+            Self::Phone(_) =>           Key::Phone,
+            Self::Connection(_) =>      Key::Connection,
+            Self::Bandwidth(_) =>       Key::Bandwidth,
+            Self::Timing(_) =>          Key::Timing,
+            Self::RepeatTimes(_) =>     Key::RepeatTimes,
+            Self::TimeZones(_) =>       Key::TimeZones,
+            Self::EncryptionKey(_) =>   Key::EncryptionKey,
+            Self::Attribute(_) =>       Key::Attributes,
+            Self::Media(_) =>           Key::Media,
+            Self::Unknown(_) =>         return None,
+        })
+    }
+}
+
+/// Streaming line iterator.
+///
+/// Yields one typed [`Line`] per input line without building a full
+/// session, which is useful for consumers that only need to inspect a
+/// description (e.g. SIP proxies).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::lines::*;
+/// use sdp::options::*;
+/// use sdp::version::Version;
+///
+/// let temp = "v=0\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=ptime:20\r\n";
+/// let lines = Lines::new(temp, &ParseOptions::default())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(lines.len(), 3);
+/// assert!(matches!(lines[0], Line::Version(Version(0))));
+/// assert!(matches!(lines[1], Line::Media(_)));
+/// assert!(matches!(lines[2], Line::Attribute(_)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lines<'a, 'o> {
+    inner: SplitLines<'a, 'o>,
+    source: &'a str,
+}
+
+impl<'a, 'o> Lines<'a, 'o> {
+    /// iterate over the lines of a session description, split according
+    /// to the given options.
+    pub fn new(source: &'a str, options: &'o ParseOptions) -> Self {
+        Self {
+            inner: split_lines(source, options),
+            source,
+        }
+    }
+
+    /// yield the position of every line with the line, also for lines
+    /// that fail to parse.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::lines::*;
+    /// use sdp::options::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\na=ptime:a\r\n";
+    /// let lines = Lines::new(temp, &ParseOptions::default())
+    ///     .spanned()
+    ///     .map(|(span, line)| (span.line, &temp[span.range()], line.is_ok()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lines, vec![
+    ///     (1, "v=0", true),
+    ///     (2, "s=-", true),
+    ///     (3, "a=ptime:a", false),
+    /// ]);
+    /// ```
+    pub fn spanned(self) -> Spanned<'a, 'o> {
+        Spanned {
+            inner: self.inner,
+            source: self.source,
+            offset: 0,
+            line: 1,
+        }
+    }
+}
+
+/// Line iterator with positions, see [`Lines::spanned`].
+#[derive(Debug, Clone)]
+pub struct Spanned<'a, 'o> {
+    inner: SplitLines<'a, 'o>,
+    source: &'a str,
+    /// the offset up to which line breaks are counted.
+    offset: usize,
+    /// the line number at `offset`.
+    line: usize,
+}
+
+impl Spanned<'_, '_> {
+    /// the span of a part of the input.
+    fn span(&mut self, part: &str) -> Span {
+        let start = part.as_ptr() as usize - self.source.as_ptr() as usize;
+        self.line += self.source[self.offset..start].matches('\n').count();
+        self.offset = start;
+        Span {
+            line: self.line,
+            start,
+            end: start + part.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for Spanned<'a, '_> {
+    type Item = (Span, anyhow::Result<Line<'a>>);
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(match self.inner.next()? {
+            Ok(line) => (self.span(line), Line::parse(line)),
+            Err(e) => {
+                let current = self.inner.current;
+                let end = current.find('\n').unwrap_or(current.len());
+                let line = current[..end].trim_end_matches('\r');
+                (self.span(line), Err(e))
+            }
+        })
+    }
+}
+
+impl<'a> Iterator for Lines<'a, '_> {
+    type Item = anyhow::Result<Line<'a>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|line| Line::parse(line?))
+    }
+}
@@ -1,11 +1,17 @@
 use crate::attributes::*;
-use anyhow::{
-    ensure,
-    anyhow
-};
+use crate::bandwidth::Bandwidth;
+use crate::connection::Connection;
+use crate::lines::RawLine;
+use crate::scanner::Scanner;
+use crate::span::Span;
+use smallvec::SmallVec;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::anyhow;
 
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
@@ -13,7 +19,10 @@ use std::{
 /// 
 /// <media> is the media type.  Currently defined media are "audio",
 /// "video", "text", "application", and "message"
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Encoding {
     Audio,
     Video,
@@ -49,7 +58,10 @@ pub enum Encoding {
 /// Modulation (PCM) audio and RTP PCM audio; another might be TCP/RTP
 /// PCM audio.  In addition, relays and monitoring tools that are
 /// transport-protocol-specific but format-independent are possible.
-#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Proto {
     Udp,
     Tls,
@@ -59,6 +71,8 @@ pub enum Proto {
     Savpf,
     Dtls,
     Sctp,
+    Tcp,
+    Msrp,
 }
 
 /// media port.
@@ -116,7 +130,9 @@ pub enum Proto {
 /// practice, there is no implicit grouping defined by such means and
 /// an explicit grouping framework should instead be used to express 
 /// the intended semantics.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Port {
     pub num: u16,
     pub count: Option<u8>
@@ -130,11 +146,13 @@ pub struct Port {
 /// Each media description starts with an "m=" field and is terminated by
 /// either the next "m=" field or by the end of the session description.
 /// A media field has several sub-fields:
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone)]
 pub struct Media<'a> {
     pub encoding: Encoding,
     pub port: Port,
-    pub protos: Vec<Proto>,
+    pub protos: SmallVec<[Proto; 4]>,
     /// <fmt> is a media format description.  The fourth and any subsequent
     /// sub-fields describe the format of the media.  The interpretation
     /// of the media format depends on the value of the <proto> sub-field.
@@ -158,15 +176,386 @@ pub struct Media<'a> {
     /// For media using other transport protocols, the <fmt> field is
     /// protocol specific.  Rules for interpretation of the <fmt> sub-
     /// field MUST be defined when registering new protocols.
-    pub fmts: Vec<u8>,
+    pub fmts: SmallVec<[u8; 32]>,
+    /// Media Title ("i="), overrides the session level information.
+    pub info: Option<Cow<'a, str>>,
+    /// Connection Information ("c=")
+    ///
+    /// Overrides the session level connection, empty when the session
+    /// level one applies.  Hierarchically encoded streams can use one
+    /// line per layer, or an address range on a single line, see
+    /// [`Connection::addresses`].
+    pub connections: SmallVec<[Connection; 1]>,
+    /// Bandwidth ("b="), the proposed bandwidth of the media.
+    pub bandwidth: SmallVec<[Bandwidth; 1]>,
     /// Attributes ("a=")
-    pub attributes: Vec<Attributes<'a>>,
+    pub attributes: Vec<Attribute<'a>>,
+    /// Lines of unknown type that appeared in the media description, in
+    /// input order.
+    pub unknown_lines: Vec<RawLine<'a>>,
+    /// The media description as it appeared in the input, set by
+    /// [`crate::Sdp::parse`].  It is not compared by `==`, see
+    /// [`Media::original`].
+    pub section: Option<Section<'a>>,
+}
+
+/// The input text of a media description, from its "m=" line to its
+/// last line, without the final line break.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// the position of the section in the input, `line` is the line of
+    /// the "m=" line.
+    pub span: Span,
+}
+
+impl PartialEq for Media<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoding == other.encoding
+            && self.port == other.port
+            && self.protos == other.protos
+            && self.fmts == other.fmts
+            && self.info == other.info
+            && self.connections == other.connections
+            && self.bandwidth == other.bandwidth
+            && self.attributes == other.attributes
+            && self.unknown_lines == other.unknown_lines
+    }
+}
+
+/// whether the lines of the section with the given prefix parse to the
+/// given values, in order.
+fn unchanged<'b, T>(text: &'b str, prefix: &str, values: &[T]) -> bool
+where
+    T: PartialEq + TryFrom<&'b str>,
+{
+    let mut lines = text.split("\r\n").filter_map(|l| l.strip_prefix(prefix));
+    values.iter().all(|v| lines.next().and_then(|l| T::try_from(l).ok()).as_ref() == Some(v))
+        && lines.next().is_none()
 }
 
 impl<'a> Media<'a> {
-    pub(crate) fn push(&mut self, data: &'a str) -> anyhow::Result<()> {
-        self.attributes.push(Attributes::try_from(data)?);
-        Ok(())
+    /// the media identification tag ("a=mid:").
+    pub fn mid(&self) -> Option<&Mid<'a>> {
+        self.attributes.iter().find_map(|a| match &a.value {
+            Attributes::Mid(v) => Some(v),
+            _ => None,
+        })
+    }
+
+    /// the position of the "m=" line in the input, from the
+    /// [`Media::section`] set by [`crate::Sdp::parse`].  The other lines
+    /// of the media description are in [`crate::span::Spans::media`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0\r\n\
+    ///     c=IN IP4 127.0.0.1\r\n\
+    ///     a=ptime:20\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &sdp.medias[0];
+    /// assert_eq!(media.span().unwrap().line, 3);
+    /// assert_eq!(&temp[media.span().unwrap().range()], "m=audio 9 RTP/AVP 0");
+    ///
+    /// assert_eq!(media.clone().into_owned().span(), None);
+    /// ```
+    pub fn span(&self) -> Option<Span> {
+        let section = self.section?;
+        let line = section.text.split('\n').next().unwrap_or_default();
+        Some(Span {
+            end: section.span.start + line.trim_end_matches('\r').len(),
+            ..section.span
+        })
+    }
+
+    /// whether the media description is rejected, i.e. uses port 0
+    /// without being "a=bundle-only".
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 0 RTP/AVP 0\r\n\
+    ///     m=video 0 RTP/AVP 96\r\n\
+    ///     a=bundle-only\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let rejected = sdp.medias.iter().map(|m| m.is_rejected()).collect::<Vec<_>>();
+    /// assert_eq!(rejected, vec![true, false, false]);
+    /// ```
+    pub fn is_rejected(&self) -> bool {
+        self.port.num == 0 && !self.attributes
+            .iter()
+            .any(|a| matches!(a.value, Attributes::BundleOnly))
+    }
+
+    /// reject the media description in an answer.
+    ///
+    /// The port is set to zero
+    /// ([RFC3264](https://datatracker.ietf.org/doc/html/rfc3264#section-6)),
+    /// and every attribute except "a=mid:" is removed, so that the
+    /// rejected media is still identified but no longer bundled.  The
+    /// proto and the format list stay unchanged, as the "m=" line must
+    /// still list at least one format.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=bundle-only\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].reject();
+    /// assert!(sdp.medias[0].is_rejected());
+    /// assert_eq!(
+    ///     format!("{}", sdp),
+    ///     "v=0\r\ns=-\r\nm=video 0 UDP/TLS/RTP/SAVPF 96 97\r\na=mid:1\r\n"
+    /// );
+    /// ```
+    pub fn reject(&mut self) {
+        self.port = Port {
+            num: 0,
+            count: None,
+        };
+
+        self.attributes.retain(|a| matches!(a.value, Attributes::Mid(_)));
+    }
+
+    /// whether the media is transported with RTP.
+    pub fn is_rtp(&self) -> bool {
+        self.protos.contains(&Proto::Rtp)
+    }
+
+    /// the transport ports of the "m=" line, empty for rejected media.
+    ///
+    /// With a port count, RTP uses every other port, each RTP port
+    /// paired with the following RTCP port, other protocols use
+    /// consecutive ports
+    /// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-5.14).
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 49170/2 RTP/AVP 31\r\n\
+    ///     m=video 5000/3 UDP 33\r\n\
+    ///     m=audio 0 RTP/AVP 0\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].ports().collect::<Vec<_>>(), vec![49170, 49172]);
+    /// assert_eq!(sdp.medias[1].ports().collect::<Vec<_>>(), vec![5000, 5001, 5002]);
+    /// assert_eq!(sdp.medias[2].ports().count(), 0);
+    /// ```
+    pub fn ports(&self) -> impl Iterator<Item = u16> {
+        let count = match self.port.num {
+            0 => 0,
+            _ => self.port.count.unwrap_or(1) as usize,
+        };
+
+        let step = if self.is_rtp() { 2 } else { 1 };
+        (u32::from(self.port.num)..=u32::from(u16::MAX))
+            .step_by(step)
+            .take(count)
+            .map(|v| v as u16)
+    }
+
+    /// the RTCP port of RTP media, `None` for rejected or non RTP
+    /// media.
+    ///
+    /// The port is taken from "a=rtcp:"
+    /// ([RFC3605](https://datatracker.ietf.org/doc/html/rfc3605#section-2.1)),
+    /// it is the RTP port with "a=rtcp-mux"
+    /// ([RFC5761](https://datatracker.ietf.org/doc/html/rfc5761#section-5.1.1)),
+    /// and the next higher port otherwise.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 49170 RTP/AVP 0\r\n\
+    ///     m=audio 49172 RTP/AVP 0\r\n\
+    ///     a=rtcp:53020 IN IP4 192.0.2.1\r\n\
+    ///     m=audio 49174 RTP/AVP 0\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     m=audio 0 RTP/AVP 0\r\n\
+    ///     m=video 5004 UDP 33\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let ports = sdp.medias.iter().map(|m| m.rtcp_port()).collect::<Vec<_>>();
+    /// assert_eq!(ports, vec![Some(49171), Some(53020), Some(49174), None, None]);
+    /// ```
+    pub fn rtcp_port(&self) -> Option<u16> {
+        if self.port.num == 0 || !self.is_rtp() {
+            return None;
+        }
+
+        let rtcp = self.attributes.iter().find_map(|a| match &a.value {
+            Attributes::Other(k, Some(v)) if k == "rtcp" => v.split(' ').next()?.parse().ok(),
+            _ => None,
+        });
+
+        let mux = self.attributes
+            .iter()
+            .any(|a| matches!(&a.value, Attributes::Other(k, None) if k == "rtcp-mux"));
+        match (rtcp, mux) {
+            (Some(port), _) => Some(port),
+            (None, true) => Some(self.port.num),
+            (None, false) => self.port.num.checked_add(1),
+        }
+    }
+
+    /// the media description as it appeared in the input, unless it was
+    /// modified.
+    ///
+    /// Pass-through proxies use this to copy the input with
+    /// [`crate::options::WriteOptions::raw_sections`]: no line may have
+    /// been added or removed, the "m=", "i=", "c=" and "b=" lines must
+    /// parse to the current values, and the attributes must still be the
+    /// parsed ones, in input order and unmodified, see
+    /// [`Attribute::original`].  The lines are parsed again, so this is
+    /// only cheap enough for pass-through uses.  Sections with line
+    /// breaks other than CRLF are not returned.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=audio 9 RTP/AVP 0 8\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=ptime:20\r\n\
+    ///     m=video 9 RTP/AVP 96\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].original(), Some("m=audio 9 RTP/AVP 0 8\r\nc=IN IP4 0.0.0.0\r\na=ptime:20"));
+    /// assert_eq!(sdp.medias[1].original(), Some("m=video 9 RTP/AVP 96"));
+    ///
+    /// sdp.medias[0].fmts.pop();
+    /// assert_eq!(sdp.medias[0].original(), None);
+    ///
+    /// sdp.medias[1].attributes.push(Attributes::Recvonly(true).into());
+    /// assert_eq!(sdp.medias[1].original(), None);
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     m=video 9 RTP/AVP 96 97\r\n\
+    ///     b=AS:500\r\n\
+    ///     a=ssrc-group:FID 1 2\r\n\
+    ///     a=ssrc:1 cname:a\r\n\
+    ///     a=ssrc:2 cname:a\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].rewrite_ssrcs(&[(1, 1001), (2, 1002)]);
+    /// assert_eq!(sdp.medias[0].original(), None);
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.medias[0].bandwidth[0].bandwidth = 600;
+    /// assert_eq!(sdp.medias[0].original(), None);
+    ///
+    /// let sdp = Sdp::try_from("v=0\ns=-\nm=audio 9 RTP/AVP 0\na=ptime:20\n").unwrap();
+    /// assert_eq!(sdp.medias[0].original(), None);
+    /// ```
+    pub fn original(&self) -> Option<&'a str> {
+        let section = self.section?;
+        let text = section.text;
+        let lines = text.matches("\r\n").count() + 1;
+        if text.matches('\n').count() + 1 != lines {
+            return None;
+        }
+
+        let count = 1
+            + self.info.is_some() as usize
+            + self.connections.len()
+            + self.bandwidth.len()
+            + self.attributes.len()
+            + self.unknown_lines.len();
+        if count != lines {
+            return None;
+        }
+
+        let media = Media::try_from(text.split("\r\n").next()?.strip_prefix("m=")?).ok()?;
+        if media.encoding != self.encoding
+            || media.port != self.port
+            || media.protos != self.protos
+            || media.fmts != self.fmts
+        {
+            return None;
+        }
+
+        if text.split("\r\n").filter_map(|l| l.strip_prefix("i=")).next() != self.info.as_deref()
+            || !unchanged(text, "c=", &self.connections)
+            || !unchanged(text, "b=", &self.bandwidth)
+        {
+            return None;
+        }
+
+        let mut start = section.span.start;
+        for attribute in &self.attributes {
+            match attribute.span {
+                Some(span) if span.start > start && span.end <= section.span.end => start = span.start,
+                _ => return None,
+            }
+
+            attribute.original()?;
+        }
+
+        Some(text)
+    }
+
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Media<'b> {
+        Media {
+            encoding: self.encoding,
+            port: self.port,
+            protos: self.protos,
+            fmts: self.fmts,
+            info: self.info.map(|v| Cow::Owned(v.into_owned())),
+            connections: self.connections,
+            bandwidth: self.bandwidth,
+            attributes: self.attributes
+                .into_iter()
+                .map(Attribute::into_owned)
+                .collect(),
+            unknown_lines: self.unknown_lines
+                .into_iter()
+                .map(RawLine::into_owned)
+                .collect(),
+            section: None,
+        }
     }
 }
 
@@ -179,18 +568,23 @@ impl fmt::Display for Media<'_> {
     ///
     /// let media = Media {
     ///     attributes: vec![],
+    ///     unknown_lines: vec![],
+    ///     section: None,
+    ///     info: None,
+    ///     connections: smallvec::smallvec![],
+    ///     bandwidth: smallvec::smallvec![],
     ///     encoding: Encoding::Video,
     ///     port: Port {
     ///         num: 9,
     ///         count: Some(2)
     ///     },
-    ///     protos: vec![
+    ///     protos: smallvec::smallvec![
     ///         Proto::Udp,
     ///         Proto::Tls,
     ///         Proto::Avp,
     ///         Proto::Savp
     ///     ],
-    ///     fmts: vec![
+    ///     fmts: smallvec::smallvec![
     ///         96, 97, 98, 99, 100, 101,
     ///         102, 121, 127, 120, 125
     ///     ]
@@ -222,6 +616,8 @@ impl fmt::Display for Media<'_> {
 
         if !self.fmts.is_empty() {
             write!(f, " ")?;
+        } else if self.protos.contains(&Proto::Msrp) {
+            write!(f, " *")?;
         }
 
         for (i, x) in self.fmts.iter().enumerate() {
@@ -258,36 +654,59 @@ impl<'a> TryFrom<&'a str> for Media<'a> {
     /// assert_eq!(media.protos[3], Proto::Savp);
     ///
     /// assert_eq!(
-    ///     media.fmts, 
-    ///     vec![96, 97, 98, 99, 100, 101, 102, 121, 127, 120, 125]
+    ///     media.fmts.as_slice(), 
+    ///     &[96, 97, 98, 99, 100, 101, 102, 121, 127, 120, 125]
     /// );
+    ///
+    /// let media: Media = Media::try_from("message 7654 TCP/TLS/MSRP *").unwrap();
+    /// assert_eq!(media.encoding, Encoding::Message);
+    /// assert_eq!(media.protos.as_slice(), &[Proto::Tcp, Proto::Tls, Proto::Msrp]);
+    /// assert!(media.fmts.is_empty());
+    /// assert_eq!(format!("{}", media), "message 7654 TCP/TLS/MSRP *");
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split(' ').collect::<Vec<&str>>();
-        ensure!(values.len() >= 3, "invalid media!");
+        let mut scanner = Scanner::new(value, "media");
+        let encoding = scanner.field()?;
+        let port = scanner.field()?;
+        let proto = scanner.field()?;
 
-        let mut protos = Vec::with_capacity(5);
-        for p in values[2].split('/') {
+        let mut protos = SmallVec::new();
+        for p in proto.split('/') {
             protos.push(Proto::try_from(p)?);
         }
 
-        let mut fmts = Vec::with_capacity(30);
-        for f in values[3..].iter() {
-            if f != &"webrtc-datachannel" {
+        // MSRP media has no formats, its format list is "*".
+        let mut fmts = SmallVec::new();
+        while !scanner.is_empty() {
+            let f = scanner.field()?;
+            if f != "webrtc-datachannel" && f != "*" {
                 fmts.push(f.parse()?);   
             }
         }
 
         Ok(Self {
-            attributes: Vec::with_capacity(20),
-            encoding: Encoding::try_from(values[0])?,
-            port: Port::try_from(values[1])?,
+            attributes: Vec::new(),
+            unknown_lines: Vec::new(),
+            section: None,
+            info: None,
+            connections: SmallVec::new(),
+            bandwidth: SmallVec::new(),
+            encoding: Encoding::try_from(encoding)?,
+            port: Port::try_from(port)?,
             protos,
             fmts
         })
     }
 }
 
+impl FromStr for Media<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned media description, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Media::try_from(value).map(Media::into_owned)
+    }
+}
+
 impl fmt::Display for Port {
     /// # Unit Test
     ///
@@ -337,14 +756,14 @@ impl<'a> TryFrom<&'a str> for Port {
     /// assert_eq!(port.count, Some(2));
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split('/').collect::<Vec<&str>>();
-        ensure!(!values.is_empty(), "invalid media port!");
+        let mut values = value.split('/');
+        let num = values.next().ok_or_else(|| {
+            anyhow!("invalid media port!")
+        })?;
+
         Ok(Self {
-            num: values[0].parse()?,
-            count: match values.get(1) {
-                Some(c) => Some(c.parse()?),
-                None => None
-            }
+            num: num.parse()?,
+            count: values.next().map(str::parse).transpose()?
         })
     }
 }
@@ -410,6 +829,7 @@ impl fmt::Display for Proto {
     /// assert_eq!(format!("{}", Proto::Rtp), "RTP");
     /// assert_eq!(format!("{}", Proto::Avp), "AVP");
     /// assert_eq!(format!("{}", Proto::Savp), "SAVP");
+    /// assert_eq!(format!("{}", Proto::Msrp), "MSRP");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", match self {
@@ -421,6 +841,8 @@ impl fmt::Display for Proto {
             Self::Savpf =>  "SAVPF",
             Self::Dtls =>   "DTLS",
             Self::Sctp =>   "SCTP",
+            Self::Tcp =>    "TCP",
+            Self::Msrp =>   "MSRP",
         })
     }
 }
@@ -450,6 +872,8 @@ impl<'a> TryFrom<&'a str> for Proto {
             "SAVPF" =>  Ok(Self::Savpf),
             "DTLS" =>   Ok(Self::Dtls),
             "SCTP" =>   Ok(Self::Sctp),
+            "TCP" =>    Ok(Self::Tcp),
+            "MSRP" =>   Ok(Self::Msrp),
             _ => Err(anyhow!("invalid media proto!"))
         }
     }
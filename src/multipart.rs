@@ -0,0 +1,193 @@
+//! Multipart message bodies.
+//!
+//! A SIP message that carries more than one body, e.g. an INVITE with a
+//! session description and an ISUP or PIDF-LO part, uses a
+//! "multipart/mixed" body
+//! ([RFC5621](https://datatracker.ietf.org/doc/html/rfc5621#section-3.1),
+//! [RFC2046](https://datatracker.ietf.org/doc/html/rfc2046#section-5.1.1)).
+//! The helpers here split such a body at the boundary given by the
+//! "Content-Type" header of the message, and parse its
+//! "application/sdp" part.  Nested multipart bodies are not split.
+
+use super::Sdp;
+use anyhow::{
+    Result,
+    anyhow
+};
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::str;
+
+/// a body part of a multipart body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyPart<'a> {
+    /// the value of the "Content-Type" header of the part, with its
+    /// parameters.  A part without it is "text/plain".
+    pub content_type: Option<&'a str>,
+    /// the header lines of the part.
+    pub headers: &'a [u8],
+    pub body: &'a [u8],
+}
+
+impl<'a> BodyPart<'a> {
+    /// whether the part is a session description, the media type is
+    /// compared case-insensitively and its parameters are ignored.
+    pub fn is_sdp(&self) -> bool {
+        self.content_type.is_some_and(|v| {
+            v.split(';').next().unwrap_or_default().trim().eq_ignore_ascii_case("application/sdp")
+        })
+    }
+}
+
+/// split a multipart body at the boundary.
+///
+/// The preamble before the first boundary and the epilogue after the
+/// close delimiter are skipped.  Lines may end with CRLF or LF.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::multipart::*;
+///
+/// let body = b"--boundary1\r\n\
+///     Content-Type: application/sdp\r\n\
+///     \r\n\
+///     v=0\r\n\
+///     s=-\r\n\
+///     \r\n\
+///     --boundary1\r\n\
+///     \r\n\
+///     plain text\r\n\
+///     --boundary1--\r\n";
+///
+/// let found = parts(body, "boundary1").unwrap();
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].content_type, Some("application/sdp"));
+/// assert_eq!(found[0].headers, b"Content-Type: application/sdp\r\n");
+/// assert_eq!(found[0].body, b"v=0\r\ns=-\r\n");
+/// assert!(found[0].is_sdp());
+/// assert_eq!(found[1].content_type, None);
+/// assert_eq!(found[1].body, b"plain text");
+///
+/// assert!(parts(&body[..60], "boundary1").is_err());
+/// assert!(parts(body, "boundary2").is_err());
+/// ```
+pub fn parts<'a>(body: &'a [u8], boundary: &str) -> Result<Vec<BodyPart<'a>>> {
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+    let mut rest = match body.starts_with(delimiter) {
+        true => &body[delimiter.len()..],
+        false => next_delimiter(body, delimiter)
+            .map(|(_, rest)| rest)
+            .ok_or_else(|| anyhow!("invalid multipart body: missing boundary!"))?,
+    };
+
+    let mut parts = Vec::new();
+    loop {
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+
+        // transport padding and the line ending of the delimiter line.
+        let start = rest
+            .iter()
+            .position(|c| *c == b'\n')
+            .ok_or_else(|| anyhow!("invalid multipart body: missing close delimiter!"))?;
+        let (part, next) = next_delimiter(&rest[start + 1..], delimiter)
+            .ok_or_else(|| anyhow!("invalid multipart body: missing close delimiter!"))?;
+
+        parts.push(body_part(part)?);
+        rest = next;
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// parse the first "application/sdp" part of a multipart body, see
+    /// [`parts`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    ///
+    /// let body = b"preamble\r\n\
+    ///     --unique-boundary-1\r\n\
+    ///     Content-Type: application/pidf+xml\r\n\
+    ///     \r\n\
+    ///     <presence/>\r\n\
+    ///     --unique-boundary-1\r\n\
+    ///     content-type: Application/SDP; charset=utf-8\r\n\
+    ///     Content-Disposition: session\r\n\
+    ///     \r\n\
+    ///     v=0\r\n\
+    ///     s=panda\r\n\
+    ///     \r\n\
+    ///     --unique-boundary-1--\r\n";
+    ///
+    /// let sdp = Sdp::from_multipart(body, "unique-boundary-1").unwrap();
+    /// assert_eq!(sdp.session_name.as_deref(), Some("panda"));
+    ///
+    /// assert!(Sdp::from_multipart(&body[..72], "unique-boundary-1").is_err());
+    /// ```
+    pub fn from_multipart(body: &'a [u8], boundary: &str) -> Result<Self> {
+        let part = parts(body, boundary)?
+            .into_iter()
+            .find(BodyPart::is_sdp)
+            .ok_or_else(|| anyhow!("invalid multipart body: missing application/sdp part!"))?;
+
+        Sdp::try_from(str::from_utf8(part.body)?)
+    }
+}
+
+/// the text before the next delimiter line and the text after the
+/// delimiter.  The line ending before the delimiter belongs to the
+/// delimiter.
+fn next_delimiter<'a>(value: &'a [u8], delimiter: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let mut offset = 0;
+    while let Some(i) = value[offset..].iter().position(|c| *c == b'\n') {
+        let end = offset + i;
+        let rest = &value[end + 1..];
+        if rest.starts_with(delimiter) {
+            let part = &value[..end];
+            let part = part.strip_suffix(b"\r").unwrap_or(part);
+            return Some((part, &rest[delimiter.len()..]));
+        }
+
+        offset = end + 1;
+    }
+
+    None
+}
+
+/// split a body part into its headers and body at the first empty line.
+fn body_part(value: &[u8]) -> Result<BodyPart<'_>> {
+    let mut offset = 0;
+    let mut content_type = None;
+    loop {
+        let end = value[offset..]
+            .iter()
+            .position(|c| *c == b'\n')
+            .map(|i| offset + i)
+            .ok_or_else(|| anyhow!("invalid multipart body: missing empty line after headers!"))?;
+        let line = &value[offset..end];
+        if line.strip_suffix(b"\r").unwrap_or(line).is_empty() {
+            return Ok(BodyPart {
+                content_type,
+                headers: &value[..offset],
+                body: &value[end + 1..],
+            });
+        }
+
+        // "c" is the compact form of "Content-Type" in SIP.
+        let line = str::from_utf8(line)?;
+        if let Some((name, v)) = line.split_once(':') {
+            let name = name.trim();
+            if name.eq_ignore_ascii_case("content-type") || name.eq_ignore_ascii_case("c") {
+                content_type = Some(v.trim());
+            }
+        }
+
+        offset = end + 1;
+    }
+}
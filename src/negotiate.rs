@@ -0,0 +1,400 @@
+//! Applying remote descriptions to the local one.
+//!
+//! The local description keeps the negotiated state of a session: the
+//! codecs, directions and header extension ids both sides agreed on.
+//! Applying the remote answer to a local offer, or a remote offer to
+//! the local description, narrows that state down to what the remote
+//! side accepted and reports what changed in every media description,
+//! in the way of the JSEP offer/answer exchange
+//! ([RFC8829](https://datatracker.ietf.org/doc/html/rfc8829#section-5.10)).
+//!
+//! Media descriptions are matched by index, as the offer/answer model
+//! never removes or reorders "m=" lines.
+
+use super::attributes::{
+    Attribute,
+    Attributes,
+    Direction,
+    RtpValue
+};
+
+use super::media::{
+    Media,
+    Port
+};
+
+use super::Sdp;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure
+};
+
+use smallvec::SmallVec;
+
+/// the changes applying a remote description made to a local media
+/// description.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MediaChanges {
+    /// the media description was added by the remote offer, it is
+    /// rejected until the application accepts it.
+    pub added: bool,
+    /// the remote side rejected the media description.
+    pub rejected: bool,
+    /// local payload types the remote side did not accept.
+    pub removed_codecs: Vec<u8>,
+    /// local payload types renumbered to the remote ones, as (local,
+    /// remote) pairs.
+    pub renumbered_codecs: Vec<(u8, u8)>,
+    /// the direction before and after, if it changed.
+    pub direction: Option<(Direction, Direction)>,
+    /// local header extension ids the remote side did not accept.
+    pub removed_extensions: Vec<u8>,
+    /// local header extension ids renumbered to the remote ones, as
+    /// (local, remote) pairs.
+    pub renumbered_extensions: Vec<(u8, u8)>,
+}
+
+impl MediaChanges {
+    /// nothing changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// applies the remote answer to the local offer.
+    ///
+    /// The codecs become the ones of the answer, in the order of the
+    /// answer, the directions the reverse of the answered ones, and the
+    /// header extensions the ones the answer kept, with the ids of the
+    /// answer.  Media descriptions the answer rejected are rejected.
+    ///
+    /// Fails if the answer has a different number of media descriptions
+    /// or a different media type than the offer.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let mut local = Sdp::try_from("v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111 0 8\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=extmap:1 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     a=extmap:2 http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time\r\n\
+    ///     a=extmap:3 http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtpmap:0 PCMU/8000\r\n\
+    ///     a=rtpmap:8 PCMA/8000\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n").unwrap();
+    /// let answer = Sdp::try_from("v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 0 111\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=extmap:4 urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+    ///     a=sendonly\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+    ///     a=mid:1\r\n").unwrap();
+    ///
+    /// let changes = local.apply_answer(&answer).unwrap();
+    /// assert_eq!(changes[0].removed_codecs, [8]);
+    /// assert_eq!(changes[0].direction, Some((Direction::SendRecv, Direction::RecvOnly)));
+    /// assert_eq!(changes[0].removed_extensions, [2, 3]);
+    /// assert_eq!(changes[0].renumbered_extensions, [(1, 4)]);
+    /// assert!(changes[1].rejected);
+    ///
+    /// assert_eq!(local.medias[0].fmts.as_slice(), &[0, 111]);
+    /// assert_eq!(local.medias[0].direction(), Some(Direction::RecvOnly));
+    /// assert!(local.medias[1].is_rejected());
+    ///
+    /// // applying the same answer again changes nothing.
+    /// assert!(local.apply_answer(&answer).unwrap().iter().all(|c| c.is_empty()));
+    /// ```
+    pub fn apply_answer(&mut self, answer: &Sdp<'_>) -> Result<Vec<MediaChanges>> {
+        ensure!(answer.medias.len() == self.medias.len(), "answer media count differs from offer!");
+
+        let mut changes = Vec::with_capacity(self.medias.len());
+        for index in 0..self.medias.len() {
+            let direction = answer.direction_for(index).unwrap_or_default().reverse();
+            changes.push(self.apply_media(answer, index, direction)?);
+        }
+
+        Ok(changes)
+    }
+
+    /// applies a remote offer to the local description.
+    ///
+    /// Codecs and header extensions are narrowed down like in
+    /// [`Sdp::apply_answer`], taking the payload types and ids of the
+    /// offer.  The direction becomes the answer to the offered one, see
+    /// [`Direction::answer_to`], with the current local direction as the
+    /// supported one.  Media descriptions added by the offer are added
+    /// rejected.
+    ///
+    /// Fails if the offer has fewer media descriptions than the local
+    /// description or a different media type.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let mut local = Sdp::try_from("v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=recvonly\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtcp-fb:96 nack\r\n\
+    ///     a=rtpmap:97 rtx/90000\r\n\
+    ///     a=fmtp:97 apt=96\r\n").unwrap().into_owned();
+    ///
+    /// let offer = Sdp::try_from("v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 100 101\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=rtpmap:100 VP8/90000\r\n\
+    ///     a=rtpmap:101 rtx/90000\r\n\
+    ///     a=fmtp:101 apt=100\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:1\r\n").unwrap();
+    ///
+    /// let changes = local.apply_offer(&offer).unwrap();
+    /// assert_eq!(changes[0].renumbered_codecs, [(96, 100), (97, 101)]);
+    /// assert_eq!(changes[0].direction, None);
+    /// assert!(changes[1].added && changes[1].rejected);
+    ///
+    /// assert_eq!(local.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 100 101\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=recvonly\r\n\
+    ///     a=rtpmap:100 VP8/90000\r\n\
+    ///     a=rtcp-fb:100 nack\r\n\
+    ///     a=rtpmap:101 rtx/90000\r\n\
+    ///     a=fmtp:101 apt=100\r\n\
+    ///     m=audio 0 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     a=mid:1\r\n");
+    /// ```
+    pub fn apply_offer(&mut self, offer: &Sdp<'_>) -> Result<Vec<MediaChanges>> {
+        ensure!(offer.medias.len() >= self.medias.len(), "offer removes media descriptions!");
+
+        let mut changes = Vec::with_capacity(offer.medias.len());
+        for index in 0..self.medias.len() {
+            let direction = Direction::answer_to(
+                offer.direction_for(index).unwrap_or_default(),
+                self.direction_for(index).unwrap_or_default(),
+            );
+            changes.push(self.apply_media(offer, index, direction)?);
+        }
+
+        for media in &offer.medias[self.medias.len()..] {
+            self.medias.push(Media {
+                encoding: media.encoding.clone(),
+                port: Port {
+                    num: 0,
+                    count: None,
+                },
+                protos: media.protos.clone(),
+                fmts: media.fmts.clone(),
+                info: None,
+                connections: SmallVec::new(),
+                bandwidth: SmallVec::new(),
+                attributes: media.mid()
+                    .map(|v| Attributes::Mid(v.clone().into_owned()).into())
+                    .into_iter()
+                    .collect(),
+                unknown_lines: Vec::new(),
+                section: None,
+            });
+
+            changes.push(MediaChanges {
+                added: true,
+                rejected: true,
+                ..MediaChanges::default()
+            });
+        }
+
+        Ok(changes)
+    }
+
+    fn apply_media(&mut self, remote: &Sdp<'_>, index: usize, direction: Direction) -> Result<MediaChanges> {
+        let mut changes = MediaChanges::default();
+        let current = self.direction_for(index).unwrap_or_default();
+        let (local, theirs) = (&mut self.medias[index], &remote.medias[index]);
+        ensure!(local.encoding == theirs.encoding, "media type differs at {}!", index);
+
+        if theirs.is_rejected() {
+            changes.rejected = !local.is_rejected();
+            local.reject();
+            return Ok(changes);
+        }
+
+        apply_codecs(local, theirs, &mut changes);
+
+        let extensions = theirs.attributes.iter().chain(&remote.attributes);
+        apply_extensions(local, extensions, &mut changes);
+
+        if direction != current {
+            local.set_direction(direction);
+            changes.direction = Some((current, direction));
+        }
+
+        Ok(changes)
+    }
+}
+
+fn codec<'b>(media: &'b Media<'_>, pt: u8) -> Option<&'b RtpValue<'b>> {
+    media.attributes.iter().find_map(|a| match &a.value {
+        Attributes::Rtpmap(v) if v.key == pt => Some(&v.value),
+        _ => None,
+    })
+}
+
+fn same_codec(a: &RtpValue<'_>, b: &RtpValue<'_>) -> bool {
+    a.codec.eq_ignore_ascii_case(&b.codec)
+        && a.frequency == b.frequency
+        && a.channels.unwrap_or(1) == b.channels.unwrap_or(1)
+}
+
+/// the local payload type of a remote one, payload types with the same
+/// number are preferred.  Static payload types (below 96) match by
+/// number if either side has no "a=rtpmap:" for them.
+fn local_codec(local: &Media<'_>, remote: &Media<'_>, pt: u8, used: &[(u8, u8)]) -> Option<u8> {
+    let unused = |v: &u8| !used.iter().any(|(l, _)| l == v);
+    let mapped = codec(remote, pt).and_then(|value| {
+        local.fmts
+            .iter()
+            .filter(|v| unused(v))
+            .filter(|v| codec(local, **v).is_some_and(|c| same_codec(c, value)))
+            .min_by_key(|v| **v != pt)
+            .copied()
+    });
+
+    let static_pt = (codec(remote, pt).is_none() || codec(local, pt).is_none()) && pt < 96;
+    mapped.or(Some(pt).filter(|v| static_pt && local.fmts.contains(v) && unused(v)))
+}
+
+/// the payload type of a per payload type attribute.
+fn codec_key(attribute: &Attributes<'_>) -> Option<u8> {
+    match attribute {
+        Attributes::Rtpmap(v) => Some(v.key),
+        Attributes::Fmtp(v) => Some(v.key),
+        Attributes::Other(k, Some(v)) if k == "rtcp-fb" => v.split(' ').next()?.parse().ok(),
+        _ => None,
+    }
+}
+
+fn apply_codecs(local: &mut Media<'_>, remote: &Media<'_>, changes: &mut MediaChanges) {
+    let mut map = Vec::new();
+    let mut fmts = SmallVec::new();
+    for pt in &remote.fmts {
+        if let Some(v) = local_codec(local, remote, *pt, &map) {
+            map.push((v, *pt));
+            fmts.push(*pt);
+        }
+    }
+
+    let renumber = |pt: u8| map.iter().find(|(l, _)| *l == pt).map(|(_, r)| *r);
+    changes.removed_codecs = local.fmts
+        .iter()
+        .copied()
+        .filter(|v| renumber(*v).is_none())
+        .collect();
+    changes.renumbered_codecs = map
+        .iter()
+        .copied()
+        .filter(|(l, r)| l != r)
+        .collect();
+
+    local.fmts = fmts;
+    local.attributes.retain(|a| codec_key(&a.value).map_or(true, |v| renumber(v).is_some()));
+    if changes.renumbered_codecs.is_empty() {
+        return;
+    }
+
+    for attribute in &mut local.attributes {
+        match &mut attribute.value {
+            Attributes::Rtpmap(v) => v.key = renumber(v.key).unwrap_or(v.key),
+            Attributes::Fmtp(v) => {
+                v.key = renumber(v.key).unwrap_or(v.key);
+
+                // retransmission formats name the payload type they
+                // repeat.
+                for (key, value) in &mut v.values {
+                    if let (true, Some(value)) = (key.trim() == "apt", value) {
+                        if let Some(pt) = value.trim().parse().ok().and_then(renumber) {
+                            *value = Cow::Owned(pt.to_string());
+                        }
+                    }
+                }
+            }
+            Attributes::Other(k, Some(v)) if k == "rtcp-fb" => {
+                if let Some((pt, rest)) = v.split_once(' ') {
+                    if let Some(pt) = pt.parse().ok().and_then(renumber) {
+                        *v = Cow::Owned(format!("{} {}", pt, rest));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// the extension URI of an "a=extmap:" value, without the extension
+/// attributes.
+fn uri(value: &str) -> &str {
+    value.split(' ').next().unwrap_or_default()
+}
+
+fn apply_extensions<'r, 'b: 'r>(
+    local: &mut Media<'_>,
+    remote: impl Iterator<Item = &'r Attribute<'b>> + Clone,
+    changes: &mut MediaChanges,
+) {
+    local.attributes.retain_mut(|attribute| {
+        let Attributes::Extmap(extmap) = &mut attribute.value else {
+            return true;
+        };
+
+        let remote_id = remote.clone().find_map(|a| match &a.value {
+            Attributes::Extmap(v) if uri(&v.value) == uri(&extmap.value) => Some(v.key),
+            _ => None,
+        });
+
+        match remote_id {
+            Some(id) => {
+                if id != extmap.key {
+                    changes.renumbered_extensions.push((extmap.key, id));
+                    extmap.key = id;
+                }
+
+                true
+            }
+            None => {
+                changes.removed_extensions.push(extmap.key);
+                false
+            }
+        }
+    });
+}
@@ -0,0 +1,168 @@
+//! Attribute normalization.
+//!
+//! Descriptions that went through several hands tend to collect
+//! repeated flags, duplicated "a=ssrc:" lines and codec attributes of
+//! payload types that were removed from the "m=" line.  Normalizing
+//! removes them and sorts the media attributes into the order browsers
+//! write them.
+
+use super::attributes::{
+    Attribute,
+    Attributes,
+    Direction
+};
+
+use super::media::Media;
+use super::Sdp;
+use alloc::vec::Vec;
+
+/// the position of an attribute in the WebRTC attribute order.
+///
+/// The order is the one of browser generated descriptions: "a=rtcp:",
+/// ICE, fingerprint, setup, mid, extmap, direction, msid, rtcp-mux, the
+/// unknown attributes, then rtpmap, rtcp-fb and fmtp of every payload
+/// type in "m=" line order, and last ssrc-group and ssrc.
+pub(crate) fn webrtc_rank(attribute: &Attributes<'_>, fmts: &[u8]) -> (u8, usize, u8) {
+    // the position of a payload type in the "m=" line, unknown payload
+    // types go last.
+    let pt = |pt: Option<u8>| match pt {
+        Some(pt) => fmts.iter().position(|v| *v == pt).unwrap_or(fmts.len()),
+        None => 0,
+    };
+
+    if Direction::from_attribute(attribute).is_some() {
+        return (6, 0, 0);
+    }
+
+    match attribute {
+        Attributes::IceMismatch | Attributes::IcePacing(_) => (1, 0, 0),
+        Attributes::Fingerprint(_) | Attributes::TlsId(_) => (2, 0, 0),
+        Attributes::Setup(_) | Attributes::Connection(_) => (3, 0, 0),
+        Attributes::Mid(_) => (4, 0, 0),
+        Attributes::Extmap(_) => (5, 0, 0),
+        Attributes::RtcpMuxOnly => (8, 0, 0),
+        Attributes::Rtpmap(v) => (10, pt(Some(v.key)), 0),
+        Attributes::Fmtp(v) => (10, pt(Some(v.key)), 2),
+        Attributes::Ssrc(_) => (12, 0, 0),
+        Attributes::Other(k, v) => match k.as_ref() {
+            "rtcp" => (0, 0, 0),
+            "ice-ufrag" | "ice-pwd" | "ice-options" | "ice-lite" | "candidate" | "end-of-candidates" => (1, 0, 0),
+            "fingerprint" => (2, 0, 0),
+            "extmap-allow-mixed" => (5, 0, 0),
+            "msid" => (7, 0, 0),
+            "rtcp-mux" | "rtcp-rsize" => (8, 0, 0),
+            "rtcp-fb" => {
+                let key = v.as_deref().and_then(|v| v.split(' ').next());
+                (10, pt(key.and_then(|v| v.parse().ok())), 1)
+            }
+            "ssrc-group" => (11, 0, 0),
+            _ => (9, 0, 0),
+        },
+        _ => (9, 0, 0),
+    }
+}
+
+/// attributes that carry no value.
+fn is_flag(attribute: &Attributes<'_>) -> bool {
+    matches!(
+        attribute,
+        Attributes::Other(_, None)
+            | Attributes::BundleOnly
+            | Attributes::RtcpMuxOnly
+            | Attributes::IceMismatch
+            | Attributes::Sendrecv(_)
+            | Attributes::Sendonly(_)
+            | Attributes::Recvonly(_)
+            | Attributes::Inactive(_)
+    )
+}
+
+/// removes repeated flags and "a=ssrc:" lines, the first one is kept.
+fn dedup(attributes: &mut Vec<Attribute<'_>>) {
+    let mut i = 0;
+    while i < attributes.len() {
+        let value = &attributes[i].value;
+        let repeated = (is_flag(value) || matches!(value, Attributes::Ssrc(_)))
+            && attributes[..i].iter().any(|a| a.value == *value);
+        match repeated {
+            true => drop(attributes.remove(i)),
+            false => i += 1,
+        }
+    }
+}
+
+impl<'a> Media<'a> {
+    /// removes repeated flags, repeated "a=ssrc:" lines and the
+    /// "a=rtpmap:" and "a=fmtp:" attributes of payload types that are
+    /// not in the format list, then sorts the attributes into the
+    /// WebRTC order.
+    ///
+    /// Attributes of the same kind keep their relative order.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 0 111\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=fmtp:111 minptime=10\r\n\
+    ///     a=rtpmap:0 PCMU/8000\r\n\
+    ///     a=rtpmap:9 G722/8000\r\n\
+    ///     a=fmtp:9 x=1\r\n\
+    ///     a=ssrc:1 cname:panda\r\n\
+    ///     a=ssrc:1 cname:panda\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=setup:actpass\r\n";
+    ///
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// sdp.normalize();
+    ///
+    /// assert_eq!(sdp.to_string(), "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 0 111\r\n\
+    ///     a=ice-ufrag:Oyef\r\n\
+    ///     a=setup:actpass\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=sendrecv\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=rtpmap:0 PCMU/8000\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtcp-fb:111 transport-cc\r\n\
+    ///     a=fmtp:111 minptime=10\r\n\
+    ///     a=ssrc:1 cname:panda\r\n");
+    /// ```
+    pub fn normalize(&mut self) {
+        dedup(&mut self.attributes);
+
+        let fmts = &self.fmts;
+        self.attributes.retain(|a| match &a.value {
+            Attributes::Rtpmap(v) => fmts.contains(&v.key),
+            Attributes::Fmtp(v) => fmts.contains(&v.key),
+            _ => true,
+        });
+
+        self.attributes.sort_by_key(|a| webrtc_rank(&a.value, fmts));
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// removes repeated session level flags and normalizes every media,
+    /// see [`Media::normalize`].
+    pub fn normalize(&mut self) {
+        dedup(&mut self.attributes);
+        for media in &mut self.medias {
+            media.normalize();
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use crate::attributes::{
+    Attribute,
+    OnceKey,
+    UsageLevel
+};
+
+use alloc::string::String;
+use core::fmt;
+
+/// Parse options.
+///
+/// Controls how tolerant the session parser is towards input that does
+/// not strictly follow the grammar of
+/// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-9).
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::options::*;
+///
+/// let options = ParseOptions::default();
+/// assert_eq!(options.strict, false);
+/// assert_eq!(options.trim, false);
+/// assert_eq!(options.enforce_usage, false);
+/// assert_eq!(options.duplicates, DuplicatePolicy::KeepAll);
+///
+/// let options = ParseOptions::strict();
+/// assert_eq!(options.strict, true);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Strict mode only accepts lines terminated by CRLF, as required by
+    /// the SDP grammar.  In lenient mode (the default) bare LF line
+    /// endings and a missing line ending after the last line are
+    /// accepted as well, and empty lines are skipped.  Strict mode also
+    /// rejects "a=mid:" values that are not tokens or too long, see
+    /// [`crate::attributes::Mid::check`], and "a=orient:" and "a=type:"
+    /// values that are not one of their case-sensitive names, which
+    /// lenient mode keeps as [`crate::attributes::Attributes::Other`].
+    pub strict: bool,
+    /// Remove leading and trailing whitespace from every line before it
+    /// is parsed.  Ignored in strict mode.  Note that the value of a line
+    /// can contain significant whitespace (e.g. "s= "), so this is
+    /// disabled by default.
+    pub trim: bool,
+    /// Reject attributes that appear at a level their usage level does
+    /// not allow, e.g. "a=orient:" at session level, with a
+    /// [`UsageViolation`] error.  To only warn about them, validate the
+    /// description with [`crate::validate::UsageLevels`] instead.
+    pub enforce_usage: bool,
+    /// What to do with an attribute that may appear at most once, e.g.
+    /// "a=mid:" or "a=fmtp:" for the same payload type, when it appears
+    /// again in the same session or media description, see
+    /// [`crate::attributes::Attributes::once`].  Ignored by
+    /// [`crate::Sdp::parse_in`].
+    pub duplicates: DuplicatePolicy,
+    /// Input size and count limits.
+    pub limits: Limits,
+}
+
+impl ParseOptions {
+    /// strict parse options.
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
+    /// check the usage level of an attribute, if enforced.
+    pub(crate) fn check_usage(&self, attribute: &Attribute<'_>, media: bool) -> Result<(), UsageViolation> {
+        let usage = attribute.value.usage();
+        if !self.enforce_usage || usage.allows(media) {
+            return Ok(())
+        }
+
+        Err(UsageViolation {
+            name: String::from(attribute.value.name()),
+            usage,
+        })
+    }
+
+    /// the index of the earlier attribute the given one duplicates, if
+    /// duplicates are not kept.
+    pub(crate) fn find_duplicate(
+        &self,
+        attribute: &Attribute<'_>,
+        attributes: &[Attribute<'_>]
+    ) -> Result<Option<usize>, DuplicateAttribute> {
+        let key = match attribute.value.once() {
+            Some(k) if self.duplicates != DuplicatePolicy::KeepAll => k,
+            _ => return Ok(None),
+        };
+
+        match attributes.iter().position(|a| a.value.once() == Some(key)) {
+            Some(_) if self.duplicates == DuplicatePolicy::Error => Err(DuplicateAttribute { key }),
+            index => Ok(index),
+        }
+    }
+}
+
+/// Duplicate attribute policy, see [`ParseOptions::duplicates`].
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::options::*;
+/// use sdp::Sdp;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=fmtp:96 profile-id=0\r\n\
+///     a=mid:0\r\n\
+///     a=fmtp:96 profile-id=1\r\n";
+///
+/// let parse = |duplicates| Sdp::parse(temp, &ParseOptions {
+///     duplicates,
+///     ..ParseOptions::default()
+/// });
+///
+/// assert_eq!(parse(DuplicatePolicy::KeepAll).unwrap().medias[0].attributes.len(), 3);
+///
+/// let err = parse(DuplicatePolicy::Error).unwrap_err();
+/// let err = err.downcast_ref::<DuplicateAttribute>().unwrap();
+/// assert_eq!(err.to_string(), "duplicate attribute fmtp:96!");
+///
+/// let sdp = parse(DuplicatePolicy::KeepFirst).unwrap();
+/// let raw = sdp.medias[0].attributes.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+/// assert_eq!(raw, ["fmtp:96 profile-id=0", "mid:0"]);
+///
+/// let sdp = parse(DuplicatePolicy::KeepLast).unwrap();
+/// let raw = sdp.medias[0].attributes.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+/// assert_eq!(raw, ["mid:0", "fmtp:96 profile-id=1"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// keep every attribute, in input order.
+    #[default]
+    KeepAll,
+    /// fail the parse with a [`DuplicateAttribute`] error.
+    Error,
+    /// keep the first attribute and drop the later ones.
+    KeepFirst,
+    /// keep the last attribute, at its position in the input.
+    KeepLast,
+}
+
+/// Error returned when an attribute that may appear at most once is
+/// repeated, see [`DuplicatePolicy::Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateAttribute {
+    /// the identity of the repeated attribute.
+    pub key: OnceKey,
+}
+
+impl fmt::Display for DuplicateAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate attribute {}!", self.key)
+    }
+}
+
+impl core::error::Error for DuplicateAttribute {}
+
+/// Error returned when an attribute appears at a level its usage level
+/// does not allow, see [`ParseOptions::enforce_usage`].
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::attributes::UsageLevel;
+/// use sdp::options::*;
+/// use sdp::Sdp;
+///
+/// let options = ParseOptions {
+///     enforce_usage: true,
+///     ..ParseOptions::default()
+/// };
+///
+/// let temp = "v=0\r\ns=-\r\na=orient:portrait\r\nm=video 9 RTP/AVP 96\r\n";
+/// let err = Sdp::parse(temp, &options).unwrap_err();
+/// let err = err.downcast_ref::<UsageViolation>().unwrap();
+/// assert_eq!(err.name, "orient");
+/// assert_eq!(err.usage, UsageLevel::Media);
+/// assert_eq!(err.to_string(), "attribute orient is only allowed at media level!");
+///
+/// assert!(Sdp::parse(temp, &ParseOptions::default()).is_ok());
+///
+/// let temp = "v=0\r\ns=-\r\nm=video 9 RTP/AVP 96\r\na=orient:portrait\r\n";
+/// assert!(Sdp::parse(temp, &options).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageViolation {
+    /// the attribute name.
+    pub name: String,
+    /// the usage level of the attribute.
+    pub usage: UsageLevel,
+}
+
+impl fmt::Display for UsageViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "attribute {} is only allowed at {} level!", self.name, self.usage)
+    }
+}
+
+impl core::error::Error for UsageViolation {}
+
+/// Parse limits.
+///
+/// Bounds the amount of work and memory spent on a single description,
+/// so that services exposed to untrusted input can not be blown up by
+/// adversarial descriptions.  Exceeding a limit fails the parse with a
+/// [`LimitsExceeded`] error.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::options::*;
+/// use sdp::Sdp;
+///
+/// let options = ParseOptions {
+///     limits: Limits {
+///         max_medias: 1,
+///         ..Limits::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let temp = "v=0\r\nm=audio 9 RTP/AVP 0\r\nm=video 9 RTP/AVP 96\r\n";
+/// let err = Sdp::parse(temp, &options).unwrap_err();
+/// let err = err.downcast_ref::<LimitsExceeded>().unwrap();
+/// assert_eq!(err.limit, Limit::Medias);
+/// assert_eq!(err.max, 1);
+///
+/// let temp = format!("v=0\r\ns={}\r\n", "-".repeat(5000));
+/// let err = Sdp::parse(&temp, &ParseOptions::default()).unwrap_err();
+/// let err = err.downcast_ref::<LimitsExceeded>().unwrap();
+/// assert_eq!(err.limit, Limit::LineLength);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// maximum length of a line in bytes, without the line ending.
+    pub max_line_length: usize,
+    /// maximum number of attributes at session level and per media
+    /// description.
+    pub max_attributes: usize,
+    /// maximum number of media descriptions.
+    pub max_medias: usize,
+    /// maximum number of parameters in a single "a=fmtp" attribute.
+    pub max_fmtp_params: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_line_length: 4096,
+            max_attributes: 1024,
+            max_medias: 256,
+            max_fmtp_params: 64,
+        }
+    }
+}
+
+/// Limit kind.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    LineLength,
+    Attributes,
+    Medias,
+    FmtpParams,
+}
+
+/// Error returned when a description exceeds the configured [`Limits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitsExceeded {
+    /// the exceeded limit.
+    pub limit: Limit,
+    /// the configured maximum.
+    pub max: usize,
+}
+
+impl Limits {
+    pub(crate) fn check(&self, limit: Limit, value: usize) -> Result<(), LimitsExceeded> {
+        let max = match limit {
+            Limit::LineLength => self.max_line_length,
+            Limit::Attributes => self.max_attributes,
+            Limit::Medias => self.max_medias,
+            Limit::FmtpParams => self.max_fmtp_params,
+        };
+
+        match value > max {
+            true => Err(LimitsExceeded { limit, max }),
+            false => Ok(())
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::LineLength => "line length",
+            Self::Attributes => "attributes",
+            Self::Medias =>     "media descriptions",
+            Self::FmtpParams => "fmtp parameters",
+        })
+    }
+}
+
+impl fmt::Display for LimitsExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "limits exceeded: more than {} {}!", self.max, self.limit)
+    }
+}
+
+impl core::error::Error for LimitsExceeded {}
+
+/// Serialization options, see [`crate::Sdp::display`].
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    /// the order of the media attributes.
+    pub order: AttributeOrder,
+    /// write parsed attributes as they appeared in the input, unless
+    /// their value was modified, see [`crate::attributes::Attribute::original`].
+    /// Pass-through proxies use this to forward attributes unchanged.
+    pub raw: bool,
+    /// write the lines of unknown type kept by the parser, see
+    /// [`crate::lines::RawLine`], after the attributes of the session or
+    /// media description they appeared under.
+    pub unknown_lines: bool,
+    /// how "a=rtcp-fb:*" wildcards are written.
+    pub feedback_wildcards: FeedbackWildcards,
+    /// copy parsed media descriptions from the input instead of writing
+    /// every line, unless they were modified, see
+    /// [`crate::media::Media::original`].  Only applies with the input
+    /// attribute order and the wildcards kept, and to media without
+    /// unknown lines unless these are written.
+    pub raw_sections: bool,
+}
+
+/// Writing of "a=rtcp-fb:*" wildcards, see
+/// [`WriteOptions::feedback_wildcards`].
+///
+/// Some endpoints ignore the wildcard, others prefer the short form.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::options::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=video 9 UDP/TLS/RTP/SAVPF 96 98\r\n\
+///     a=rtcp-fb:* nack\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let options = WriteOptions {
+///     feedback_wildcards: FeedbackWildcards::Expand,
+///     ..Default::default()
+/// };
+///
+/// let expanded = sdp.display(&options).to_string();
+/// assert!(expanded.ends_with("a=rtcp-fb:96 nack\r\na=rtcp-fb:98 nack\r\n"));
+///
+/// let sdp = Sdp::try_from(expanded.as_str()).unwrap();
+/// let options = WriteOptions {
+///     feedback_wildcards: FeedbackWildcards::Collapse,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(sdp.display(&options).to_string(), temp);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedbackWildcards {
+    /// write the attributes as they are.
+    #[default]
+    Keep,
+    /// write one attribute per payload type instead of a wildcard, see
+    /// [`crate::media::Media::expand_feedback_wildcards`].
+    Expand,
+    /// write a wildcard for feedback every payload type has, see
+    /// [`crate::media::Media::collapse_feedback_wildcards`].
+    Collapse,
+}
+
+/// Attribute order of a written description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeOrder {
+    /// the order of the attribute list, which is the input order for
+    /// parsed descriptions.
+    #[default]
+    Input,
+    /// the order browsers write media attributes in: ICE, fingerprint,
+    /// setup, mid, extmap, direction, msid, rtcp-mux, then rtpmap,
+    /// rtcp-fb and fmtp per payload type, ssrc-group and ssrc.  Some
+    /// endpoints reject descriptions with an unusual order.
+    WebRtc,
+}
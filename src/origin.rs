@@ -1,13 +1,15 @@
-use std::net::IpAddr;
-use anyhow::ensure;
+use alloc::borrow::Cow;
+use core::net::IpAddr;
+use super::scanner::Scanner;
 use super::{
     NetKind,
     AddrKind,
     util::placeholder
 };
 
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
@@ -16,25 +18,27 @@ use std::{
 /// The "o=" line (origin-field) gives the originator of the session (her
 /// username and the address of the user's host) plus a session
 /// identifier and version number.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Origin<'a> {
     /// <username>  is the user's login on the originating host, or it is "-"
     /// if the originating host does not support the concept of user IDs.
     /// The <username> MUST NOT contain spaces.
-    pub username: Option<&'a str>,
+    pub username: Option<Cow<'a, str>>,
     /// <sess-id>  is a numeric string such that the tuple of <username>,
     /// <sess-id>, <nettype>, <addrtype>, and <unicast-address> forms a
     /// globally unique identifier for the session.  The method of <sess-
     /// id> allocation is up to the creating tool, but a timestamp, in
     /// seconds since January 1, 1900 UTC, is recommended to ensure
     /// uniqueness.
-    pub sess_id: &'a str,
+    pub sess_id: Cow<'a, str>,
     /// <sess-version>  is a version number for this session description.
     /// Its usage is up to the creating tool, so long as <sess-version> is
     /// increased when a modification is made to the session description.
     /// Again, as with <sess-id> it is RECOMMENDED that a timestamp be
     /// used.
-    pub sess_version: u8,
+    pub sess_version: u64,
     /// <nettype>  is a text string giving the type of network.  Initially,
     /// "IN" is defined to have the meaning "Internet".
     pub nettype: NetKind,
@@ -54,6 +58,45 @@ pub struct Origin<'a> {
     pub unicast_address: IpAddr,
 }
 
+impl<'a> Origin<'a> {
+    /// convert to an owned value that does not borrow the input.
+    pub fn into_owned<'b>(self) -> Origin<'b> {
+        Origin {
+            username: self.username.map(|u| Cow::Owned(u.into_owned())),
+            sess_id: Cow::Owned(self.sess_id.into_owned()),
+            sess_version: self.sess_version,
+            nettype: self.nettype,
+            addrtype: self.addrtype,
+            unicast_address: self.unicast_address,
+        }
+    }
+
+    /// whether this origin is a later version of the same session.
+    ///
+    /// Both lines must have the same <sess-id>, and this line a higher
+    /// <sess-version>
+    /// ([RFC3264](https://tools.ietf.org/html/rfc3264#section-8)).
+    /// Lines of different sessions are never newer than each other.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::origin::*;
+    /// use std::convert::*;
+    ///
+    /// let old = Origin::try_from("- 1 3724394400 IN IP4 127.0.0.1").unwrap();
+    /// let new = Origin::try_from("- 1 3724394401 IN IP4 127.0.0.1").unwrap();
+    /// let other = Origin::try_from("- 2 3724394402 IN IP4 127.0.0.1").unwrap();
+    ///
+    /// assert!(new.newer_than(&old));
+    /// assert!(!old.newer_than(&new));
+    /// assert!(!other.newer_than(&new));
+    /// ```
+    pub fn newer_than(&self, other: &Origin<'_>) -> bool {
+        self.sess_id == other.sess_id && self.sess_version > other.sess_version
+    }
+}
+
 impl<'a> fmt::Display for Origin<'a> {
     /// # Unit Test
     ///
@@ -64,7 +107,7 @@ impl<'a> fmt::Display for Origin<'a> {
     /// let temp = "- 9216395717180620054 2 IN IP4 127.0.0.1".to_string();
     /// let origin = Origin {
     ///     username: None,
-    ///     sess_id: "9216395717180620054",
+    ///     sess_id: "9216395717180620054".into(),
     ///     sess_version: 2,
     ///     nettype: NetKind::IN,
     ///     addrtype: AddrKind::IP4,
@@ -77,7 +120,7 @@ impl<'a> fmt::Display for Origin<'a> {
         write!(
             f, 
             "{} {} {} {} {} {:?}",
-            self.username.unwrap_or("-"),
+            self.username.as_deref().unwrap_or("-"),
             self.sess_id,
             self.sess_version,
             self.nettype,
@@ -95,7 +138,7 @@ impl<'a> TryFrom<&'a str> for Origin<'a> {
     /// use sdp::*;
     /// use sdp::origin::*;
     /// use std::convert::*;
-    /// use std::net::IpAddr;
+    /// use core::net::IpAddr;
     ///
     /// let addr: IpAddr = "127.0.0.1".parse().unwrap();
     /// let temp = "- 9216395717180620054 2 IN IP4 127.0.0.1";
@@ -107,17 +150,37 @@ impl<'a> TryFrom<&'a str> for Origin<'a> {
     /// assert_eq!(instance.nettype, NetKind::IN);
     /// assert_eq!(instance.addrtype, AddrKind::IP4);
     /// assert_eq!(instance.unicast_address, addr);
+    ///
+    /// let instance = Origin::try_from("Mitel Networks 1 2 IN IP4 127.0.0.1").unwrap();
+    /// assert_eq!(instance.username.as_deref(), Some("Mitel Networks"));
+    ///
+    /// let err = Origin::try_from("2 IN IP4 127.0.0.1").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid origin: missing field 5!");
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split(' ').collect::<Vec<&str>>();
-        ensure!(values.len() == 6, "invalid origin!");
+        // the username is read last, some implementations put SP in it.
+        let mut scanner = Scanner::new(value, "origin");
+        let addr = scanner.field_back()?;
+        let a = scanner.field_back()?;
+        let n = scanner.field_back()?;
+        let v = scanner.field_back()?;
+        let i = scanner.field_back()?;
+        let u = scanner.rest()?;
         Ok(Self {
-            sess_id: values[1],
-            sess_version: values[2].parse()?,
-            unicast_address: values[5].parse()?,
-            nettype: NetKind::try_from(values[3])?,
-            addrtype: AddrKind::try_from(values[4])?,
-            username: placeholder(values[0]),
+            sess_id: Cow::Borrowed(i),
+            sess_version: v.parse()?,
+            unicast_address: addr.parse()?,
+            nettype: NetKind::try_from(n)?,
+            addrtype: AddrKind::try_from(a)?,
+            username: placeholder(u).map(Cow::Borrowed),
         })
     }
 }
+
+impl FromStr for Origin<'static> {
+    type Err = anyhow::Error;
+    /// parse an owned origin, the value does not borrow the input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Origin::try_from(value).map(Origin::into_owned)
+    }
+}
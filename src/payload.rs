@@ -0,0 +1,217 @@
+//! RTP payload type allocation.
+//!
+//! Dynamic payload types are allocated from 96-127 first, then from
+//! 35-63.  The range 64-95 is not used, since it conflicts with the RTCP
+//! packet types when RTP and RTCP are multiplexed
+//! [RFC5761](https://datatracker.ietf.org/doc/html/rfc5761#section-4).
+
+use crate::attributes::{
+    Attributes,
+    RtpMap,
+    RtpValue
+};
+
+use crate::media::Media;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+/// Set of used payload types.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::payload::*;
+///
+/// let mut types = PayloadTypes::default();
+/// types.reserve(96).unwrap();
+/// assert!(types.contains(96));
+/// assert!(types.reserve(96).is_err());
+/// assert!(types.reserve(128).is_err());
+///
+/// assert_eq!(types.allocate().unwrap(), 97);
+///
+/// for pt in 98..=127 {
+///     types.reserve(pt).unwrap();
+/// }
+///
+/// assert_eq!(types.allocate().unwrap(), 35);
+/// for _ in 36..=63 {
+///     types.allocate().unwrap();
+/// }
+///
+/// assert!(types.allocate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PayloadTypes(u128);
+
+impl PayloadTypes {
+    /// the payload types used by a media description, in the "m=" line
+    /// or in "a=rtpmap:" and "a=fmtp:" attributes.
+    pub fn from_media(media: &Media<'_>) -> Self {
+        let mut types = Self::default();
+        let attributes = media.attributes.iter().filter_map(|a| match &a.value {
+            Attributes::Rtpmap(v) => Some(v.key),
+            Attributes::Fmtp(v) => Some(v.key),
+            _ => None,
+        });
+
+        for pt in media.fmts.iter().copied().chain(attributes) {
+            if pt < 128 {
+                types.0 |= 1 << pt;
+            }
+        }
+
+        types
+    }
+
+    pub fn contains(&self, pt: u8) -> bool {
+        pt < 128 && self.0 & (1 << pt) != 0
+    }
+
+    /// mark a payload type as used, fails if it is already used.
+    pub fn reserve(&mut self, pt: u8) -> Result<()> {
+        ensure!(pt < 128, "invalid payload type {}!", pt);
+        ensure!(!self.contains(pt), "payload type {} is already used!", pt);
+        self.0 |= 1 << pt;
+        Ok(())
+    }
+
+    /// allocate a free dynamic payload type.
+    pub fn allocate(&mut self) -> Result<u8> {
+        let pt = (96..=127)
+            .chain(35..=63)
+            .find(|pt| !self.contains(*pt))
+            .ok_or_else(|| anyhow!("no free payload type!"))?;
+        self.0 |= 1 << pt;
+        Ok(pt)
+    }
+}
+
+impl<'a> Media<'a> {
+    /// the payload types used by the media description.
+    pub fn payload_types(&self) -> PayloadTypes {
+        PayloadTypes::from_media(self)
+    }
+
+    /// the encoding of a payload type, from its "a=rtpmap:" attribute
+    /// or from the static payload types of the RTP/AVP profile, see
+    /// [`RtpValue::from_static`].
+    pub fn codec(&self, pt: u8) -> Option<RtpValue<'_>> {
+        let rtpmap = self.attributes.iter().find_map(|a| match &a.value {
+            Attributes::Rtpmap(v) if v.key == pt => Some(RtpValue {
+                codec: Cow::Borrowed(v.value.codec.as_ref()),
+                frequency: v.value.frequency,
+                channels: v.value.channels,
+            }),
+            _ => None,
+        });
+
+        rtpmap.or_else(|| RtpValue::from_static(pt))
+    }
+
+    /// the payload types of the "m=" line with their encodings, see
+    /// [`Media::codec`].  Payload types without a known encoding are
+    /// skipped.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 111 0 8 97\r\na=rtpmap:111 opus/48000/2\r\n";
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let codecs = sdp.medias[0]
+    ///     .codecs()
+    ///     .iter()
+    ///     .map(|(pt, v)| format!("{} {}", pt, v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(codecs, vec!["111 opus/48000/2", "0 PCMU/8000/1", "8 PCMA/8000/1"]);
+    /// ```
+    pub fn codecs(&self) -> Vec<(u8, RtpValue<'_>)> {
+        self.fmts
+            .iter()
+            .filter_map(|pt| Some((*pt, self.codec(*pt)?)))
+            .collect()
+    }
+
+    /// the comfort noise payload types of the "m=" line, "CN" from an
+    /// "a=rtpmap:" attribute or the static payload type 13
+    /// [RFC3389](https://datatracker.ietf.org/doc/html/rfc3389#section-5).
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 111 13 105\r\na=rtpmap:111 opus/48000/2\r\na=rtpmap:105 CN/16000\r\n";
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert_eq!(sdp.medias[0].comfort_noise(), vec![13, 105]);
+    /// ```
+    pub fn comfort_noise(&self) -> Vec<u8> {
+        self.codecs()
+            .into_iter()
+            .filter(|(_, v)| v.codec.eq_ignore_ascii_case("CN"))
+            .map(|(pt, _)| pt)
+            .collect()
+    }
+
+    /// add a codec with a free dynamic payload type, and return the
+    /// payload type.
+    ///
+    /// The payload type is appended to the "m=" line, and an
+    /// "a=rtpmap:" attribute is added.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0 96\r\na=rtpmap:96 opus/48000/2\r\n";
+    /// let mut sdp = Sdp::try_from(temp).unwrap();
+    /// let media = &mut sdp.medias[0];
+    ///
+    /// let pt = media.add_codec(RtpValue::try_from("telephone-event/8000").unwrap()).unwrap();
+    /// assert_eq!(pt, 97);
+    /// assert_eq!(
+    ///     format!("{}", sdp),
+    ///     "v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0 96 97\r\n\
+    ///     a=rtpmap:96 opus/48000/2\r\na=rtpmap:97 telephone-event/8000\r\n"
+    /// );
+    ///
+    /// let media = &mut sdp.medias[0];
+    /// assert!(media.add_codec_with(96, RtpValue::try_from("PCMA/8000").unwrap()).is_err());
+    /// assert!(media.add_codec_with(8, RtpValue::try_from("PCMA/8000").unwrap()).is_ok());
+    /// assert_eq!(media.fmts.as_slice(), &[0, 96, 97, 8]);
+    /// ```
+    pub fn add_codec(&mut self, codec: RtpValue<'a>) -> Result<u8> {
+        let pt = self.payload_types().allocate()?;
+        self.push_codec(pt, codec);
+        Ok(pt)
+    }
+
+    /// add a codec with the given payload type, fails if the payload
+    /// type is already used.
+    pub fn add_codec_with(&mut self, pt: u8, codec: RtpValue<'a>) -> Result<()> {
+        self.payload_types().reserve(pt)?;
+        self.push_codec(pt, codec);
+        Ok(())
+    }
+
+    fn push_codec(&mut self, pt: u8, codec: RtpValue<'a>) {
+        self.fmts.push(pt);
+        self.attributes.push(Attributes::Rtpmap(RtpMap {
+            key: pt,
+            value: codec,
+        }).into());
+    }
+}
@@ -0,0 +1,243 @@
+//! Parsing from a reader.
+//!
+//! [`Sdp::from_reader`] reads one line at a time and converts every
+//! parsed line to owned values, so only the current line is buffered,
+//! not the whole description.  With the "tokio" feature,
+//! [`Sdp::from_async_reader`] does the same for an `AsyncBufRead`, e.g.
+//! a signaling connection.
+
+use super::lines::{
+    Line,
+    RawLine
+};
+use super::options::ParseOptions;
+use super::span::Span;
+
+use super::util::split_lines;
+use super::{
+    Key,
+    Sdp
+};
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use std::io::{
+    BufRead,
+    Read
+};
+
+impl Sdp<'static> {
+    /// parse a session description from a reader with the given options.
+    ///
+    /// Lines are read one at a time, so large descriptions, e.g. long
+    /// multicast channel lists, are parsed without holding the input in
+    /// memory.  A line is read up to the line length limit, see
+    /// [`crate::options::Limits::max_line_length`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::options::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 1 2 IN IP4 127.0.0.1\r\n\
+    ///     s=Channels\r\n\
+    ///     t=0 0\r\n\
+    ///     m=video 5000 RTP/AVP 33\r\n\
+    ///     i=channel 1\r\n\
+    ///     c=IN IP4 233.252.0.1/127\r\n\
+    ///     a=recvonly\r\n";
+    ///
+    /// let sdp = Sdp::from_reader(temp.as_bytes(), &ParseOptions::default()).unwrap();
+    /// assert_eq!(sdp, Sdp::try_from(temp).unwrap());
+    /// assert_eq!(sdp.to_string(), temp);
+    ///
+    /// let span = sdp.medias[0].attributes[0].span().unwrap();
+    /// assert_eq!(span.line, 8);
+    /// assert_eq!(&temp[span.range()], "a=recvonly");
+    ///
+    /// let media = sdp.spans.media(0).unwrap();
+    /// assert_eq!(&temp[media.get(Key::Media).unwrap().range()], "m=video 5000 RTP/AVP 33");
+    /// assert_eq!(&temp[media.get(Key::SessionInfo).unwrap().range()], "i=channel 1");
+    /// assert_eq!(sdp.spans.session().get(Key::Origin).unwrap().line, 2);
+    ///
+    /// let options = ParseOptions {
+    ///     limits: Limits {
+    ///         max_line_length: 8,
+    ///         ..Limits::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let err = Sdp::from_reader(temp.as_bytes(), &options).unwrap_err();
+    /// assert_eq!(err.downcast_ref::<LimitsExceeded>().unwrap().limit, Limit::LineLength);
+    /// ```
+    pub fn from_reader<R: BufRead>(mut reader: R, options: &ParseOptions) -> anyhow::Result<Self> {
+        let mut state = State::new(options);
+        loop {
+            state.buf.clear();
+            let len = reader.by_ref().take(state.max).read_line(&mut state.buf)?;
+            if len == 0 {
+                break;
+            }
+
+            state.handle(len)?;
+        }
+
+        Ok(state.sdp)
+    }
+
+    /// parse a session description from an async reader with the given
+    /// options, see [`Sdp::from_reader`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::options::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 1 2 IN IP4 127.0.0.1\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=audio 49170 RTP/AVP 0\r\n\
+    ///     a=sendrecv\r\n";
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let sdp = runtime
+    ///     .block_on(Sdp::from_async_reader(temp.as_bytes(), &ParseOptions::default()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sdp, Sdp::try_from(temp).unwrap());
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn from_async_reader<R>(mut reader: R, options: &ParseOptions) -> anyhow::Result<Self>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+    {
+        use tokio::io::{
+            AsyncBufReadExt,
+            AsyncReadExt
+        };
+
+        let mut state = State::new(options);
+        loop {
+            state.buf.clear();
+            let len = (&mut reader).take(state.max).read_line(&mut state.buf).await?;
+            if len == 0 {
+                break;
+            }
+
+            state.handle(len)?;
+        }
+
+        Ok(state.sdp)
+    }
+}
+
+/// the parser state between lines, shared by the blocking and the async
+/// reader.
+struct State<'o> {
+    sdp: Sdp<'static>,
+    options: &'o ParseOptions,
+    in_media: bool,
+    /// the current line.
+    buf: String,
+    /// the maximum number of bytes to read for a line: the line, its
+    /// line ending and one more byte, so that a line over the limit is
+    /// detected by `split_lines` without reading all of it.
+    max: u64,
+    /// the byte offset of the current line.
+    offset: usize,
+    /// the 1-based number of the current line.
+    number: usize,
+}
+
+impl<'o> State<'o> {
+    fn new(options: &'o ParseOptions) -> Self {
+        Self {
+            sdp: Sdp::default(),
+            options,
+            in_media: false,
+            buf: String::new(),
+            max: options.limits.max_line_length.saturating_add(3) as u64,
+            offset: 0,
+            number: 0,
+        }
+    }
+
+    /// handle the `len` bytes read into the buffer.
+    fn handle(&mut self, len: usize) -> anyhow::Result<()> {
+        self.number += 1;
+        for line in split_lines(&self.buf, self.options) {
+            let line = line?;
+            let start = self.offset + (line.as_ptr() as usize - self.buf.as_ptr() as usize);
+            let span = Span {
+                line: self.number,
+                start,
+                end: start + line.len(),
+            };
+
+            let (key, text) = match Line::parse(line)? {
+                Line::Attribute(mut v) => {
+                    v.span = Some(span);
+                    self.sdp.handle_line(owned(Line::Attribute(v)), &mut self.in_media, self.options)?;
+                    continue;
+                }
+                Line::Unknown(v) => {
+                    self.sdp.handle_unknown(RawLine {
+                        text: Cow::Owned(String::from(v)),
+                        span: Some(span),
+                    }, self.in_media);
+                    continue;
+                }
+                Line::SessionName(v) => (Key::SessionName, v),
+                Line::SessionInfo(v) => (Key::SessionInfo, v),
+                Line::Uri(v) =>         (Key::Uri, v),
+                Line::Email(v) =>       (Key::Email, v),
+                Line::Phone(v) =>       (Key::Phone, v),
+                line => {
+                    let key = line.key();
+                    self.sdp.handle_line(owned(line), &mut self.in_media, self.options)?;
+                    if let Some(key) = key {
+                        self.sdp.spans.push(key, span);
+                    }
+
+                    continue;
+                }
+            };
+
+            self.sdp.handle_text(key, Cow::Owned(String::from(text)), self.in_media)?;
+            self.sdp.spans.push(key, span);
+        }
+
+        self.offset += len;
+        Ok(())
+    }
+}
+
+/// convert a line to owned values, the text lines are handled by
+/// [`Sdp::handle_text`] and are dropped.
+fn owned(line: Line<'_>) -> Line<'static> {
+    match line {
+        Line::Version(v) =>       Line::Version(v),
+        Line::Origin(v) =>        Line::Origin(v.into_owned()),
+        Line::Connection(v) =>    Line::Connection(v),
+        Line::Bandwidth(v) =>     Line::Bandwidth(v),
+        Line::Timing(v) =>        Line::Timing(v),
+        Line::RepeatTimes(v) =>   Line::RepeatTimes(v),
+        Line::TimeZones(v) =>     Line::TimeZones(v),
+        Line::EncryptionKey(v) => Line::EncryptionKey(v.into_owned()),
+        Line::Attribute(v) =>     Line::Attribute(v.into_owned()),
+        Line::Media(v) =>         Line::Media(v.into_owned()),
+        Line::SessionName(_)
+        | Line::SessionInfo(_)
+        | Line::Uri(_)
+        | Line::Email(_)
+        | Line::Phone(_)
+        | Line::Unknown(_) =>     Line::Unknown(""),
+    }
+}
@@ -1,11 +1,10 @@
+use super::scanner::Scanner;
 use super::util::short_time;
-use anyhow::{
-    ensure,
-    Result
-};
+use anyhow::Result;
 
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
@@ -45,7 +44,9 @@ use std::{
 /// Monthly and yearly repeats cannot be directly specified with a single
 /// SDP repeat time; instead, separate "t=" fields should be used to
 /// explicitly list the session times.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RepeatTimes {
     pub repeat_interval: f64,
     pub active_duration: f64,
@@ -93,6 +94,10 @@ impl<'a> TryFrom<&'a str> for RepeatTimes {
     /// assert_eq!(instance.active_duration, 3600.0);
     /// assert_eq!(instance.offsets_from_start_time, 1.0);
     ///
+    /// let temp = "7d  1h 0 25h";
+    /// let instance: RepeatTimes = RepeatTimes::try_from(temp).unwrap();
+    /// assert_eq!(instance.repeat_interval, 604800.0);
+    ///
     /// let temp = "86400 3600 0 1";
     /// let instance: RepeatTimes = RepeatTimes::try_from(temp).unwrap();
     /// 
@@ -101,12 +106,23 @@ impl<'a> TryFrom<&'a str> for RepeatTimes {
     /// assert_eq!(instance.offsets_from_start_time, 1.0);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let values = value.split(' ').collect::<Vec<&str>>();
-        ensure!(values.len() == 4, "invalid timing!");
+        let mut scanner = Scanner::new(value, "repeat times");
+        let repeat_interval = short_time(scanner.field()?)?;
+        let active_duration = short_time(scanner.field()?)?;
+        scanner.field()?;
+        let offsets_from_start_time = short_time(scanner.field()?)?;
+        scanner.finish()?;
         Ok(Self {
-            repeat_interval: short_time(values[0])?,
-            active_duration: short_time(values[1])?,
-            offsets_from_start_time: short_time(values[3])?
+            repeat_interval,
+            active_duration,
+            offsets_from_start_time
         })
     }
 }
+
+impl FromStr for RepeatTimes {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
@@ -0,0 +1,170 @@
+//! RTSP DESCRIBE helpers.
+//!
+//! Resolves the control URLs of a session description returned by an
+//! RTSP DESCRIBE request
+//! ([RFC2326](https://datatracker.ietf.org/doc/html/rfc2326#appendix-C.1.1)),
+//! so that every media description can be fed into a SETUP request.
+
+use super::attributes::{
+    Attributes,
+    Attribute,
+    RtpValue
+};
+
+use super::media::Media;
+use super::Sdp;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// RTSP track descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<'b, 'a> {
+    /// the media description of the track.
+    pub media: &'b Media<'a>,
+    /// the resolved control URL, to be used in the SETUP request.
+    pub control: String,
+    /// the first payload type of the media description.
+    pub payload_type: Option<u8>,
+    /// the "a=rtpmap:" value of the payload type, if given.
+    pub codec: Option<&'b RtpValue<'a>>,
+}
+
+fn control<'b>(attributes: &'b [Attribute<'_>]) -> Option<&'b str> {
+    attributes.iter().find_map(|a| match &a.value {
+        Attributes::Control(v) => Some(&**v),
+        _ => None,
+    })
+}
+
+/// split an url into the "scheme://authority" prefix and the path.
+fn split_authority(url: &str) -> (&str, &str) {
+    let start = url.find("://").map(|i| i + 3).unwrap_or(0);
+    match url[start..].find('/') {
+        Some(i) => url.split_at(start + i),
+        None => (url, ""),
+    }
+}
+
+fn is_absolute(url: &str) -> bool {
+    match url.split_once(':') {
+        Some((scheme, _)) => {
+            !scheme.is_empty()
+                && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        },
+        None => false,
+    }
+}
+
+/// resolve a control URL against a base URL.
+///
+/// "*" refers to the base URL itself.  A relative URL is appended to the
+/// base URL, as done by common RTSP clients and servers, instead of
+/// replacing the last path segment as a generic URI resolver would.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::rtsp::*;
+///
+/// let base = "rtsp://example.com/movie/";
+/// assert_eq!(resolve(base, "*"), "rtsp://example.com/movie/");
+/// assert_eq!(resolve(base, "trackID=1"), "rtsp://example.com/movie/trackID=1");
+/// assert_eq!(resolve("rtsp://example.com/movie", "trackID=1"), "rtsp://example.com/movie/trackID=1");
+/// assert_eq!(resolve(base, "/audio"), "rtsp://example.com/audio");
+/// assert_eq!(resolve(base, "rtsp://other.com/audio"), "rtsp://other.com/audio");
+/// ```
+pub fn resolve(base: &str, url: &str) -> String {
+    if url.is_empty() || url == "*" {
+        return String::from(base)
+    }
+
+    if is_absolute(url) {
+        return String::from(url)
+    }
+
+    if url.starts_with('/') {
+        let (authority, _) = split_authority(base);
+        return [authority, url].concat()
+    }
+
+    match base.ends_with('/') {
+        true => [base, url].concat(),
+        false => [base, "/", url].concat(),
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// the aggregate control URL of the session.
+    ///
+    /// `base` is the base URL of the description, i.e. the value of the
+    /// Content-Base header, else the Content-Location header, else the
+    /// request URL of the DESCRIBE request.  An absolute session level
+    /// "a=control:" replaces the base URL.
+    pub fn aggregate_control(&self, base: &str) -> String {
+        match control(&self.attributes) {
+            Some(url) => resolve(base, url),
+            None => String::from(base),
+        }
+    }
+
+    /// the tracks of an RTSP presentation, one per media description.
+    ///
+    /// See [`Sdp::aggregate_control`] for the base URL.  Media
+    /// descriptions without "a=control:" are controlled by the
+    /// aggregate URL.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 0 0 IN IP4 127.0.0.1\r\n\
+    ///     s=movie\r\n\
+    ///     t=0 0\r\n\
+    ///     a=control:*\r\n\
+    ///     a=range:npt=0-34.357\r\n\
+    ///     m=video 0 RTP/AVP 96\r\n\
+    ///     a=rtpmap:96 H264/90000\r\n\
+    ///     a=control:trackID=1\r\n\
+    ///     m=audio 0 RTP/AVP 0\r\n\
+    ///     a=control:rtsp://example.com/movie/trackID=2\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let tracks = sdp.tracks("rtsp://example.com/movie/");
+    ///
+    /// assert_eq!(sdp.aggregate_control("rtsp://example.com/movie/"), "rtsp://example.com/movie/");
+    /// assert_eq!(tracks.len(), 2);
+    /// assert_eq!(tracks[0].control, "rtsp://example.com/movie/trackID=1");
+    /// assert_eq!(tracks[0].payload_type, Some(96));
+    /// assert_eq!(tracks[0].codec.unwrap().codec, "H264");
+    /// assert_eq!(tracks[1].control, "rtsp://example.com/movie/trackID=2");
+    /// assert_eq!(tracks[1].payload_type, Some(0));
+    /// assert!(tracks[1].codec.is_none());
+    /// ```
+    pub fn tracks<'b>(&'b self, base: &str) -> Vec<Track<'b, 'a>> {
+        let aggregate = self.aggregate_control(base);
+        self.medias
+            .iter()
+            .map(|media| {
+                let payload_type = media.fmts.first().copied();
+                let codec = media.attributes.iter().find_map(|a| match &a.value {
+                    Attributes::Rtpmap(v) if Some(v.key) == payload_type => Some(&v.value),
+                    _ => None,
+                });
+
+                Track {
+                    control: match control(&media.attributes) {
+                        Some(url) => resolve(&aggregate, url),
+                        None => aggregate.clone(),
+                    },
+                    payload_type,
+                    codec,
+                    media,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,177 @@
+//! Session Announcement Protocol.
+//!
+//! Parses SAP packets as defined in
+//! [RFC2974](https://datatracker.ietf.org/doc/html/rfc2974#section-3),
+//! which announce multicast sessions (e.g. IPTV channels and AES67
+//! streams) by periodically sending their session description.
+
+use super::Sdp;
+use anyhow::{
+    Result,
+    ensure,
+    anyhow
+};
+
+use core::{
+    convert::TryFrom,
+    net::{
+        IpAddr,
+        Ipv4Addr,
+        Ipv6Addr
+    }
+};
+
+/// SAP message type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageType {
+    /// the session is announced.
+    Announcement,
+    /// the session is deleted, receivers should stop listing it.
+    Deletion,
+}
+
+/// SAP packet.
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// | V=1 |A|R|T|E|C|   auth len    |         msg id hash           |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// :                originating source (32 or 128 bits)            :
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                    optional authentication data               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                      optional payload type                    |
+/// +                                         +-+- - - - - - - - - -+
+/// |                                         |0|                   |
+/// + - - - - - - - - - - - - - - - - - - - - +-+                   |
+/// |                                                               |
+/// :                            payload                            :
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::sap::*;
+/// use std::convert::*;
+///
+/// let mut packet = vec![0x20, 0, 0x12, 0x34, 192, 168, 1, 1];
+/// packet.extend_from_slice(b"application/sdp\0v=0\r\ns=panda\r\n");
+///
+/// let sap = SapPacket::try_from(&packet[..]).unwrap();
+/// assert_eq!(sap.message_type, MessageType::Announcement);
+/// assert_eq!(sap.msg_id_hash, 0x1234);
+/// assert_eq!(sap.source, "192.168.1.1".parse::<std::net::IpAddr>().unwrap());
+/// assert_eq!(sap.payload_type, Some("application/sdp"));
+/// assert_eq!(sap.sdp().unwrap().session_name.as_deref(), Some("panda"));
+///
+/// // the payload type can be omitted.
+/// let mut packet = vec![0x24, 0, 0x12, 0x34, 192, 168, 1, 1];
+/// packet.extend_from_slice(b"v=0\r\ns=panda\r\n");
+///
+/// let sap = SapPacket::try_from(&packet[..]).unwrap();
+/// assert_eq!(sap.message_type, MessageType::Deletion);
+/// assert_eq!(sap.payload_type, None);
+/// assert!(sap.sdp().is_ok());
+///
+/// assert!(SapPacket::try_from(&packet[..6]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SapPacket<'a> {
+    /// the protocol version, always 1.
+    pub version: u8,
+    pub message_type: MessageType,
+    /// the payload is encrypted.
+    pub encrypted: bool,
+    /// the payload is compressed with zlib.
+    pub compressed: bool,
+    /// identifies the announced version of the session, together with
+    /// the originating source.
+    pub msg_id_hash: u16,
+    /// the address of the announcer.
+    pub source: IpAddr,
+    /// the authentication data, empty if not authenticated.
+    pub auth: &'a [u8],
+    /// the MIME type of the payload, missing for old announcers that
+    /// only send session descriptions.
+    pub payload_type: Option<&'a str>,
+    pub payload: &'a [u8],
+}
+
+impl<'a> SapPacket<'a> {
+    /// parse the announced session description.
+    ///
+    /// Fails for encrypted or compressed payloads, and for payload
+    /// types other than "application/sdp".
+    pub fn sdp(&self) -> Result<Sdp<'a>> {
+        ensure!(!self.encrypted, "encrypted sap payload!");
+        ensure!(!self.compressed, "compressed sap payload!");
+        ensure!(
+            matches!(self.payload_type, None | Some("application/sdp")),
+            "invalid sap payload type!"
+        );
+
+        Sdp::try_from(core::str::from_utf8(self.payload)?)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SapPacket<'a> {
+    type Error = anyhow::Error;
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        ensure!(value.len() >= 4, "invalid sap packet!");
+        let flags = value[0];
+        let version = flags >> 5;
+        ensure!(version == 1, "invalid sap version!");
+
+        let ipv6 = flags & 0x10 != 0;
+        let auth_len = value[1] as usize * 4;
+        let msg_id_hash = u16::from_be_bytes([value[2], value[3]]);
+        let (source, rest) = match ipv6 {
+            false => {
+                ensure!(value.len() >= 8, "invalid sap packet!");
+                let addr: [u8; 4] = value[4..8].try_into()?;
+                (IpAddr::V4(Ipv4Addr::from(addr)), &value[8..])
+            },
+            true => {
+                ensure!(value.len() >= 20, "invalid sap packet!");
+                let addr: [u8; 16] = value[4..20].try_into()?;
+                (IpAddr::V6(Ipv6Addr::from(addr)), &value[20..])
+            },
+        };
+
+        ensure!(rest.len() >= auth_len, "invalid sap packet!");
+        let (auth, rest) = rest.split_at(auth_len);
+
+        // the payload type is optional, a payload starting with "v=0" is
+        // a session description without payload type.
+        let encrypted = flags & 0x02 != 0;
+        let compressed = flags & 0x01 != 0;
+        let (payload_type, payload) = match encrypted || compressed || rest.starts_with(b"v=0") {
+            true => (None, rest),
+            false => {
+                let end = rest
+                    .iter()
+                    .position(|b| *b == 0)
+                    .ok_or_else(|| anyhow!("invalid sap payload type!"))?;
+                (Some(core::str::from_utf8(&rest[..end])?), &rest[end + 1..])
+            },
+        };
+
+        Ok(Self {
+            version,
+            message_type: match flags & 0x04 != 0 {
+                false => MessageType::Announcement,
+                true => MessageType::Deletion,
+            },
+            encrypted,
+            compressed,
+            msg_id_hash,
+            source,
+            auth,
+            payload_type,
+            payload,
+        })
+    }
+}
@@ -0,0 +1,175 @@
+//! Field scanner for line and attribute values.
+//!
+//! Most values are fields separated by SP
+//! [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-9),
+//! where the last field of some values may contain SP itself, e.g. the
+//! byte-string of "a=label:".  [`Scanner`] reads such values field by
+//! field from either end, accepts runs of SP between fields, and names
+//! the value and the field in its errors.
+
+use anyhow::{
+    Result,
+    anyhow
+};
+
+/// whether the value is a token of
+/// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-9).
+///
+/// token-char = ALPHA / DIGIT / "!" / "#" / "$" / "%" / "&" / "'" / "*"
+///     / "+" / "-" / "." / "^" / "_" / "`" / "{" / "|" / "}" / "~"
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::scanner::*;
+///
+/// assert!(is_token("SAVPF"));
+/// assert!(is_token("x-google-flag"));
+/// assert!(!is_token("a b"));
+/// assert!(!is_token(""));
+/// ```
+pub fn is_token(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|c| {
+        c.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`{|}~".contains(&c)
+    })
+}
+
+/// Scanner over the fields of a value.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::scanner::*;
+///
+/// let mut scanner = Scanner::new("a  b c:d e", "example");
+/// assert_eq!(scanner.field().unwrap(), "a");
+/// assert_eq!(scanner.field_back().unwrap(), "e");
+/// assert_eq!(scanner.field().unwrap(), "b");
+/// assert_eq!(scanner.until(':').unwrap(), "c");
+/// assert_eq!(scanner.rest().unwrap(), "d");
+/// assert!(scanner.finish().is_ok());
+///
+/// let mut scanner = Scanner::new(" a  b ", "example");
+/// assert_eq!(scanner.fields().collect::<Vec<_>>(), vec!["a", "b"]);
+/// assert!(scanner.finish().is_ok());
+///
+/// let mut scanner = Scanner::new("a", "example");
+/// scanner.field().unwrap();
+/// assert_eq!(scanner.field().unwrap_err().to_string(), "invalid example: missing field 2!");
+///
+/// let mut scanner = Scanner::new("a b", "example");
+/// scanner.field().unwrap();
+/// assert_eq!(scanner.finish().unwrap_err().to_string(), "invalid example: unexpected \"b\"!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    rest: &'a str,
+    what: &'static str,
+    field: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// scan the value, `what` names the value in errors, e.g.
+    /// "origin".
+    pub fn new(value: &'a str, what: &'static str) -> Self {
+        Self {
+            rest: value,
+            what,
+            field: 0,
+        }
+    }
+
+    /// whether every field has been read.
+    pub fn is_empty(&self) -> bool {
+        self.rest.trim_matches(' ').is_empty()
+    }
+
+    /// the next field.
+    pub fn field(&mut self) -> Result<&'a str> {
+        self.field += 1;
+        let rest = self.rest.trim_start_matches(' ');
+        let (field, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        if field.is_empty() {
+            return Err(self.missing());
+        }
+
+        self.rest = rest;
+        Ok(field)
+    }
+
+    /// the last field, so that the fields before it may contain SP.
+    pub fn field_back(&mut self) -> Result<&'a str> {
+        self.field += 1;
+        let rest = self.rest.trim_end_matches(' ');
+        let (rest, field) = rest.rsplit_once(' ').unwrap_or(("", rest));
+        if field.is_empty() {
+            return Err(self.missing());
+        }
+
+        self.rest = rest;
+        Ok(field)
+    }
+
+    /// the remaining fields.
+    pub fn fields(&mut self) -> impl Iterator<Item = &'a str> + '_ {
+        core::iter::from_fn(move || match self.is_empty() {
+            false => self.field().ok(),
+            true => None,
+        })
+    }
+
+    /// the next field, which must be a token.
+    pub fn token(&mut self) -> Result<&'a str> {
+        let field = self.field()?;
+        if !is_token(field) {
+            return Err(anyhow!("invalid {}: field {} is not a token!", self.what, self.field));
+        }
+
+        Ok(field)
+    }
+
+    /// the text up to the delimiter, the delimiter is skipped.
+    pub fn until(&mut self, delimiter: char) -> Result<&'a str> {
+        self.field += 1;
+        let (field, rest) = self.rest
+            .trim_start_matches(' ')
+            .split_once(delimiter)
+            .ok_or_else(|| anyhow!("invalid {}: missing {:?} after field {}!", self.what, delimiter, self.field))?;
+        if field.is_empty() {
+            return Err(self.missing());
+        }
+
+        self.rest = rest;
+        Ok(field)
+    }
+
+    /// the remaining fields as a byte-string, which may contain SP but
+    /// not NUL, CR or LF.
+    pub fn rest(&mut self) -> Result<&'a str> {
+        self.field += 1;
+        let rest = self.rest.trim_matches(' ');
+        if rest.is_empty() {
+            return Err(self.missing());
+        }
+
+        if rest.contains(['\0', '\r', '\n']) {
+            return Err(anyhow!("invalid {}: field {} is not a byte-string!", self.what, self.field));
+        }
+
+        self.rest = "";
+        Ok(rest)
+    }
+
+    /// fail if fields are left.
+    pub fn finish(&self) -> Result<()> {
+        if !self.is_empty() {
+            return Err(anyhow!("invalid {}: unexpected {:?}!", self.what, self.rest.trim_matches(' ')));
+        }
+
+        Ok(())
+    }
+
+    fn missing(&self) -> anyhow::Error {
+        anyhow!("invalid {}: missing field {}!", self.what, self.field)
+    }
+}
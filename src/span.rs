@@ -0,0 +1,137 @@
+//! Input positions.
+//!
+//! Parsed attributes remember the line they were parsed from, see
+//! [`crate::attributes::Attribute::span`], the other lines are kept by
+//! line type in [`crate::Sdp::spans`], see [`Spans`], and
+//! [`crate::lines::Lines::spanned`] gives the position of every line,
+//! so that errors and highlighting can point at the input.  Errors of
+//! [`crate::Sdp::parse`] carry the span of the failing line as context.
+
+use super::Key;
+use smallvec::SmallVec;
+use core::{
+    ops::Range,
+    fmt
+};
+
+/// the position of a line in the input.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// the line number, starting at 1.
+    pub line: usize,
+    /// the byte offset of the line.
+    pub start: usize,
+    /// the byte offset of the end of the line, without the line break.
+    pub end: usize,
+}
+
+impl Span {
+    /// the byte range of the line in the input.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}", self.line)
+    }
+}
+
+/// the positions of the lines of a session description, by line type.
+///
+/// Filled by [`crate::Sdp::parse`] for every line except the "a=" lines,
+/// which keep their own [`crate::attributes::Attribute::span`], and the
+/// lines of unknown type.  Empty for descriptions that are built rather
+/// than parsed, and not updated by edits.  It is not compared by `==`.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     o=- 1 2 IN IP4 127.0.0.1\r\n\
+///     s=-\r\n\
+///     b=AS:30\r\n\
+///     b=TIAS:30000\r\n\
+///     t=0 0\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     c=IN IP4 127.0.0.1\r\n\
+///     a=ptime:20\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let session = sdp.spans.session();
+/// let origin = session.get(Key::Origin).unwrap();
+/// assert_eq!(origin.line, 2);
+/// assert_eq!(&temp[origin.range()], "o=- 1 2 IN IP4 127.0.0.1");
+///
+/// let bandwidth = session.all(Key::Bandwidth).map(|v| &temp[v.range()]).collect::<Vec<_>>();
+/// assert_eq!(bandwidth, vec!["b=AS:30", "b=TIAS:30000"]);
+/// assert_eq!(session.get(Key::Connection), None);
+///
+/// let media = sdp.spans.media(0).unwrap();
+/// assert_eq!(media.get(Key::Media), sdp.medias[0].span());
+/// assert_eq!(&temp[media.get(Key::Connection).unwrap().range()], "c=IN IP4 127.0.0.1");
+/// assert!(sdp.spans.media(1).is_none());
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Default)]
+pub struct Spans(SmallVec<[(Key, Span); 16]>);
+
+/// the positions of the lines of the session or of one media
+/// description, see [`Spans`].
+#[derive(Debug, Clone, Copy)]
+pub struct LevelSpans<'s>(&'s [(Key, Span)]);
+
+impl Spans {
+    /// the positions of the session level lines.
+    pub fn session(&self) -> LevelSpans<'_> {
+        let end = self.0.iter().position(|(k, _)| *k == Key::Media).unwrap_or(self.0.len());
+        LevelSpans(&self.0[..end])
+    }
+
+    /// the positions of the lines of the media description with the
+    /// given index, its "m=" line first.
+    pub fn media(&self, index: usize) -> Option<LevelSpans<'_>> {
+        let start = self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, (k, _))| *k == Key::Media)
+            .nth(index)?
+            .0;
+
+        let end = self.0[start + 1..]
+            .iter()
+            .position(|(k, _)| *k == Key::Media)
+            .map_or(self.0.len(), |v| start + 1 + v);
+
+        Some(LevelSpans(&self.0[start..end]))
+    }
+
+    pub(crate) fn push(&mut self, key: Key, span: Span) {
+        self.0.push((key, span));
+    }
+}
+
+impl<'s> LevelSpans<'s> {
+    /// the position of the first line of the given type.
+    pub fn get(&self, key: Key) -> Option<Span> {
+        self.all(key).next()
+    }
+
+    /// the positions of all lines of the given type, in input order.
+    pub fn all(&self, key: Key) -> impl Iterator<Item = Span> + 's {
+        self.0.iter().filter(move |(k, _)| *k == key).map(|(_, v)| *v)
+    }
+}
+
+impl PartialEq for Spans {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,162 @@
+//! Session statistics.
+//!
+//! [`Sdp::summary`] counts the media descriptions and collects a few
+//! quick facts about a description, for logs and metrics of media
+//! servers.
+
+use super::attributes::{
+    Attributes,
+    Semantics
+};
+
+use super::media::Encoding;
+use super::Sdp;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// counts and quick facts of a session description.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Summary<'s> {
+    pub audio: usize,
+    pub video: usize,
+    pub text: usize,
+    pub application: usize,
+    pub message: usize,
+    /// media descriptions rejected with port 0.
+    pub rejected: usize,
+    /// the codec names of all "a=rtpmap:" attributes, without
+    /// duplicates and in order of appearance.
+    pub codecs: Vec<&'s str>,
+    /// there is an "a=group:BUNDLE" group.
+    pub bundle: bool,
+    /// a media description has "a=rtcp-mux".
+    pub rtcp_mux: bool,
+    /// a media description has "a=simulcast:".
+    pub simulcast: bool,
+    /// the number of "a=candidate:" attributes.
+    pub candidates: usize,
+}
+
+impl<'a> Sdp<'a> {
+    /// counts and quick facts of the description.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     a=group:BUNDLE 0 1\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111 0\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtcp-mux\r\n\
+    ///     a=candidate:1 1 udp 2122260223 192.0.2.1 54400 typ host\r\n\
+    ///     a=candidate:2 1 udp 1686052607 198.51.100.1 54400 typ srflx raddr 192.0.2.1 rport 54400\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n\
+    ///     a=rtpmap:0 PCMU/8000\r\n\
+    ///     m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=rtpmap:96 VP8/90000\r\n\
+    ///     a=rtpmap:97 vp8/90000\r\n\
+    ///     a=simulcast:send h;l\r\n\
+    ///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let summary = sdp.summary();
+    /// assert_eq!(summary.audio, 1);
+    /// assert_eq!(summary.video, 2);
+    /// assert_eq!(summary.rejected, 1);
+    /// assert_eq!(summary.codecs, ["opus", "PCMU", "VP8"]);
+    /// assert!(summary.bundle && summary.rtcp_mux && summary.simulcast);
+    /// assert_eq!(summary.candidates, 2);
+    ///
+    /// assert_eq!(
+    ///     summary.to_string(),
+    ///     "3 media (1 audio, 2 video, 1 rejected), codecs opus PCMU VP8, bundle, rtcp-mux, simulcast, 2 candidates"
+    /// );
+    /// ```
+    pub fn summary(&self) -> Summary<'_> {
+        let mut summary = Summary::default();
+        for attribute in &self.attributes {
+            if let Attributes::Group(v) = &attribute.value {
+                summary.bundle |= v.semantics == Semantics::Bundle;
+            }
+        }
+
+        for media in &self.medias {
+            match media.encoding {
+                Encoding::Audio => summary.audio += 1,
+                Encoding::Video => summary.video += 1,
+                Encoding::Text => summary.text += 1,
+                Encoding::Application => summary.application += 1,
+                Encoding::Message => summary.message += 1,
+            }
+
+            if media.is_rejected() {
+                summary.rejected += 1;
+            }
+
+            for attribute in &media.attributes {
+                match &attribute.value {
+                    Attributes::Rtpmap(v) => {
+                        let codec = v.value.codec.as_ref();
+                        if !summary.codecs.iter().any(|c| c.eq_ignore_ascii_case(codec)) {
+                            summary.codecs.push(codec);
+                        }
+                    }
+                    Attributes::Other(k, None) if k == "rtcp-mux" => summary.rtcp_mux = true,
+                    Attributes::Other(k, Some(_)) if k == "simulcast" => summary.simulcast = true,
+                    Attributes::Other(k, Some(_)) if k == "candidate" => summary.candidates += 1,
+                    _ => (),
+                }
+            }
+        }
+
+        summary
+    }
+}
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.audio + self.video + self.text + self.application + self.message;
+        write!(f, "{} media (", total)?;
+
+        let mut first = true;
+        for (count, kind) in [
+            (self.audio, "audio"),
+            (self.video, "video"),
+            (self.text, "text"),
+            (self.application, "application"),
+            (self.message, "message"),
+            (self.rejected, "rejected"),
+        ] {
+            if count > 0 {
+                write!(f, "{}{} {}", if first { "" } else { ", " }, count, kind)?;
+                first = false;
+            }
+        }
+
+        write!(f, ")")?;
+        if !self.codecs.is_empty() {
+            write!(f, ", codecs")?;
+            for codec in &self.codecs {
+                write!(f, " {}", codec)?;
+            }
+        }
+
+        for (present, name) in [
+            (self.bundle, "bundle"),
+            (self.rtcp_mux, "rtcp-mux"),
+            (self.simulcast, "simulcast"),
+        ] {
+            if present {
+                write!(f, ", {}", name)?;
+            }
+        }
+
+        write!(f, ", {} candidates", self.candidates)
+    }
+}
@@ -1,12 +1,16 @@
+use alloc::vec::Vec;
+use super::scanner::Scanner;
 use super::util::short_time;
-use itertools::Itertools;
-use std::{
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
 /// time zone.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZone {
     pub adjustment_time: u64,
     pub offset: f64
@@ -47,7 +51,9 @@ pub struct TimeZone {
 /// transmit several years' worth of adjustments in one session
 /// announcement.
 #[rustfmt::skip]
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimeZones(
     pub Vec<TimeZone>
 );
@@ -149,13 +155,25 @@ impl<'a> TryFrom<&'a str> for TimeZones {
     /// assert_eq!(instance.get_values()[0].offset, 100.0);
     /// assert_eq!(instance.get_values()[1].adjustment_time, 2898848070);
     /// assert_eq!(instance.get_values()[1].offset, 0.0);
+    ///
+    /// assert_eq!(TimeZones::try_from("2882844526  100").unwrap().get_values().len(), 1);
+    /// assert!(TimeZones::try_from("2882844526 100 2898848070").is_err());
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut scanner = Scanner::new(value, "time zones");
         let mut values = Vec::with_capacity(5);
-        for (a, b) in value.split(' ').tuples() {
-            values.push(TimeZone::try_from((a, b))?);
+        while !scanner.is_empty() {
+            let adjustment_time = scanner.field()?;
+            values.push(TimeZone::try_from((adjustment_time, scanner.field()?))?);
         }
 
         Ok(Self(values))
     }
 }
+
+impl FromStr for TimeZones {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
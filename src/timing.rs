@@ -1,9 +1,21 @@
-use super::util::tuple2_from_split;
-use std::{
+use super::scanner::Scanner;
+use super::Sdp;
+use core::{
     convert::TryFrom,
+    str::FromStr,
     fmt
 };
 
+#[cfg(feature = "std")]
+use std::time::{
+    Duration,
+    SystemTime,
+    UNIX_EPOCH
+};
+
+/// seconds between the NTP epoch (1900) and the UNIX epoch (1970).
+pub const NTP_UNIX_OFFSET: u64 = 2208988800;
+
 /// Timing ("t=")
 /// 
 /// t=<start-time> <stop-time>
@@ -22,7 +34,6 @@ use std::{
 /// representation of Network Time Protocol (NTP) time values in seconds
 /// since 1900.  To convert these values to UNIX time, subtract
 /// decimal 2208988800.
-
 /// NTP timestamps are elsewhere represented by 64-bit values, which wrap
 /// sometime in the year 2036.  Since SDP uses an arbitrary length
 /// decimal representation, this should not cause an issue (SDP
@@ -32,12 +43,252 @@ use std::{
 /// If the <stop-time> is set to zero, then the session is not bounded,
 /// though it will not become active until after the <start-time>.  If
 /// the <start-time> is also zero, the session is regarded as permanent.
-#[derive(Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Timing {
     pub start: u64,
     pub stop: u64
 }
 
+impl Timing {
+    /// the session has no stop time.
+    pub fn is_unbounded(&self) -> bool {
+        self.stop == 0
+    }
+
+    /// the session has neither a start nor a stop time.
+    pub fn is_permanent(&self) -> bool {
+        self.start == 0 && self.stop == 0
+    }
+
+    /// whether the NTP time lies between the start and the stop time,
+    /// repeat times are not taken into account, see
+    /// [`crate::Sdp::is_active_at`].
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::timing::*;
+    ///
+    /// let timing = Timing { start: 3034423619, stop: 3042462419 };
+    /// assert!(timing.is_active_at_ntp(3034423619));
+    /// assert!(!timing.is_active_at_ntp(3042462420));
+    /// assert!(!timing.is_unbounded());
+    ///
+    /// let timing = Timing { start: 3034423619, stop: 0 };
+    /// assert!(timing.is_unbounded());
+    /// assert!(!timing.is_active_at_ntp(3034423618));
+    /// assert!(timing.is_active_at_ntp(u64::MAX));
+    ///
+    /// assert!(Timing { start: 0, stop: 0 }.is_permanent());
+    /// ```
+    pub fn is_active_at_ntp(&self, ntp: u64) -> bool {
+        ntp >= self.start && (self.is_unbounded() || ntp <= self.stop)
+    }
+
+    /// whether the time lies between the start and the stop time.
+    #[cfg(feature = "std")]
+    pub fn is_active_at(&self, time: SystemTime) -> bool {
+        self.is_active_at_ntp(ntp_from_system_time(time))
+    }
+
+    /// the start time, `None` for a permanent session.
+    #[cfg(feature = "std")]
+    pub fn start_time(&self) -> Option<SystemTime> {
+        (self.start != 0).then(|| ntp_to_system_time(self.start))
+    }
+
+    /// the stop time, `None` for an unbounded session.
+    #[cfg(feature = "std")]
+    pub fn stop_time(&self) -> Option<SystemTime> {
+        (self.stop != 0).then(|| ntp_to_system_time(self.stop))
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// whether the session is active at the NTP time.
+    ///
+    /// The time must lie between the start and the stop time of the
+    /// "t=" line, and with an "r=" line, within the active duration of
+    /// one of the repeats.  The repeats are shifted by the offset of the
+    /// last "z=" adjustment before the time.  A description without a
+    /// "t=" line is regarded as permanent.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// // one hour at 10am on Monday and 11am on Tuesday, every week.
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=3034423619 3042462419\r\n\
+    ///     r=7d 1h 0 25h\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let start = 3034423619;
+    /// assert!(sdp.is_active_at_ntp(start));
+    /// assert!(sdp.is_active_at_ntp(start + 3599));
+    /// assert!(!sdp.is_active_at_ntp(start + 3600));
+    /// assert!(sdp.is_active_at_ntp(start + 25 * 3600));
+    /// assert!(sdp.is_active_at_ntp(start + 7 * 86400 + 60));
+    /// assert!(!sdp.is_active_at_ntp(start + 2 * 86400));
+    /// assert!(!sdp.is_active_at_ntp(3042462420));
+    ///
+    /// // the time base is shifted back by one hour after the adjustment.
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=3034423619 0\r\n\
+    ///     r=7d 1h 0 25h\r\n\
+    ///     z=3035000000 -1h\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// assert!(sdp.is_active_at_ntp(start + 7 * 86400 - 1800));
+    /// assert!(!sdp.is_active_at_ntp(start + 7 * 86400 + 1800));
+    ///
+    /// let sdp = Sdp::try_from("v=0\r\ns=-\r\nt=0 0\r\n").unwrap();
+    /// assert!(sdp.is_active_at_ntp(0));
+    /// ```
+    pub fn is_active_at_ntp(&self, ntp: u64) -> bool {
+        let timing = match &self.timing {
+            Some(v) => v,
+            None => return true,
+        };
+
+        if !timing.is_active_at_ntp(ntp) {
+            return false
+        }
+
+        let repeat = match &self.repeat_times {
+            Some(v) => v,
+            None => return true,
+        };
+
+        let adjustment = self.time_zones
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .filter(|v| v.adjustment_time <= ntp)
+            .max_by_key(|v| v.adjustment_time)
+            .map(|v| v.offset)
+            .unwrap_or(0.0);
+
+        // the first offset is always zero in this model.
+        [0.0, repeat.offsets_from_start_time].iter().any(|offset| {
+            let elapsed = (ntp - timing.start) as f64 - offset - adjustment;
+            if elapsed < 0.0 {
+                return false
+            }
+
+            match repeat.repeat_interval > 0.0 {
+                true => elapsed % repeat.repeat_interval < repeat.active_duration,
+                false => elapsed < repeat.active_duration,
+            }
+        })
+    }
+
+    /// whether the session is active at the time, see
+    /// [`Sdp::is_active_at_ntp`].
+    #[cfg(feature = "std")]
+    pub fn is_active_at(&self, time: SystemTime) -> bool {
+        self.is_active_at_ntp(ntp_from_system_time(time))
+    }
+}
+
+/// convert an NTP timestamp in seconds to a system time.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::timing::*;
+/// use std::time::*;
+///
+/// assert_eq!(ntp_to_system_time(NTP_UNIX_OFFSET), UNIX_EPOCH);
+/// assert_eq!(ntp_to_system_time(3034423619), UNIX_EPOCH + Duration::from_secs(825434819));
+/// assert_eq!(ntp_to_system_time(0), UNIX_EPOCH - Duration::from_secs(NTP_UNIX_OFFSET));
+/// ```
+#[cfg(feature = "std")]
+pub fn ntp_to_system_time(ntp: u64) -> SystemTime {
+    match ntp.checked_sub(NTP_UNIX_OFFSET) {
+        Some(v) => UNIX_EPOCH + Duration::from_secs(v),
+        None => UNIX_EPOCH - Duration::from_secs(NTP_UNIX_OFFSET - ntp),
+    }
+}
+
+/// convert a system time to an NTP timestamp in seconds, sub-second
+/// precision is truncated and times before 1900 saturate to zero.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::timing::*;
+/// use std::time::*;
+///
+/// assert_eq!(ntp_from_system_time(UNIX_EPOCH), NTP_UNIX_OFFSET);
+/// assert_eq!(ntp_from_system_time(ntp_to_system_time(3034423619)), 3034423619);
+/// assert_eq!(ntp_from_system_time(UNIX_EPOCH - Duration::from_millis(1500)), NTP_UNIX_OFFSET - 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn ntp_from_system_time(time: SystemTime) -> u64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(v) => NTP_UNIX_OFFSET + v.as_secs(),
+        Err(e) => {
+            let v = e.duration();
+            let secs = v.as_secs() + u64::from(v.subsec_nanos() > 0);
+            NTP_UNIX_OFFSET.saturating_sub(secs)
+        }
+    }
+}
+
+/// convert an NTP timestamp in seconds to a chrono date time.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::timing::*;
+///
+/// let time = ntp_to_chrono(3034423619).unwrap();
+/// assert_eq!(time.timestamp(), 825434819);
+/// assert_eq!(ntp_from_chrono(&time), Some(3034423619));
+/// ```
+#[cfg(feature = "chrono")]
+pub fn ntp_to_chrono(ntp: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+    let secs = i64::try_from(ntp).ok()?.checked_sub(NTP_UNIX_OFFSET as i64)?;
+    chrono::DateTime::from_timestamp(secs, 0)
+}
+
+/// convert a chrono date time to an NTP timestamp in seconds, `None`
+/// before 1900.
+#[cfg(feature = "chrono")]
+pub fn ntp_from_chrono<Tz: chrono::TimeZone>(time: &chrono::DateTime<Tz>) -> Option<u64> {
+    u64::try_from(time.timestamp().checked_add(NTP_UNIX_OFFSET as i64)?).ok()
+}
+
+/// convert an NTP timestamp in seconds to a time date time.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::timing::*;
+///
+/// let time = ntp_to_offset_date_time(3034423619).unwrap();
+/// assert_eq!(time.unix_timestamp(), 825434819);
+/// assert_eq!(ntp_from_offset_date_time(time), Some(3034423619));
+/// ```
+#[cfg(feature = "time")]
+pub fn ntp_to_offset_date_time(ntp: u64) -> Option<time::OffsetDateTime> {
+    let secs = i64::try_from(ntp).ok()?.checked_sub(NTP_UNIX_OFFSET as i64)?;
+    time::OffsetDateTime::from_unix_timestamp(secs).ok()
+}
+
+/// convert a time date time to an NTP timestamp in seconds, `None`
+/// before 1900.
+#[cfg(feature = "time")]
+pub fn ntp_from_offset_date_time(time: time::OffsetDateTime) -> Option<u64> {
+    u64::try_from(time.unix_timestamp().checked_add(NTP_UNIX_OFFSET as i64)?).ok()
+}
+
 impl fmt::Display for Timing {
     /// # Unit Test
     ///
@@ -72,10 +323,20 @@ impl<'a> TryFrom<&'a str> for Timing {
     /// assert_eq!(instance.stop, 0);
     /// ```
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (sa, st) = tuple2_from_split(value, ' ', "invalid timing!")?;
+        let mut scanner = Scanner::new(value, "timing");
+        let sa = scanner.field()?;
+        let st = scanner.field()?;
+        scanner.finish()?;
         Ok(Self {
             start: sa.parse::<u64>()?,
             stop: st.parse::<u64>()?
         })
     }
 }
+
+impl FromStr for Timing {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
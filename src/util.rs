@@ -1,3 +1,8 @@
+use super::options::{
+    ParseOptions,
+    Limits,
+    Limit
+};
 use anyhow::{
     Result,
     anyhow
@@ -15,16 +20,53 @@ use anyhow::{
 /// assert_eq!(short_time("1m").unwrap(), 60.0);
 /// assert_eq!(short_time("1s").unwrap(), 1.0);
 /// assert_eq!(short_time("100").unwrap(), 100.0);
+/// assert!(short_time("").is_err());
+/// assert!(short_time("1é").is_err());
 /// ```
 pub fn short_time(time: &str) -> Result<f64> {
-    let (value, last) = time.split_at(time.len() - 1);
-    Ok(match last {
-        "d" => value.parse::<f64>()? * 86400.0,
-        "h" => value.parse::<f64>()? * 3600.0,
-        "m" => value.parse::<f64>()? * 60.0,
-        "s" => value.parse::<f64>()?,
-        _ => time.parse::<f64>()?
-    })
+    let scale = match time.as_bytes().last() {
+        Some(b'd') => 86400.0,
+        Some(b'h') => 3600.0,
+        Some(b'm') => 60.0,
+        Some(b's') => 1.0,
+        _ => return Ok(time.parse::<f64>()?)
+    };
+
+    // the unit is a single ascii byte, so this is always a char boundary.
+    Ok(time[..time.len() - 1].parse::<f64>()? * scale)
+}
+
+/// unsigned decimal number, digits with an optional fraction.
+///
+/// Unlike `f64` parsing, signs, exponents, "inf" and "NaN" are
+/// rejected.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::util::*;
+///
+/// assert_eq!(decimal("12.5").unwrap(), 12.5);
+/// assert_eq!(decimal("50").unwrap(), 50.0);
+/// assert!(decimal("").is_err());
+/// assert!(decimal(".5").is_err());
+/// assert!(decimal("5.").is_err());
+/// assert!(decimal("-1").is_err());
+/// assert!(decimal("1e3").is_err());
+/// assert!(decimal("NaN").is_err());
+/// ```
+pub fn decimal(value: &str) -> Result<f64> {
+    let digits = |v: &str| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit());
+    let valid = match value.split_once('.') {
+        Some((integer, fraction)) => digits(integer) && digits(fraction),
+        None => digits(value),
+    };
+
+    if !valid {
+        return Err(anyhow!("invalid decimal!"));
+    }
+
+    Ok(value.parse()?)
 }
 
 /// placeholder char.
@@ -45,51 +87,108 @@ pub fn placeholder(source: &str) -> Option<&str> {
     }
 }
 
-/// # Unit Test
-///
-/// ```
-/// use sdp::util::*;
+/// Line iterator over a session description.
 ///
-/// assert_eq!(tuple2_from_split("1/2", '/', "").unwrap(), ("1", "2"));
-/// assert_eq!(tuple2_from_split("1:2", ':', "").unwrap(), ("1", "2"));
-/// assert!(tuple2_from_split("1 2", ':', "").is_err());
-/// ```
-pub fn tuple2_from_split<'a>(
-    value: &'a str, 
-    pat: char,
-    msg: &'static str
-) -> Result<(&'a str, &'a str)> {
-    let mut split = value.split(pat);
-    let v1 = split.next().ok_or_else(|| anyhow!(msg))?;
-    let v2 = split.next().ok_or_else(|| anyhow!(msg))?;
-    if split.next().is_some() {
-        return Err(anyhow!(msg))
+/// Created by [`split_lines`].
+#[derive(Debug, Clone)]
+pub struct SplitLines<'a, 'o> {
+    source: &'a str,
+    /// the input from the start of the line being split, so that the
+    /// position of a failing line is known.
+    pub(crate) current: &'a str,
+    strict: bool,
+    trim: bool,
+    limits: &'o Limits,
+}
+
+impl<'a> Iterator for SplitLines<'a, '_> {
+    type Item = Result<&'a str>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.source.is_empty() {
+                return None;
+            }
+
+            self.current = self.source;
+
+            let end = self.source.find('\n');
+            let line = &self.source[..end.unwrap_or(self.source.len())];
+            if let Err(e) = self.limits.check(Limit::LineLength, line.trim_end_matches('\r').len()) {
+                self.source = "";
+                return Some(Err(e.into()))
+            }
+
+            let terminated = end.is_some();
+            self.source = match end {
+                Some(i) => &self.source[i + 1..],
+                None => "",
+            };
+
+            if self.strict {
+                let line = match line.strip_suffix('\r') {
+                    Some(l) if terminated => l,
+                    _ => {
+                        self.source = "";
+                        return Some(Err(anyhow!("invalid line ending!")))
+                    }
+                };
+
+                if line.is_empty() {
+                    self.source = "";
+                    return Some(Err(anyhow!("invalid empty line!")))
+                }
+
+                return Some(Ok(line));
+            }
+
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let line = match self.trim {
+                true => line.trim(),
+                false => line
+            };
+
+            if !line.is_empty() {
+                return Some(Ok(line));
+            }
+        }
     }
-    
-    Ok((v1, v2))
 }
 
+/// split a session description into lines.
+///
+/// In lenient mode `\r\n`, bare `\n` and a missing line ending after the
+/// last line are all accepted, and empty lines are skipped.  In strict
+/// mode every line must be terminated by `\r\n`.
+///
 /// # Unit Test
 ///
 /// ```
+/// use sdp::options::*;
 /// use sdp::util::*;
 ///
-/// assert_eq!(tuple3_from_split("1/2/3", '/', "").unwrap(), ("1", "2", "3"));
-/// assert_eq!(tuple3_from_split("1:2:3", ':', "").unwrap(), ("1", "2", "3"));
-/// assert!(tuple3_from_split("1 2 3", ':', "").is_err());
+/// let options = ParseOptions::default();
+/// let lines = split_lines("v=0\r\no=-\ns=-", &options)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(lines, vec!["v=0", "o=-", "s=-"]);
+///
+/// let options = ParseOptions { trim: true, ..ParseOptions::default() };
+/// let lines = split_lines("  v=0 \r\n\r\ns=-\n", &options)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(lines, vec!["v=0", "s=-"]);
+///
+/// let options = ParseOptions::strict();
+/// assert!(split_lines("v=0\r\ns=-\r\n", &options).all(|l| l.is_ok()));
+/// assert!(split_lines("v=0\ns=-\r\n", &options).any(|l| l.is_err()));
+/// assert!(split_lines("v=0\r\ns=-", &options).any(|l| l.is_err()));
 /// ```
-pub fn tuple3_from_split<'a>(
-    value: &'a str, 
-    pat: char, 
-    msg: &'static str
-) -> Result<(&'a str, &'a str, &'a str)> {
-    let mut split = value.split(pat);
-    let v1 = split.next().ok_or_else(|| anyhow!(msg))?;
-    let v2 = split.next().ok_or_else(|| anyhow!(msg))?;
-    let v3 = split.next().ok_or_else(|| anyhow!(msg))?;
-    if split.next().is_some() {
-        return Err(anyhow!(msg))
+pub fn split_lines<'a, 'o>(source: &'a str, options: &'o ParseOptions) -> SplitLines<'a, 'o> {
+    SplitLines {
+        source,
+        current: source,
+        strict: options.strict,
+        trim: options.trim,
+        limits: &options.limits,
     }
-    
-    Ok((v1, v2, v3))
 }
@@ -0,0 +1,61 @@
+use super::{
+    Profile,
+    Report,
+    has
+};
+
+use crate::attributes::Attributes;
+use crate::Sdp;
+
+/// BUNDLE and RTP/RTCP multiplexing profile.
+///
+/// Checks the media restricted to BUNDLE
+/// [RFC9143](https://datatracker.ietf.org/doc/html/rfc9143) or to
+/// multiplexed RTCP
+/// [RFC8858](https://datatracker.ietf.org/doc/html/rfc8858):
+///
+/// * "a=bundle-only" media must use port 0.
+/// * "a=rtcp-mux-only" media must also have "a=rtcp-mux".
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+///     a=rtcp-mux\r\n\
+///     a=rtcp-mux-only\r\n\
+///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n\
+///     a=bundle-only\r\n\
+///     a=rtcp-mux-only\r\n\
+///     m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+///     a=bundle-only\r\n\
+///     a=rtcp-mux\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Bundle);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![("rtcp-mux-only", Some(1)), ("bundle-only-port", Some(2))]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bundle;
+
+impl Profile for Bundle {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            if has(&media.attributes, |a| matches!(a, Attributes::BundleOnly)) && media.port.num != 0 {
+                report.error("bundle-only-port", index, "a=bundle-only media must use port 0");
+            }
+
+            let mux = has(&media.attributes, |a| matches!(a, Attributes::Other(k, None) if k == "rtcp-mux"));
+            if has(&media.attributes, |a| matches!(a, Attributes::RtcpMuxOnly)) && !mux {
+                report.error("rtcp-mux-only", index, "a=rtcp-mux-only without a=rtcp-mux");
+            }
+        }
+    }
+}
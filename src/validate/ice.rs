@@ -0,0 +1,169 @@
+use super::{
+    Profile,
+    Report,
+    find,
+    has
+};
+
+use crate::attributes::Attributes;
+use crate::ice::candidate;
+use crate::Sdp;
+use alloc::format;
+
+/// the value of an unknown attribute with the given name.
+fn other<'b>(name: &'static str) -> impl Fn(&'b Attributes<'_>) -> Option<&'b str> {
+    move |a| match a {
+        Attributes::Other(k, Some(v)) if k == name => Some(v.as_ref()),
+        _ => None,
+    }
+}
+
+fn flag(name: &'static str) -> impl Fn(&Attributes<'_>) -> bool {
+    move |a| matches!(a, Attributes::Other(k, _) if k == name)
+}
+
+/// whether a credential is made of ice-char and has a length in the
+/// given range.
+fn is_credential(value: &str, min: usize) -> bool {
+    (min..=256).contains(&value.len())
+        && value.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'/')
+}
+
+/// ICE profile.
+///
+/// Checks the ICE attributes of
+/// [RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5) and
+/// [RFC8840](https://datatracker.ietf.org/doc/html/rfc8840#section-8.2)
+/// for every media that is not rejected:
+///
+/// * "a=ice-ufrag:" and "a=ice-pwd:" are present at media or session
+///   level and are made of ice-char, 4 to 256 characters for the ufrag
+///   and 22 to 256 for the password.
+/// * "a=candidate:" values are well-formed, with a component ID in the
+///   range 1 to 256.  With "a=rtcp-mux" only component 1 is expected, a
+///   warning, and without it RTP media should have a component 2
+///   candidate.
+/// * the media that owns a transport has candidates, unless the agent
+///   is an "a=ice-lite" agent or trickles its candidates, a warning.
+/// * "a=ice-lite" only appears at session level.
+/// * "a=end-of-candidates" appears at most once and after the
+///   candidates of the media.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=ice-ufrag:8hhY\r\n\
+///     a=ice-pwd:asd88fgpdd777uzjYhagZg\r\n\
+///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+///     a=rtcp-mux\r\n\
+///     a=candidate:1 1 UDP 2130706431 192.0.2.1 5000 typ host\r\n\
+///     a=end-of-candidates\r\n\
+///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+///     a=ice-ufrag:8h\r\n\
+///     a=rtcp-mux\r\n\
+///     a=candidate:1 2 UDP 2130706430 192.0.2.1 5001 typ host\r\n\
+///     a=end-of-candidates\r\n\
+///     a=candidate:1 1 UDP 2130706431 192.0.2.1 5000 typ host\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     a=ice-lite\r\n\
+///     a=candidate:1 0 UDP 2130706431 192.0.2.1 5002 typ host\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     m=video 0 UDP/TLS/RTP/SAVPF 96\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Ice);
+/// let codes = report.diagnostics
+///     .iter()
+///     .map(|d| (d.severity, d.code, d.media))
+///     .collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     (Severity::Error, "ice-ufrag", Some(1)),
+///     (Severity::Error, "ice-end-of-candidates", Some(1)),
+///     (Severity::Warning, "ice-component", Some(1)),
+///     (Severity::Error, "ice-lite", Some(2)),
+///     (Severity::Error, "ice-candidate", Some(2)),
+///     (Severity::Warning, "ice-candidates", Some(3)),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ice;
+
+impl Profile for Ice {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        let lite = has(&sdp.attributes, flag("ice-lite"));
+        let trickle = |options: Option<&str>| options.is_some_and(|v| v.split(' ').any(|v| v == "trickle"));
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            if media.port.num == 0 {
+                continue;
+            }
+
+            let attributes = &media.attributes;
+            for (name, min) in [("ice-ufrag", 4), ("ice-pwd", 22)] {
+                match find(attributes, other(name)).or_else(|| find(&sdp.attributes, other(name))) {
+                    None => report.error(name, index, format!("missing a={}", name)),
+                    Some(v) if !is_credential(v, min) => {
+                        report.error(name, index, format!("a={}:{} is not {} to 256 ice-char", name, v, min))
+                    }
+                    _ => (),
+                }
+            }
+
+            if has(attributes, flag("ice-lite")) {
+                report.error("ice-lite", index, "a=ice-lite is only allowed at session level");
+            }
+
+            let rtcp_mux = has(attributes, flag("rtcp-mux"));
+            let mut components = [false; 2];
+            let mut candidates = 0;
+            let mut end = 0;
+            for attribute in attributes {
+                if flag("end-of-candidates")(&attribute.value) {
+                    end += 1;
+                }
+
+                let Some(value) = other("candidate")(&attribute.value) else {
+                    continue;
+                };
+
+                candidates += 1;
+                if end > 0 {
+                    report.error("ice-end-of-candidates", index, "a=candidate after a=end-of-candidates");
+                }
+
+                match candidate(value) {
+                    Some((component, _, _)) if (1..=256).contains(&component) => {
+                        if component <= 2 {
+                            components[component as usize - 1] = true;
+                        }
+                    }
+                    _ => report.error("ice-candidate", index, format!("invalid a=candidate:{}", value)),
+                }
+            }
+
+            if end > 1 {
+                report.warning("ice-end-of-candidates", index, "more than one a=end-of-candidates");
+            }
+
+            if rtcp_mux && components[1] {
+                report.warning("ice-component", index, "component 2 candidates with a=rtcp-mux");
+            }
+
+            if !rtcp_mux && media.is_rtp() && components[0] && !components[1] {
+                report.warning("ice-component", index, "no component 2 candidates without a=rtcp-mux");
+            }
+
+            let trickled = trickle(find(attributes, other("ice-options")))
+                || trickle(find(&sdp.attributes, other("ice-options")));
+            if candidates == 0 && sdp.transport_media(i) == i && !lite && !trickled {
+                report.warning("ice-candidates", index, "no a=candidate, and candidates are not trickled");
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::{
+    Profile,
+    Report
+};
+
+use crate::attributes::{
+    Attributes,
+    Mid
+};
+use crate::Sdp;
+use alloc::format;
+
+/// Media identification profile.
+///
+/// Checks the identification tags of
+/// [RFC5888](https://datatracker.ietf.org/doc/html/rfc5888):
+///
+/// * "a=mid:" values must be tokens.
+/// * "a=mid:" values must be unique within the session.
+/// * "a=mid:" values should not be longer than [`Mid::MAX_LEN`] bytes
+///   ([RFC9143](https://datatracker.ietf.org/doc/html/rfc9143)), a
+///   warning.
+/// * "a=group:" must only reference existing mids.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=group:BUNDLE 0 1 2\r\n\
+///     m=audio 9 RTP/AVP 0\r\n\
+///     a=mid:0\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:0\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:a/b\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Mids);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("mid-unique", Some(1)),
+///     ("mid-token", Some(2)),
+///     ("group-mid", None),
+///     ("group-mid", None),
+/// ]);
+///
+/// let sdp = Sdp::try_from("v=0\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\na=mid:0123456789abcdefg\r\n").unwrap();
+/// let report = sdp.validate(&Mids);
+/// assert_eq!(report.warnings().next().unwrap().code, "mid-length");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mids;
+
+impl Profile for Mids {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let mid = match media.mid() {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if sdp.medias[..i].iter().any(|m| m.mid() == Some(mid)) {
+                report.error("mid-unique", Some(i), format!("duplicate a=mid:{}", mid));
+            }
+
+            if !mid.is_token() {
+                report.error("mid-token", Some(i), format!("a=mid:{} is not a token", mid));
+            }
+
+            if mid.len() > Mid::MAX_LEN {
+                report.warning("mid-length", Some(i), format!("a=mid:{} is longer than {} bytes", mid, Mid::MAX_LEN));
+            }
+        }
+
+        for attribute in &sdp.attributes {
+            if let Attributes::Group(group) = &attribute.value {
+                for mid in group.mids.iter().filter(|v| sdp.media_index(v).is_none()) {
+                    report.error("group-mid", None, format!("a=group:{} references unknown mid {}", group.semantics, mid));
+                }
+            }
+        }
+    }
+}
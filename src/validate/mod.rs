@@ -0,0 +1,170 @@
+//! Session description validation.
+//!
+//! The parser accepts any description that is syntactically valid.
+//! Validation checks the semantic rules of a profile on top of that, and
+//! reports every violation instead of failing on the first one, so that
+//! a description can be linted as a whole.
+
+#[cfg(feature = "broadcast")]
+mod st2110;
+mod tcp;
+mod bundle;
+mod usage;
+mod mid;
+mod ptime;
+mod strict;
+mod ssrc;
+mod simulcast;
+mod ice;
+#[cfg(feature = "webrtc")]
+mod webrtc;
+
+#[cfg(feature = "broadcast")]
+pub use st2110::St2110;
+pub use tcp::Tcp;
+pub use bundle::Bundle;
+pub use usage::UsageLevels;
+pub use mid::Mids;
+pub use ptime::Ptime;
+pub use strict::Strict;
+pub use ssrc::Ssrcs;
+pub use simulcast::Simulcast;
+pub use ice::Ice;
+#[cfg(feature = "webrtc")]
+pub use webrtc::WebRtc;
+
+use super::Sdp;
+use crate::attributes::{
+    Attribute,
+    Attributes
+};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Diagnostic severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// the description works, but does not follow a recommendation.
+    Warning,
+    /// the description violates a requirement of the profile.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Warning => "warning",
+            Self::Error =>   "error",
+        })
+    }
+}
+
+/// A single validation finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// stable identifier of the check, e.g. "st2110-ts-refclk".
+    pub code: &'static str,
+    /// index of the media description, `None` for the session level.
+    pub media: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::validate::*;
+    ///
+    /// let diagnostic = Diagnostic {
+    ///     severity: Severity::Error,
+    ///     code: "st2110-mediaclk",
+    ///     media: Some(1),
+    ///     message: "missing a=mediaclk".to_string(),
+    /// };
+    ///
+    /// assert_eq!(format!("{}", diagnostic), "error[st2110-mediaclk] m=1: missing a=mediaclk");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}] ", self.severity, self.code)?;
+        if let Some(media) = self.media {
+            write!(f, "m={}: ", media)?;
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Validation report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    /// the description has no errors, warnings are allowed.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Warning)
+    }
+
+    /// add an error.
+    pub fn error(&mut self, code: &'static str, media: Option<usize>, message: impl Into<String>) {
+        self.push(Severity::Error, code, media, message.into())
+    }
+
+    /// add a warning.
+    pub fn warning(&mut self, code: &'static str, media: Option<usize>, message: impl Into<String>) {
+        self.push(Severity::Warning, code, media, message.into())
+    }
+
+    fn push(&mut self, severity: Severity, code: &'static str, media: Option<usize>, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            code,
+            media,
+            message,
+        })
+    }
+}
+
+/// Validation profile.
+///
+/// A profile is a set of checks, implement it to add custom rules.
+pub trait Profile {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report);
+}
+
+impl<'a> Sdp<'a> {
+    /// validate the description against a profile.
+    pub fn validate(&self, profile: &dyn Profile) -> Report {
+        let mut report = Report::default();
+        profile.check(self, &mut report);
+        report
+    }
+}
+
+/// the first attribute value selected by `f`.
+fn find<'b, 'a, T>(
+    attributes: &'b [Attribute<'a>],
+    f: impl Fn(&'b Attributes<'a>) -> Option<T>
+) -> Option<T> {
+    attributes.iter().find_map(|a| f(&a.value))
+}
+
+fn has(attributes: &[Attribute<'_>], f: impl Fn(&Attributes<'_>) -> bool) -> bool {
+    attributes.iter().any(|a| f(&a.value))
+}
+
+fn count(attributes: &[Attribute<'_>], f: impl Fn(&Attributes<'_>) -> bool) -> usize {
+    attributes.iter().filter(|a| f(&a.value)).count()
+}
@@ -0,0 +1,136 @@
+use super::{
+    Profile,
+    Report,
+    find
+};
+
+use crate::attributes::Attributes;
+use crate::media::{
+    Encoding,
+    Media
+};
+
+use crate::Sdp;
+use alloc::borrow::Cow;
+use alloc::format;
+
+/// frame sizes in milliseconds of frame based audio codecs.
+const FRAME_SIZES: [(&str, u64); 8] = [
+    ("G723", 30),
+    ("G729", 10),
+    ("GSM", 20),
+    ("AMR", 20),
+    ("AMR-WB", 20),
+    ("EVRC", 20),
+    ("QCELP", 20),
+    ("iLBC", 30),
+];
+
+/// Packet time profile.
+///
+/// Checks the "a=ptime:" and "a=maxptime:" attributes
+/// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-6.4):
+///
+/// * "a=maxptime:" must not be less than "a=ptime:".
+/// * "a=ptime:" should be a multiple of the frame size of the frame
+///   based codecs of the media, e.g. 10 ms for G.729.  iLBC uses the
+///   frame size of its "mode" format parameter, 30 ms by default.
+/// * "a=ptime:" and "a=maxptime:" only apply to audio.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=audio 49170 RTP/AVP 0 18\r\n\
+///     a=ptime:20\r\n\
+///     a=maxptime:40\r\n\
+///     m=audio 49172 RTP/AVP 97 18\r\n\
+///     a=rtpmap:97 iLBC/8000\r\n\
+///     a=fmtp:97 mode=20\r\n\
+///     a=ptime:25\r\n\
+///     a=maxptime:20\r\n\
+///     m=video 51372 RTP/AVP 96\r\n\
+///     a=ptime:20\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Ptime);
+/// let codes = report.diagnostics.iter().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("ptime-maxptime", Some(1)),
+///     ("ptime-frame", Some(1)),
+///     ("ptime-frame", Some(1)),
+///     ("ptime-video", Some(2)),
+/// ]);
+///
+/// assert_eq!(report.errors().count(), 1);
+/// assert_eq!(report.diagnostics[1].message, "a=ptime:25 is not a multiple of the 20 ms frames of iLBC");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ptime;
+
+impl Profile for Ptime {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            let ptime = find(&media.attributes, |a| match a {
+                Attributes::Ptime(v) => Some(*v),
+                _ => None,
+            });
+
+            let maxptime = find(&media.attributes, |a| match a {
+                Attributes::MaxPtime(v) => Some(*v),
+                _ => None,
+            });
+
+            if media.encoding == Encoding::Video && (ptime.is_some() || maxptime.is_some()) {
+                report.warning("ptime-video", index, "a=ptime and a=maxptime only apply to audio");
+                continue;
+            }
+
+            let Some(ptime) = ptime else {
+                continue;
+            };
+
+            if let Some(maxptime) = maxptime.filter(|v| *v < ptime) {
+                let message = format!("a=maxptime:{} is less than a=ptime:{}", maxptime, ptime);
+                report.error("ptime-maxptime", index, message);
+            }
+
+            for pt in &media.fmts {
+                if let Some((codec, frame)) = frame_size(media, *pt).filter(|(_, v)| ptime % v != 0) {
+                    let message = format!(
+                        "a=ptime:{} is not a multiple of the {} ms frames of {}",
+                        ptime,
+                        frame,
+                        codec
+                    );
+
+                    report.warning("ptime-frame", index, message);
+                }
+            }
+        }
+    }
+}
+
+/// the codec name and frame size of a frame based payload type.
+fn frame_size<'b>(media: &'b Media<'_>, pt: u8) -> Option<(Cow<'b, str>, u64)> {
+    let codec = media.codec(pt)?.codec;
+    let (_, frame) = FRAME_SIZES
+        .iter()
+        .find(|(v, _)| v.eq_ignore_ascii_case(&codec))?;
+    if !codec.eq_ignore_ascii_case("iLBC") {
+        return Some((codec, *frame));
+    }
+
+    let mode = find(&media.attributes, |a| match a {
+        Attributes::Fmtp(v) if v.key == pt => v.get("mode").flatten()?.parse().ok(),
+        _ => None,
+    });
+
+    Some((codec, mode.unwrap_or(*frame)))
+}
@@ -0,0 +1,120 @@
+use super::{
+    Profile,
+    Report
+};
+
+use crate::attributes::{
+    Attribute,
+    Attributes
+};
+
+use crate::media::Media;
+use crate::Sdp;
+use alloc::format;
+
+/// Simulcast profile.
+///
+/// Checks the "a=simulcast:" streams against the "a=rid:" attributes of
+/// the media
+/// ([RFC8853](https://datatracker.ietf.org/doc/html/rfc8853#section-5.1),
+/// [RFC8851](https://datatracker.ietf.org/doc/html/rfc8851#section-4)):
+///
+/// * every rid of "a=simulcast:" has an "a=rid:" line.
+/// * the "a=rid:" direction is the direction of the "a=simulcast:" list
+///   it is in.
+/// * the "pt=" restriction of "a=rid:" only lists payload types of the
+///   "m=" line.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=video 9 RTP/AVP 96 97\r\n\
+///     a=rid:h send pt=96,98\r\n\
+///     a=rid:m recv\r\n\
+///     a=rid:l send max-width=320\r\n\
+///     a=simulcast:send h;~m,x;l\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Simulcast);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("simulcast-rid", Some(0)),
+///     ("simulcast-rid", Some(0)),
+///     ("rid-pt", Some(0)),
+/// ]);
+///
+/// assert_eq!(report.diagnostics[0].message, "a=simulcast:send lists rid m, but a=rid:m is recv");
+/// assert_eq!(report.diagnostics[1].message, "a=simulcast:send lists rid x without a=rid");
+/// assert_eq!(report.diagnostics[2].message, "a=rid:h restricts payload type 98 that is not in the m= line");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Simulcast;
+
+impl Profile for Simulcast {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            if let Some(simulcast) = media.attributes.iter().find_map(|a| other(a, "simulcast")) {
+                // "send" and "recv" each followed by their stream list.
+                let mut fields = simulcast.split(' ');
+                while let (Some(direction), Some(streams)) = (fields.next(), fields.next()) {
+                    let rids = streams
+                        .split([';', ','])
+                        .map(|v| v.trim_start_matches('~'))
+                        .filter(|v| !v.is_empty());
+                    for id in rids {
+                        match rid(media, id).map(|v| v.split(' ').nth(1).unwrap_or_default()) {
+                            None => {
+                                let message = format!("a=simulcast:{} lists rid {} without a=rid", direction, id);
+                                report.error("simulcast-rid", index, message);
+                            }
+                            Some(v) if v != direction => {
+                                let message = format!("a=simulcast:{} lists rid {}, but a=rid:{} is {}", direction, id, id, v);
+                                report.error("simulcast-rid", index, message);
+                            }
+                            Some(_) => (),
+                        }
+                    }
+                }
+            }
+
+            for value in media.attributes.iter().filter_map(|a| other(a, "rid")) {
+                let mut fields = value.splitn(3, ' ');
+                let id = fields.next().unwrap_or_default();
+                let restrictions = fields.nth(1).unwrap_or_default();
+                let pts = restrictions
+                    .split(';')
+                    .find_map(|v| v.strip_prefix("pt="))
+                    .unwrap_or_default();
+                for pt in pts.split(',').filter(|v| !v.is_empty()) {
+                    if !pt.parse().is_ok_and(|v: u8| media.fmts.contains(&v)) {
+                        let message = format!("a=rid:{} restricts payload type {} that is not in the m= line", id, pt);
+                        report.error("rid-pt", index, message);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// the value of an unparsed attribute with the given name.
+fn other<'s>(attribute: &'s Attribute<'_>, name: &str) -> Option<&'s str> {
+    match &attribute.value {
+        Attributes::Other(k, Some(v)) if k == name => Some(v),
+        _ => None,
+    }
+}
+
+/// the "a=rid:" value of a rid.
+fn rid<'s>(media: &'s Media<'_>, id: &str) -> Option<&'s str> {
+    media.attributes
+        .iter()
+        .filter_map(|a| other(a, "rid"))
+        .find(|v| v.split(' ').next() == Some(id))
+}
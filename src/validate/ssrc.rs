@@ -0,0 +1,123 @@
+use super::{
+    Profile,
+    Report,
+    has
+};
+
+use crate::attributes::{
+    Attributes,
+    SsrcAttr
+};
+
+use crate::Sdp;
+use alloc::format;
+use alloc::vec::Vec;
+
+/// Synchronization source profile.
+///
+/// Checks the "a=ssrc:" and "a=ssrc-group:" attributes of
+/// [RFC5576](https://datatracker.ietf.org/doc/html/rfc5576):
+///
+/// * every SSRC of "a=ssrc-group:" has an "a=ssrc:" line in the same
+///   media.
+/// * an SSRC has one cname, all its "a=ssrc:<ssrc> cname:" lines agree.
+/// * an SSRC is only used by one media, unless the medias share a
+///   BUNDLE transport.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=group:BUNDLE 0 1\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:0\r\n\
+///     a=ssrc-group:FID 1 2\r\n\
+///     a=ssrc:1 cname:a\r\n\
+///     a=ssrc:1 cname:b\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:1\r\n\
+///     a=ssrc:1 cname:a\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:2\r\n\
+///     a=ssrc:1 cname:a\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Ssrcs);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("ssrc-group-ssrc", Some(0)),
+///     ("ssrc-cname", Some(0)),
+///     ("ssrc-unique", Some(2)),
+/// ]);
+///
+/// assert_eq!(report.diagnostics[0].message, "a=ssrc-group:FID references SSRC 2 without a=ssrc");
+/// assert_eq!(report.diagnostics[1].message, "SSRC 1 has cname b and a");
+/// assert_eq!(report.diagnostics[2].message, "SSRC 1 is already used by media 0");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ssrcs;
+
+impl Profile for Ssrcs {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        // the first media and cname of every SSRC.
+        let mut seen: Vec<(u32, usize, Option<&str>)> = Vec::new();
+
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            for attribute in &media.attributes {
+                let Attributes::Other(k, Some(v)) = &attribute.value else {
+                    continue;
+                };
+
+                if k != "ssrc-group" {
+                    continue;
+                }
+
+                let mut fields = v.split(' ');
+                let semantics = fields.next().unwrap_or_default();
+                for ssrc in fields.filter_map(|v| v.parse::<u32>().ok()) {
+                    let found = has(&media.attributes, |a| matches!(a, Attributes::Ssrc(v) if v.key == ssrc));
+                    if !found {
+                        let message = format!("a=ssrc-group:{} references SSRC {} without a=ssrc", semantics, ssrc);
+                        report.error("ssrc-group-ssrc", index, message);
+                    }
+                }
+            }
+
+            for (j, attribute) in media.attributes.iter().enumerate() {
+                let Attributes::Ssrc(ssrc) = &attribute.value else {
+                    continue;
+                };
+
+                let cname = match &ssrc.value {
+                    SsrcAttr::Cname(v) => Some(v.as_ref()),
+                    _ => None,
+                };
+
+                let Some(entry) = seen.iter_mut().find(|(v, ..)| *v == ssrc.key) else {
+                    seen.push((ssrc.key, i, cname));
+                    continue;
+                };
+
+                match (entry.2, cname) {
+                    (Some(a), Some(b)) if a != b => {
+                        report.error("ssrc-cname", index, format!("SSRC {} has cname {} and {}", ssrc.key, b, a));
+                    }
+                    (None, Some(_)) => entry.2 = cname,
+                    _ => (),
+                }
+
+                // report every media once, on its first line of the SSRC.
+                let first = !has(&media.attributes[..j], |a| matches!(a, Attributes::Ssrc(v) if v.key == ssrc.key));
+                if first && entry.1 != i && sdp.transport_media(entry.1) != sdp.transport_media(i) {
+                    report.error("ssrc-unique", index, format!("SSRC {} is already used by media {}", ssrc.key, entry.1));
+                }
+            }
+        }
+    }
+}
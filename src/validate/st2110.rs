@@ -0,0 +1,145 @@
+use super::{
+    Profile,
+    Report,
+    find,
+    has
+};
+
+use crate::attributes::{
+    Attributes,
+    MediaClock,
+    St2110Video
+};
+
+use crate::media::{
+    Encoding,
+    Media
+};
+use crate::Sdp;
+use alloc::format;
+use core::convert::TryFrom;
+
+/// SMPTE ST 2110 profile.
+///
+/// Checks the attributes required by ST 2110-10 (system timing), ST
+/// 2110-20 (uncompressed video) and ST 2110-30 (PCM audio):
+///
+/// * "a=ts-refclk:" at session or media level.
+/// * "a=mediaclk:direct=0" at session or media level.
+/// * for "raw" video, the required ST 2110-20 format parameters.
+/// * for audio, "a=ptime:".
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     o=- 123456 1 IN IP4 192.168.1.10\r\n\
+///     s=camera 1\r\n\
+///     t=0 0\r\n\
+///     a=ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:0\r\n\
+///     m=video 50000 RTP/AVP 112\r\n\
+///     a=rtpmap:112 raw/90000\r\n\
+///     a=fmtp:112 sampling=YCbCr-4:2:2; width=1920; height=1080; exactframerate=30000/1001; \
+///     depth=10; TCS=SDR; colorimetry=BT709; PM=2110GPM; SSN=ST2110-20:2017; TP=2110TPN;\r\n\
+///     a=mediaclk:direct=0\r\n\
+///     m=audio 50002 RTP/AVP 97\r\n\
+///     a=rtpmap:97 L24/48000/2\r\n\
+///     a=mediaclk:direct=0\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&St2110);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![("st2110-30-ptime", Some(1))]);
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     m=video 50000 RTP/AVP 112\r\n\
+///     a=rtpmap:112 raw/90000\r\n\
+///     a=fmtp:112 sampling=YCbCr-4:2:2; width=1920\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&St2110);
+/// assert!(!report.is_valid());
+/// assert!(report.errors().any(|d| d.code == "st2110-ts-refclk"));
+/// assert!(report.errors().any(|d| d.code == "st2110-mediaclk"));
+/// assert_eq!(report.errors().filter(|d| d.code == "st2110-20-fmtp").count(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct St2110;
+
+impl Profile for St2110 {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        let refclk = |a: &Attributes| matches!(a, Attributes::TsRefClk(_));
+        // whether the media clock is "direct=0".
+        let mediaclk = |a: &Attributes| match a {
+            Attributes::MediaClk(v) => Some(matches!(v.clock, MediaClock::Direct { offset: Some(0), .. })),
+            _ => None,
+        };
+
+        let session_refclk = has(&sdp.attributes, refclk);
+        let session_mediaclk = find(&sdp.attributes, mediaclk);
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            if !session_refclk && !has(&media.attributes, refclk) {
+                report.error("st2110-ts-refclk", index, "missing a=ts-refclk");
+            }
+
+            match find(&media.attributes, mediaclk).or(session_mediaclk) {
+                None => report.error("st2110-mediaclk", index, "missing a=mediaclk"),
+                Some(false) => report.error("st2110-mediaclk", index, "a=mediaclk must be direct=0"),
+                Some(true) => (),
+            }
+
+            match media.encoding {
+                Encoding::Video => check_video(media, i, report),
+                Encoding::Audio if !has(&media.attributes, |a| matches!(a, Attributes::Ptime(_))) => {
+                    report.error("st2110-30-ptime", index, "missing a=ptime")
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+fn check_video(media: &Media<'_>, i: usize, report: &mut Report) {
+    for pt in &media.fmts {
+        // only uncompressed video is covered by ST 2110-20.
+        let raw = has(&media.attributes, |a| matches!(
+            a, Attributes::Rtpmap(v) if v.key == *pt && v.value.codec.eq_ignore_ascii_case("raw")
+        ));
+
+        if !raw {
+            continue
+        }
+
+        let fmtp = match find(&media.attributes, |a| match a {
+            Attributes::Fmtp(v) if v.key == *pt => Some(v),
+            _ => None,
+        }) {
+            Some(v) => v,
+            None => {
+                report.error("st2110-20-fmtp", Some(i), format!("missing a=fmtp for payload type {}", pt));
+                continue
+            }
+        };
+
+        let mut complete = true;
+        for name in St2110Video::REQUIRED {
+            if fmtp.get(name).flatten().is_none() {
+                let message = format!("missing format parameter {} for payload type {}", name, pt);
+                report.error("st2110-20-fmtp", Some(i), message);
+                complete = false;
+            }
+        }
+
+        if complete {
+            if let Err(e) = St2110Video::try_from(fmtp) {
+                report.error("st2110-20-fmtp", Some(i), format!("{} for payload type {}", e, pt));
+            }
+        }
+    }
+}
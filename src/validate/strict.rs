@@ -0,0 +1,152 @@
+use super::{
+    Mids,
+    Profile,
+    Ptime,
+    Report,
+    Simulcast,
+    Ssrcs,
+    UsageLevels
+};
+
+use crate::attributes::{
+    Attribute,
+    Attributes
+};
+
+use crate::media::Encoding;
+use crate::Sdp;
+use alloc::format;
+
+/// Strict RFC 8866 profile.
+///
+/// Runs the general checks every description should pass, the
+/// [`UsageLevels`], [`Ptime`], [`Mids`], [`Ssrcs`] and [`Simulcast`]
+/// profiles, and checks the attribute values the parser accepts beyond
+/// their defined range:
+///
+/// * "a=quality:" is in the range 0 to 10
+///   ([RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-6.13)),
+///   an error for video where the range is defined, a warning for other
+///   media.
+/// * "a=ice-pacing:" is a positive number of milliseconds
+///   ([RFC8839](https://datatracker.ietf.org/doc/html/rfc8839#section-5.7)).
+/// * "a=charset:" is a registered IANA character set name
+///   ([RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-6.10)),
+///   a warning since the compiled list may not know every registration.
+/// * with the `bcp47` feature, "a=sdplang:" and "a=lang:" are well-formed
+///   [RFC5646](https://datatracker.ietf.org/doc/html/rfc5646#section-2.1)
+///   language tags.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=charset:utf8\r\n\
+///     a=ice-pacing:0\r\n\
+///     m=video 51372 RTP/AVP 96\r\n\
+///     a=quality:10\r\n\
+///     m=video 51374 RTP/AVP 96\r\n\
+///     a=quality:11\r\n\
+///     m=audio 49170 RTP/AVP 0\r\n\
+///     a=quality:20\r\n\
+///     a=ptime:20\r\n\
+///     a=maxptime:10\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Strict);
+/// let codes = report.diagnostics.iter().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("ptime-maxptime", Some(2)),
+///     ("charset-unknown", None),
+///     ("ice-pacing-range", None),
+///     ("quality-range", Some(1)),
+///     ("quality-range", Some(2)),
+/// ]);
+///
+/// assert_eq!(report.errors().count(), 3);
+/// assert_eq!(report.diagnostics[1].message, "a=charset:utf8 is not a registered character set");
+/// assert_eq!(report.diagnostics[3].message, "a=quality:11 is outside the range 0 to 10");
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "bcp47")] {
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=sdplang:en-US\r\n\
+///     m=audio 49170 RTP/AVP 0\r\n\
+///     a=lang:en_US\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Strict);
+/// let codes = report.diagnostics.iter().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![("lang-tag", Some(0))]);
+/// assert_eq!(report.diagnostics[0].message, "a=lang:en_US is not a language tag");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Strict;
+
+impl Profile for Strict {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        UsageLevels.check(sdp, report);
+        Ptime.check(sdp, report);
+        Mids.check(sdp, report);
+        Ssrcs.check(sdp, report);
+        Simulcast.check(sdp, report);
+
+        check_values(&sdp.attributes, None, report);
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let video = media.encoding == Encoding::Video;
+            check_values(&media.attributes, Some((i, video)), report);
+        }
+    }
+}
+
+/// `media` is the media index and whether it is video, `None` for the
+/// session level.
+fn check_values(attributes: &[Attribute<'_>], media: Option<(usize, bool)>, report: &mut Report) {
+    let index = media.map(|(i, _)| i);
+    for attribute in attributes {
+        if let Attributes::Quality(v) = attribute.value {
+            if v > 10 {
+                let message = format!("a=quality:{} is outside the range 0 to 10", v);
+                match media {
+                    Some((_, true)) => report.error("quality-range", index, message),
+                    _ => report.warning("quality-range", index, message),
+                }
+            }
+        }
+
+        if let Attributes::IcePacing(0) = attribute.value {
+            report.error("ice-pacing-range", index, "a=ice-pacing must be a positive number of milliseconds");
+        }
+
+        if let Attributes::Charset(v) = &attribute.value {
+            if !crate::attributes::is_charset(v) {
+                let message = format!("a=charset:{} is not a registered character set", v);
+                report.warning("charset-unknown", index, message);
+            }
+        }
+
+        #[cfg(feature = "bcp47")]
+        if let Some((name, v)) = match &attribute.value {
+            Attributes::SdpLang(v) => Some(("sdplang", v)),
+            Attributes::Lang(v) => Some(("lang", v)),
+            _ => None,
+        } {
+            if !crate::attributes::is_language_tag(v) {
+                let message = format!("a={}:{} is not a language tag", name, v);
+                report.error("lang-tag", index, message);
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use super::{
+    Profile,
+    Report,
+    find,
+    count
+};
+
+use crate::attributes::{
+    Attributes,
+    Setup,
+    TcpConnection
+};
+
+use crate::media::Proto;
+use crate::Sdp;
+
+/// TCP media transport profile.
+///
+/// Checks the "a=setup:" and "a=connection:" attributes of media over
+/// TCP, e.g. "TCP/MSRP" or "TCP/TLS/MSRP" and T.38 over "TCP/TLS"
+/// [RFC4145](https://datatracker.ietf.org/doc/html/rfc4145):
+///
+/// * at most one "a=setup:" and one "a=connection:" per media, warnings
+///   when they are missing and the defaults ("active", "new") apply.
+/// * an active endpoint should use the discard port 9, and a passive
+///   endpoint must not, since it listens on that port.
+/// * "a=connection:" only applies to TCP media.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     c=IN IP4 192.0.2.1\r\n\
+///     m=message 7654 TCP/MSRP *\r\n\
+///     a=setup:passive\r\n\
+///     a=connection:new\r\n\
+///     m=message 9 TCP/TLS/MSRP *\r\n\
+///     a=setup:passive\r\n\
+///     m=audio 49170 RTP/AVP 0\r\n\
+///     a=connection:new\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&Tcp);
+/// let codes = report.diagnostics.iter().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("tcp-connection", Some(1)),
+///     ("tcp-setup-port", Some(1)),
+///     ("tcp-connection", Some(2)),
+/// ]);
+///
+/// assert!(!report.is_valid());
+/// assert_eq!(report.errors().count(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tcp;
+
+impl Profile for Tcp {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        let setup = |a: &Attributes| match a {
+            Attributes::Setup(v) => Some(*v),
+            _ => None,
+        };
+
+        let connection = |a: &Attributes| match a {
+            Attributes::Connection(v) => Some(*v),
+            _ => None,
+        };
+
+        let session_setup = find(&sdp.attributes, setup);
+        let session_connection = find(&sdp.attributes, connection);
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            let setups = count(&media.attributes, |a| matches!(a, Attributes::Setup(_)));
+            let connections = count(&media.attributes, |a| matches!(a, Attributes::Connection(_)));
+            if media.protos.first() != Some(&Proto::Tcp) {
+                if connections > 0 {
+                    report.warning("tcp-connection", index, "a=connection only applies to TCP media");
+                }
+
+                continue
+            }
+
+            if setups > 1 {
+                report.error("tcp-setup", index, "more than one a=setup");
+            }
+
+            if connections > 1 {
+                report.error("tcp-connection", index, "more than one a=connection");
+            }
+
+            let setup = find(&media.attributes, setup).or(session_setup);
+            let connection = find(&media.attributes, connection).or(session_connection);
+            if setup.is_none() {
+                report.warning("tcp-setup", index, "missing a=setup, the default is active");
+            }
+
+            if connection.is_none() {
+                report.warning("tcp-connection", index, "missing a=connection, the default is new");
+            }
+
+            // the port is irrelevant when no new connection is set up.
+            if connection == Some(TcpConnection::Existing) || media.port.num == 0 {
+                continue
+            }
+
+            match setup.unwrap_or(Setup::Active) {
+                Setup::Active if media.port.num != 9 => {
+                    report.warning("tcp-setup-port", index, "an active endpoint should use port 9")
+                },
+                Setup::Passive | Setup::Actpass if media.port.num == 9 => {
+                    report.error("tcp-setup-port", index, "a passive endpoint must not use port 9")
+                },
+                _ => (),
+            }
+        }
+    }
+}
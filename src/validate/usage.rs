@@ -0,0 +1,79 @@
+use super::{
+    Profile,
+    Report
+};
+
+use crate::attributes::Attribute;
+use crate::Sdp;
+use alloc::format;
+
+/// Attribute usage level profile.
+///
+/// Warns about every attribute that appears at a level its usage level
+/// does not allow, e.g. "a=orient:" at session level.  The parser
+/// accepts these unless [`crate::options::ParseOptions::enforce_usage`]
+/// is set.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=orient:portrait\r\n\
+///     a=group:BUNDLE 0\r\n\
+///     m=video 9 RTP/AVP 96\r\n\
+///     a=mid:0\r\n\
+///     a=charset:UTF-8\r\n\
+///     a=group:LS 0\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&UsageLevels);
+/// assert!(report.is_valid());
+///
+/// let codes = report.warnings().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("usage-level", None),
+///     ("usage-level", Some(0)),
+///     ("usage-level", Some(0)),
+/// ]);
+/// assert_eq!(
+///     report.diagnostics[0].message,
+///     "a=orient is only allowed at media level"
+/// );
+///
+/// // attributes built in code are named by their value.
+/// let mut sdp = Sdp::try_from(temp).unwrap();
+/// sdp.medias[0].attributes.push(attributes::Attributes::IcePacing(50).into());
+/// let report = sdp.validate(&UsageLevels);
+/// assert_eq!(
+///     report.diagnostics[3].message,
+///     "a=ice-pacing is only allowed at session level"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UsageLevels;
+
+impl Profile for UsageLevels {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        check_attributes(&sdp.attributes, None, report);
+        for (i, media) in sdp.medias.iter().enumerate() {
+            check_attributes(&media.attributes, Some(i), report);
+        }
+    }
+}
+
+fn check_attributes(attributes: &[Attribute<'_>], media: Option<usize>, report: &mut Report) {
+    for attribute in attributes {
+        let usage = attribute.value.usage();
+        if usage.allows(media.is_some()) {
+            continue
+        }
+
+        let message = format!("a={} is only allowed at {} level", attribute.value.name(), usage);
+        report.warning("usage-level", media, message);
+    }
+}
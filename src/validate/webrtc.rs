@@ -0,0 +1,114 @@
+use super::{
+    Profile,
+    Report,
+    count,
+    has
+};
+
+use crate::attributes::Attributes;
+use crate::media::Proto;
+use crate::Sdp;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::format;
+
+/// whether the transport protocol is DTLS-SRTP or DTLS/SCTP.
+fn is_secure(protos: &[Proto]) -> bool {
+    protos == [Proto::Udp, Proto::Tls, Proto::Rtp, Proto::Savpf]
+        || protos.ends_with(&[Proto::Dtls, Proto::Sctp])
+}
+
+/// WebRTC DTLS-SRTP profile.
+///
+/// Checks the security of the transports of
+/// [RFC8827](https://datatracker.ietf.org/doc/html/rfc8827#section-6.5)
+/// and [RFC8842](https://datatracker.ietf.org/doc/html/rfc8842) for
+/// every media that is not rejected:
+///
+/// * the proto is "UDP/TLS/RTP/SAVPF" or DTLS/SCTP.
+/// * SDES "a=crypto:" keys are not used, also not at session level.
+/// * the media that owns a transport has exactly one "a=setup:" role,
+///   the session level role applies if the media has none.
+/// * the media that owns a transport has at least one "a=fingerprint:"
+///   at media or session level.
+///
+/// # Unit Test
+///
+/// ```
+/// use sdp::validate::*;
+/// use sdp::*;
+/// use std::convert::*;
+///
+/// let temp = "v=0\r\n\
+///     s=-\r\n\
+///     a=setup:actpass\r\n\
+///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+///     a=fingerprint:sha-256 12:DF:3E:5D:49:6B:19:E5:7C:AB:4A:AD:B9:B1:3F:82:18:3B:54:02:12:DF:3E:5D:49:6B:19:E5:7C:AB:4A:AD\r\n\
+///     m=video 9 RTP/SAVPF 96\r\n\
+///     a=setup:active\r\n\
+///     a=setup:passive\r\n\
+///     a=crypto:1 AES_CM_128_HMAC_SHA1_80 inline:PS1uQCVeeCFCanVmcjkpPywjNWhcYD0mXXtxaVBR\r\n\
+///     m=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n\
+///     m=video 0 RTP/AVP 96\r\n";
+///
+/// let sdp = Sdp::try_from(temp).unwrap();
+/// let report = sdp.validate(&WebRtc);
+/// let codes = report.errors().map(|d| (d.code, d.media)).collect::<Vec<_>>();
+/// assert_eq!(codes, vec![
+///     ("webrtc-proto", Some(1)),
+///     ("webrtc-crypto", Some(1)),
+///     ("webrtc-setup", Some(1)),
+///     ("webrtc-fingerprint", Some(1)),
+///     ("webrtc-fingerprint", Some(2)),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebRtc;
+
+impl Profile for WebRtc {
+    fn check(&self, sdp: &Sdp<'_>, report: &mut Report) {
+        let crypto = |a: &Attributes<'_>| matches!(a, Attributes::Other(k, _) if k == "crypto");
+        let setup = |a: &Attributes<'_>| matches!(a, Attributes::Setup(_));
+        let fingerprint = |a: &Attributes<'_>| matches!(a, Attributes::Fingerprint(_));
+
+        if has(&sdp.attributes, crypto) {
+            report.error("webrtc-crypto", None, "a=crypto is not allowed with DTLS-SRTP");
+        }
+
+        for (i, media) in sdp.medias.iter().enumerate() {
+            let index = Some(i);
+            if media.port.num == 0 {
+                continue;
+            }
+
+            if !is_secure(&media.protos) {
+                let protos = media.protos.iter().map(ToString::to_string).collect::<Vec<_>>();
+                report.error("webrtc-proto", index, format!("{} is not UDP/TLS/RTP/SAVPF or DTLS/SCTP", protos.join("/")));
+            }
+
+            let attributes = &media.attributes;
+            if has(attributes, crypto) {
+                report.error("webrtc-crypto", index, "a=crypto is not allowed with DTLS-SRTP");
+            }
+
+            if sdp.transport_media(i) != i {
+                continue;
+            }
+
+            let setups = match count(attributes, setup) {
+                0 => count(&sdp.attributes, setup),
+                n => n,
+            };
+
+            match setups {
+                0 => report.error("webrtc-setup", index, "missing a=setup"),
+                1 => (),
+                n => report.error("webrtc-setup", index, format!("{} a=setup roles, expected one", n)),
+            }
+
+            if !has(attributes, fingerprint) && !has(&sdp.attributes, fingerprint) {
+                report.error("webrtc-fingerprint", index, "missing a=fingerprint");
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use core::{
+    convert::TryFrom,
+    str::FromStr,
+    fmt
+};
+
+/// Protocol Version ("v=")
+///
+/// v=0
+///
+/// The "v=" line (version-field) gives the version of the Session
+/// Description Protocol.  This memo defines version 0.  There is no
+/// minor version number.
+///
+/// Strict parsing rejects every other version, lenient parsing keeps
+/// it so that the description is written back unchanged.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Version(pub u8);
+
+impl Version {
+    /// whether this is the version defined by
+    /// [RFC8866](https://datatracker.ietf.org/doc/html/rfc8866#section-5.1).
+    pub fn is_supported(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Version {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::version::*;
+    /// use std::convert::*;
+    ///
+    /// let version = Version::try_from("0").unwrap();
+    /// assert_eq!(version, Version(0));
+    /// assert!(version.is_supported());
+    ///
+    /// let version = Version::try_from("1").unwrap();
+    /// assert!(!version.is_supported());
+    /// assert_eq!(format!("{}", version), "1");
+    ///
+    /// assert!(Version::try_from("a").is_err());
+    /// assert!(Version::try_from("").is_err());
+    /// ```
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self(value.parse()?))
+    }
+}
+
+impl FromStr for Version {
+    type Err = anyhow::Error;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
@@ -0,0 +1,263 @@
+//! WebRTC offer templates.
+//!
+//! Builds a complete offer in the form browsers generate, with the
+//! media bundled on one transport and multiplexed RTCP.  The ICE and
+//! DTLS values of the template are placeholders unless given in the
+//! [`OfferConfig`], callers fill them with the real values of their
+//! transport before sending the offer.
+
+use crate::attributes::{
+    Attribute,
+    Attributes,
+    Fingerprint,
+    Fmtp,
+    Group,
+    HashFunction,
+    MidGenerator,
+    RtpValue,
+    Semantics,
+    Setup
+};
+
+use crate::connection::{
+    Addr,
+    Connection
+};
+
+use crate::media::{
+    Encoding,
+    Media,
+    Port,
+    Proto
+};
+
+use crate::origin::Origin;
+use crate::timing::Timing;
+use crate::{
+    AddrKind,
+    NetKind,
+    Sdp
+};
+
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use anyhow::Result;
+use core::convert::TryFrom;
+use core::net::{
+    IpAddr,
+    Ipv4Addr
+};
+
+use smallvec::smallvec;
+
+/// placeholder ICE username fragment.
+pub const ICE_UFRAG_PLACEHOLDER: &str = "UFRAG";
+/// placeholder ICE password, the shortest one allowed.
+pub const ICE_PWD_PLACEHOLDER: &str = "PASSWORDPASSWORDPASSWORD";
+
+/// the header extensions of every media.
+const EXTENSIONS: [&str; 3] = [
+    "urn:ietf:params:rtp-hdrext:sdes:mid",
+    "http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time",
+    "http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01",
+];
+
+/// payload type, codec, "a=fmtp:" value and RTCP feedback of the
+/// default codecs.
+type Codec = (u8, &'static str, Option<&'static str>, &'static [&'static str]);
+
+const AUDIO_CODECS: [Codec; 3] = [
+    (111, "opus/48000/2", Some("111 minptime=10;useinbandfec=1"), &["transport-cc"]),
+    (0, "PCMU/8000", None, &[]),
+    (8, "PCMA/8000", None, &[]),
+];
+
+const VIDEO_FEEDBACK: &[&str] = &["goog-remb", "transport-cc", "ccm fir", "nack", "nack pli"];
+const VIDEO_CODECS: [Codec; 4] = [
+    (96, "VP8/90000", None, VIDEO_FEEDBACK),
+    (97, "rtx/90000", Some("97 apt=96"), &[]),
+    (102, "H264/90000", Some("102 level-asymmetry-allowed=1;packetization-mode=1;profile-level-id=42e01f"), VIDEO_FEEDBACK),
+    (103, "rtx/90000", Some("103 apt=102"), &[]),
+];
+
+/// WebRTC offer configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfferConfig<'a> {
+    /// the session id of the "o=" line.
+    pub session_id: u64,
+    /// add an audio media description with Opus, PCMU and PCMA.
+    pub audio: bool,
+    /// add a video media description with VP8 and H264.
+    pub video: bool,
+    pub ice_ufrag: Cow<'a, str>,
+    pub ice_pwd: Cow<'a, str>,
+    /// the DTLS certificate fingerprint, an all zero SHA-256 digest by
+    /// default.
+    pub fingerprint: Fingerprint<'a>,
+}
+
+impl Default for OfferConfig<'_> {
+    fn default() -> Self {
+        Self {
+            session_id: 0,
+            audio: true,
+            video: true,
+            ice_ufrag: Cow::Borrowed(ICE_UFRAG_PLACEHOLDER),
+            ice_pwd: Cow::Borrowed(ICE_PWD_PLACEHOLDER),
+            fingerprint: Fingerprint {
+                hash: HashFunction::Sha256,
+                value: smallvec![0; 32],
+            },
+        }
+    }
+}
+
+impl<'a> Sdp<'a> {
+    /// a WebRTC offer with the media of the configuration.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::attributes::*;
+    /// use sdp::validate::*;
+    /// use sdp::webrtc::*;
+    /// use sdp::*;
+    /// use std::convert::*;
+    ///
+    /// let sdp = Sdp::webrtc_offer(&OfferConfig {
+    ///     session_id: 4611731400430051336,
+    ///     ice_ufrag: "Oyef".into(),
+    ///     ..OfferConfig::default()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(sdp.medias.len(), 2);
+    /// assert_eq!(sdp.medias[0].mid(), Some(&Mid::Ref(0)));
+    /// assert_eq!(sdp.medias[1].fmts.as_slice(), &[96, 97, 102, 103]);
+    /// assert_eq!(sdp.bundle_transports().len(), 2);
+    /// assert!(sdp.validate(&Bundle).is_valid());
+    /// assert!(sdp.validate(&Mids).is_valid());
+    ///
+    /// let text = sdp.to_string();
+    /// assert!(text.starts_with("v=0\r\no=- 4611731400430051336 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n"));
+    /// assert!(text.contains("a=group:BUNDLE 0 1\r\n"));
+    /// assert!(text.contains("a=ice-ufrag:Oyef\r\n"));
+    /// assert!(text.contains("a=fingerprint:sha-256 00:00:00:"));
+    /// assert!(text.contains("a=rtpmap:111 opus/48000/2\r\na=rtcp-fb:111 transport-cc\r\n"));
+    /// assert_eq!(Sdp::try_from(text.as_str()).unwrap().to_string(), text);
+    ///
+    /// let sdp = Sdp::webrtc_offer(&OfferConfig {
+    ///     video: false,
+    ///     ..OfferConfig::default()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(sdp.medias.len(), 1);
+    /// assert_eq!(sdp.medias[0].encoding, media::Encoding::Audio);
+    /// ```
+    pub fn webrtc_offer(config: &OfferConfig<'a>) -> Result<Self> {
+        let mut sdp = Self {
+            origin: Some(Origin {
+                username: None,
+                sess_id: Cow::Owned(config.session_id.to_string()),
+                sess_version: 1,
+                nettype: NetKind::IN,
+                addrtype: AddrKind::IP4,
+                unicast_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            }),
+            timing: Some(Timing {
+                start: 0,
+                stop: 0,
+            }),
+            ..Self::default()
+        };
+
+        let mut group = Group {
+            semantics: Semantics::Bundle,
+            mids: smallvec![],
+        };
+
+        let medias = [
+            (config.audio, Encoding::Audio, &AUDIO_CODECS[..]),
+            (config.video, Encoding::Video, &VIDEO_CODECS[..]),
+        ];
+
+        let medias = medias.into_iter().filter(|(enabled, ..)| *enabled);
+        for ((_, encoding, codecs), mid) in medias.zip(MidGenerator::new()) {
+            group.mids.push(mid.clone());
+            sdp.medias.push(media(config, encoding, Attributes::Mid(mid), codecs)?);
+        }
+
+        sdp.attributes = Vec::from([
+            Attributes::Group(group).into(),
+            flag("extmap-allow-mixed"),
+            Attributes::Other(Cow::Borrowed("msid-semantic"), Some(Cow::Borrowed(" WMS"))).into(),
+        ]);
+
+        Ok(sdp)
+    }
+}
+
+fn flag<'a>(name: &'static str) -> Attribute<'a> {
+    Attributes::Other(Cow::Borrowed(name), None).into()
+}
+
+fn media<'a>(
+    config: &OfferConfig<'a>, 
+    encoding: Encoding, 
+    mid: Attributes<'a>, 
+    codecs: &[Codec]
+) -> Result<Media<'a>> {
+    let value = |v: &Cow<'a, str>| Some(v.clone());
+    let mut media = Media {
+        encoding,
+        port: Port {
+            num: 9,
+            count: None,
+        },
+        protos: smallvec![Proto::Udp, Proto::Tls, Proto::Rtp, Proto::Savpf],
+        fmts: smallvec![],
+        info: None,
+        connections: smallvec![Connection {
+            nettype: NetKind::IN,
+            addrtype: AddrKind::IP4,
+            connection_address: Addr {
+                ip: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                ttl: None,
+                count: None,
+            },
+        }],
+        bandwidth: smallvec![],
+        attributes: Vec::from([
+            Attributes::Other(Cow::Borrowed("rtcp"), Some(Cow::Borrowed("9 IN IP4 0.0.0.0"))).into(),
+            Attributes::Other(Cow::Borrowed("ice-ufrag"), value(&config.ice_ufrag)).into(),
+            Attributes::Other(Cow::Borrowed("ice-pwd"), value(&config.ice_pwd)).into(),
+            Attributes::Other(Cow::Borrowed("ice-options"), Some(Cow::Borrowed("trickle"))).into(),
+            Attributes::Fingerprint(config.fingerprint.clone()).into(),
+            Attributes::Setup(Setup::Actpass).into(),
+            mid.into(),
+        ]),
+        unknown_lines: Vec::new(),
+        section: None,
+    };
+
+    for uri in EXTENSIONS {
+        media.add_extension(uri)?;
+    }
+
+    media.attributes.push(flag("sendrecv"));
+    media.attributes.push(flag("rtcp-mux"));
+    media.attributes.push(flag("rtcp-rsize"));
+    for (pt, codec, params, feedback) in codecs {
+        media.add_codec_with(*pt, RtpValue::try_from(*codec)?)?;
+        for v in *feedback {
+            let v = Cow::Owned([pt.to_string().as_str(), v].join(" "));
+            media.attributes.push(Attributes::Other(Cow::Borrowed("rtcp-fb"), Some(v)).into());
+        }
+
+        if let Some(fmtp) = params {
+            media.attributes.push(Attributes::Fmtp(Fmtp::try_from(*fmtp)?).into());
+        }
+    }
+
+    Ok(media)
+}
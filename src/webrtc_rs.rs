@@ -0,0 +1,181 @@
+//! Conversions to and from the [webrtc-rs](https://webrtc.rs) SDP types.
+//!
+//! Descriptions parsed by this crate can be handed to webrtc-rs peer
+//! connections and back.  Session descriptions are converted through
+//! their text form, media descriptions field by field.  Converting from
+//! webrtc-rs gives owned values, the webrtc-rs types own all of their
+//! strings.
+
+use crate::attributes::Attribute;
+use crate::bandwidth::Bandwidth;
+use crate::connection::Connection;
+use crate::media::Media;
+use crate::Sdp;
+
+use anyhow::{
+    Result,
+    ensure
+};
+
+use std::convert::TryFrom;
+use std::io::Cursor;
+use webrtc_sdp::description::common::{
+    Address,
+    Attribute as WebrtcAttribute,
+    Bandwidth as WebrtcBandwidth,
+    ConnectionInformation
+};
+
+use webrtc_sdp::description::media::{
+    MediaDescription,
+    MediaName,
+    RangedPort
+};
+
+use webrtc_sdp::description::session::SessionDescription;
+
+impl TryFrom<&Sdp<'_>> for SessionDescription {
+    type Error = anyhow::Error;
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use std::convert::*;
+    /// use webrtc_sdp::description::session::SessionDescription;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     a=group:BUNDLE 0\r\n\
+    ///     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+    ///     c=IN IP4 0.0.0.0\r\n\
+    ///     a=mid:0\r\n\
+    ///     a=rtpmap:111 opus/48000/2\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let session = SessionDescription::try_from(&sdp).unwrap();
+    /// assert_eq!(session.origin.session_id, 4611731400430051336);
+    /// assert_eq!(session.attribute("group").map(String::as_str), Some("BUNDLE 0"));
+    /// assert_eq!(session.media_descriptions[0].media_name.formats, ["111"]);
+    ///
+    /// assert_eq!(Sdp::try_from(&session).unwrap(), Sdp::try_from(temp).unwrap().into_owned());
+    /// ```
+    fn try_from(value: &Sdp<'_>) -> Result<Self, Self::Error> {
+        Ok(SessionDescription::unmarshal(&mut Cursor::new(value.to_string()))?)
+    }
+}
+
+impl TryFrom<&SessionDescription> for Sdp<'static> {
+    type Error = anyhow::Error;
+    fn try_from(value: &SessionDescription) -> Result<Self, Self::Error> {
+        Ok(Sdp::try_from(value.marshal().as_str())?.into_owned())
+    }
+}
+
+impl TryFrom<&Media<'_>> for MediaDescription {
+    type Error = anyhow::Error;
+    /// A webrtc-rs media description has at most one "c=" line.
+    ///
+    /// # Unit Test
+    ///
+    /// ```
+    /// use sdp::*;
+    /// use sdp::media::*;
+    /// use std::convert::*;
+    /// use webrtc_sdp::description::media::MediaDescription;
+    ///
+    /// let temp = "v=0\r\n\
+    ///     s=-\r\n\
+    ///     t=0 0\r\n\
+    ///     m=video 9/2 UDP/TLS/RTP/SAVPF 96 97\r\n\
+    ///     i=main video\r\n\
+    ///     c=IN IP4 224.2.1.1/127\r\n\
+    ///     b=TIAS:500000\r\n\
+    ///     a=mid:1\r\n\
+    ///     a=rtcp-mux\r\n";
+    ///
+    /// let sdp = Sdp::try_from(temp).unwrap();
+    /// let media = MediaDescription::try_from(&sdp.medias[0]).unwrap();
+    /// assert_eq!(media.media_name.to_string(), "video 9/2 UDP/TLS/RTP/SAVPF 96 97");
+    /// assert_eq!(media.media_title.as_deref(), Some("main video"));
+    /// assert_eq!(media.connection_information.as_ref().unwrap().to_string(), "IN IP4 224.2.1.1/127");
+    /// assert_eq!(media.bandwidth[0].to_string(), "TIAS:500000");
+    /// assert_eq!(media.attribute("mid"), Some(Some("1")));
+    /// assert_eq!(media.attribute("rtcp-mux"), Some(None));
+    ///
+    /// assert_eq!(Media::try_from(&media).unwrap(), sdp.medias[0].clone().into_owned());
+    /// ```
+    fn try_from(value: &Media<'_>) -> Result<Self, Self::Error> {
+        ensure!(value.connections.len() <= 1, "more than one connection!");
+
+        Ok(Self {
+            media_name: MediaName {
+                media: value.encoding.to_string(),
+                port: RangedPort {
+                    value: value.port.num as isize,
+                    range: value.port.count.map(|v| v as isize),
+                },
+                protos: value.protos.iter().map(ToString::to_string).collect(),
+                formats: value.fmts.iter().map(ToString::to_string).collect(),
+            },
+            media_title: value.info.as_ref().map(|v| v.to_string()),
+            connection_information: value.connections.first().map(|v| ConnectionInformation {
+                network_type: v.nettype.to_string(),
+                address_type: v.addrtype.to_string(),
+                address: Some(Address {
+                    address: v.connection_address.ip.to_string(),
+                    ttl: v.connection_address.ttl.map(|v| v as isize),
+                    range: v.connection_address.count.map(|v| v as isize),
+                }),
+            }),
+            bandwidth: value.bandwidth
+                .iter()
+                .map(|v| WebrtcBandwidth {
+                    experimental: false,
+                    bandwidth_type: v.bwtype.to_string(),
+                    bandwidth: v.bandwidth as u64,
+                })
+                .collect(),
+            encryption_key: None,
+            attributes: value.attributes
+                .iter()
+                .map(|v| {
+                    let text = v.to_string();
+                    match text.split_once(':') {
+                        Some((key, value)) => WebrtcAttribute::new(key.to_string(), Some(value.to_string())),
+                        None => WebrtcAttribute::new(text, None),
+                    }
+                })
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&MediaDescription> for Media<'static> {
+    type Error = anyhow::Error;
+    /// The media level "k=" line has no place in [`Media`] and is an
+    /// error.
+    fn try_from(value: &MediaDescription) -> Result<Self, Self::Error> {
+        ensure!(value.encryption_key.is_none(), "media encryption key is not supported!");
+
+        let name = value.media_name.to_string();
+        let mut media = Media::try_from(name.as_str())?.into_owned();
+        media.info = value.media_title.clone().map(Into::into);
+
+        if let Some(connection) = &value.connection_information {
+            media.connections.push(Connection::try_from(connection.to_string().as_str())?);
+        }
+
+        for bandwidth in &value.bandwidth {
+            media.bandwidth.push(Bandwidth::try_from(bandwidth.to_string().as_str())?);
+        }
+
+        for attribute in &value.attributes {
+            let text = attribute.to_string();
+            media.attributes.push(Attribute::try_from(text.as_str())?.into_owned());
+        }
+
+        Ok(media)
+    }
+}
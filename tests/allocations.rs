@@ -0,0 +1,99 @@
+//! Allocation count regression tests.
+//!
+//! Every allocation made by the current thread is counted by a wrapping
+//! global allocator, so the assertions below are not affected by tests
+//! running in parallel.  When a change lowers the counts, tighten the
+//! limits accordingly.
+
+use sdp::Sdp;
+use std::alloc::{
+    GlobalAlloc,
+    System,
+    Layout
+};
+
+use std::{
+    convert::TryFrom,
+    cell::Cell
+};
+
+struct Counter;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+const AUDIO_VIDEO_DATACHANNEL: &str = include_str!("fixtures/audio_video_datachannel.sdp");
+const SIMULCAST_OFFER: &str = include_str!("fixtures/simulcast_offer.sdp");
+
+fn count<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.with(|c| c.get());
+    f();
+    ALLOCATIONS.with(|c| c.get()) - before
+}
+
+#[test]
+fn audio_video_datachannel() {
+    let n = count(|| {
+        Sdp::try_from(AUDIO_VIDEO_DATACHANNEL).unwrap();
+    });
+
+    println!("audio_video_datachannel: {} allocations", n);
+    assert!(n <= 8, "{} allocations", n);
+}
+
+#[test]
+fn simulcast_offer() {
+    let n = count(|| {
+        Sdp::try_from(SIMULCAST_OFFER).unwrap();
+    });
+
+    println!("simulcast_offer: {} allocations", n);
+    assert!(n <= 4, "{} allocations", n);
+}
+
+#[test]
+fn serialize_into() {
+    let sdp = Sdp::try_from(AUDIO_VIDEO_DATACHANNEL).unwrap();
+    let mut buf = String::new();
+
+    let n = count(|| sdp.serialize_into(&mut buf));
+    println!("serialize_into: {} allocations", n);
+    assert!(n <= 1, "{} allocations", n);
+
+    buf.clear();
+    let n = count(|| sdp.serialize_into(&mut buf));
+    assert_eq!(n, 0, "{} allocations", n);
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn arena() {
+    let bump = bumpalo::Bump::with_capacity(64 * 1024);
+
+    let n = count(|| {
+        Sdp::parse_in(&bump, AUDIO_VIDEO_DATACHANNEL).unwrap();
+    });
+
+    println!("arena: {} allocations", n);
+    assert_eq!(n, 0, "{} allocations", n);
+}
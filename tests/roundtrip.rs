@@ -0,0 +1,626 @@
+//! Property based round-trip tests.
+//!
+//! The strategies below generate valid session descriptions, every
+//! generated session is written with `Display`, parsed back with
+//! `TryFrom`, and must compare structurally equal to the original.  Add a
+//! strategy for every newly typed line or attribute, so that the
+//! serializer and the parser can not drift apart.
+
+use proptest::prelude::*;
+use proptest::collection::vec;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr
+};
+
+use sdp::*;
+use sdp::attributes::*;
+use sdp::bandwidth::*;
+use sdp::connection::*;
+use sdp::contact::*;
+use sdp::media::*;
+use sdp::options::WriteOptions;
+use sdp::origin::Origin;
+use sdp::timing::Timing;
+
+fn token() -> impl Strategy<Value = Cow<'static, str>> {
+    "[a-zA-Z0-9][a-zA-Z0-9_.+-]{0,15}".prop_map(Cow::Owned)
+}
+
+fn ipv4() -> impl Strategy<Value = IpAddr> {
+    any::<[u8; 4]>().prop_map(|v| IpAddr::V4(Ipv4Addr::from(v)))
+}
+
+fn origin() -> impl Strategy<Value = Origin<'static>> {
+    (token(), any::<u64>(), any::<u64>(), ipv4()).prop_map(|(username, id, version, ip)| Origin {
+        username: Some(username),
+        sess_id: Cow::Owned(id.to_string()),
+        sess_version: version,
+        nettype: NetKind::IN,
+        addrtype: AddrKind::IP4,
+        unicast_address: ip,
+    })
+}
+
+fn connection() -> impl Strategy<Value = Connection> {
+    prop_oneof![
+        (ipv4(), proptest::option::of((any::<u16>(), proptest::option::of(any::<u8>()))))
+            .prop_map(|(ip, range)| Connection {
+                nettype: NetKind::IN,
+                addrtype: AddrKind::IP4,
+                connection_address: Addr {
+                    ip,
+                    ttl: range.map(|(ttl, _)| ttl),
+                    count: range.and_then(|(_, count)| count),
+                },
+            }),
+        (any::<[u8; 16]>(), proptest::option::of(any::<u8>())).prop_map(|(ip, count)| Connection {
+            nettype: NetKind::IN,
+            addrtype: AddrKind::IP6,
+            connection_address: Addr {
+                ip: IpAddr::V6(Ipv6Addr::from(ip)),
+                ttl: None,
+                count,
+            },
+        }),
+    ]
+}
+
+fn contact(address: &'static str) -> impl Strategy<Value = Contact<'static>> {
+    (
+        address,
+        proptest::option::of("[a-zA-Z][a-zA-Z .]{0,15}[a-zA-Z]"),
+        prop_oneof![Just(ContactForm::Comment), Just(ContactForm::Angle)],
+    )
+        .prop_map(|(address, name, form)| Contact {
+            address: Cow::Owned(address),
+            // the form is not written without a name.
+            form: if name.is_some() { form } else { ContactForm::Comment },
+            name: name.map(Cow::Owned),
+        })
+}
+
+fn bandwidth() -> impl Strategy<Value = Bandwidth> {
+    (prop_oneof![Just(BwKind::CT), Just(BwKind::AS), Just(BwKind::TIAS)], any::<u32>()).prop_map(|(bwtype, v)| {
+        Bandwidth {
+            bwtype,
+            bandwidth: v as usize,
+        }
+    })
+}
+
+fn rtpmap() -> impl Strategy<Value = Attributes<'static>> {
+    (
+        any::<u8>(),
+        "[a-zA-Z][a-zA-Z0-9-]{0,11}",
+        proptest::option::of(8000u64..=192000),
+        proptest::option::of(1u8..=8),
+    )
+        .prop_map(|(key, codec, frequency, channels)| {
+            Attributes::Rtpmap(RtpMap {
+                key,
+                value: RtpValue {
+                    codec: Cow::Owned(codec),
+                    // channels can only be given after the clock rate.
+                    channels: frequency.and(channels),
+                    frequency,
+                },
+            })
+        })
+}
+
+fn fmtp() -> impl Strategy<Value = Attributes<'static>> {
+    let param = (token(), proptest::option::of(token()));
+    (any::<u8>(), vec(param, 1..6)).prop_map(|(key, values)| {
+        Attributes::Fmtp(Fmtp {
+            key,
+            values: values.into_iter().collect(),
+        })
+    })
+}
+
+fn extmap() -> impl Strategy<Value = Attributes<'static>> {
+    (1u8..=14, "urn:ietf:params:rtp-hdrext:[a-z0-9-]{1,16}").prop_map(|(key, uri)| {
+        Attributes::Extmap(ExtMap {
+            key,
+            value: Cow::Owned(uri),
+        })
+    })
+}
+
+fn ssrc() -> impl Strategy<Value = Attributes<'static>> {
+    (any::<u32>(), token()).prop_map(|(key, cname)| {
+        Attributes::Ssrc(Ssrc {
+            key,
+            value: SsrcAttr::Cname(cname),
+        })
+    })
+}
+
+fn candidate() -> impl Strategy<Value = Attributes<'static>> {
+    (
+        any::<u32>(),
+        1u8..=2,
+        prop_oneof![Just("udp"), Just("tcp")],
+        any::<u32>(),
+        ipv4(),
+        any::<u16>(),
+        prop_oneof![Just("host"), Just("srflx"), Just("relay")],
+    )
+        .prop_map(|(foundation, component, transport, priority, ip, port, kind)| {
+            Attributes::Other(
+                Cow::Borrowed("candidate"),
+                Some(Cow::Owned(format!(
+                    "{} {} {} {} {} {} typ {}",
+                    foundation, component, transport, priority, ip, port, kind
+                ))),
+            )
+        })
+}
+
+fn rtsp() -> impl Strategy<Value = Attributes<'static>> {
+    let npt = prop_oneof![
+        Just(NptTime::Now),
+        (0u32..100_000).prop_map(|v| NptTime::Seconds(v as f64 / 1000.0)),
+    ];
+
+    prop_oneof![
+        "rtsp://[a-z0-9.]{1,16}/[a-zA-Z0-9=/]{0,16}".prop_map(|v| Attributes::Control(Cow::Owned(v))),
+        Just(Attributes::Control(Cow::Borrowed("*"))),
+        any::<u32>().prop_map(Attributes::RtpTime),
+        (proptest::option::of(npt.clone()), proptest::option::of(npt))
+            .prop_map(|(start, end)| Attributes::Range(Range::Npt { start, end })),
+        ("[0-9]{8}T[0-9]{6}Z", proptest::option::of("[0-9]{8}T[0-9]{6}Z")).prop_map(|(start, end)| {
+            Attributes::Range(Range::Clock {
+                start: Some(Cow::Owned(start)),
+                end: end.map(Cow::Owned),
+            })
+        }),
+    ]
+}
+
+fn clock() -> impl Strategy<Value = Attributes<'static>> {
+    let refclk = prop_oneof![
+        ("IEEE1588-20(08|19)", proptest::option::of(any::<[u8; 8]>()), proptest::option::of(any::<u8>()))
+            .prop_map(|(version, gmid, domain)| RefClock::Ptp {
+                version: Cow::Owned(version),
+                gmid,
+                domain,
+            }),
+        ipv4().prop_map(|ip| RefClock::Ntp(Cow::Owned(ip.to_string()))),
+        any::<[u8; 6]>().prop_map(RefClock::LocalMac),
+        any::<bool>().prop_map(|traceable| RefClock::Private { traceable }),
+        Just(RefClock::Gps),
+        Just(RefClock::Local),
+    ];
+
+    let mediaclk = prop_oneof![
+        (proptest::option::of(any::<u64>()), proptest::option::of(any::<(u32, u32)>()))
+            .prop_map(|(offset, rate)| MediaClock::Direct { offset, rate }),
+        Just(MediaClock::Sender),
+        any::<[u8; 8]>().prop_map(MediaClock::Ieee1722),
+    ];
+
+    prop_oneof![
+        refclk.prop_map(Attributes::TsRefClk),
+        (proptest::option::of(token()), mediaclk)
+            .prop_map(|(id, clock)| Attributes::MediaClk(MediaClk { id, clock })),
+    ]
+}
+
+fn source_filter() -> impl Strategy<Value = Attributes<'static>> {
+    (
+        prop_oneof![Just(FilterMode::Include), Just(FilterMode::Exclude)],
+        prop_oneof![Just(None), Just(Some(AddrKind::IP4))],
+        ipv4(),
+        vec(ipv4(), 1..4),
+    )
+        .prop_map(|(mode, addrtype, dest, src)| {
+            Attributes::SourceFilter(SourceFilter {
+                mode,
+                nettype: NetKind::IN,
+                addrtype,
+                dest_address: Cow::Owned(dest.to_string()),
+                src_list: src.into_iter().map(|v| Cow::Owned(v.to_string())).collect(),
+            })
+        })
+}
+
+fn rtcp_xr() -> impl Strategy<Value = Attributes<'static>> {
+    let flag = prop_oneof![
+        Just(StatFlag::Loss),
+        Just(StatFlag::Dup),
+        Just(StatFlag::Jitt),
+        Just(StatFlag::Ttl),
+        Just(StatFlag::Hl),
+    ];
+
+    let block = prop_oneof![
+        proptest::option::of(any::<u32>()).prop_map(XrBlock::PktLossRle),
+        proptest::option::of(any::<u32>()).prop_map(XrBlock::PktDupRle),
+        proptest::option::of(any::<u32>()).prop_map(XrBlock::PktRcptTimes),
+        (prop_oneof![Just(RttMode::All), Just(RttMode::Sender)], proptest::option::of(any::<u32>()))
+            .prop_map(|(mode, max_size)| XrBlock::RcvrRtt { mode, max_size }),
+        vec(flag, 0..5).prop_map(XrBlock::StatSummary),
+        Just(XrBlock::VoipMetrics),
+    ];
+
+    vec(block, 0..4).prop_map(|blocks| Attributes::RtcpXr(RtcpXr { blocks }))
+}
+
+fn zrtp_hash() -> impl Strategy<Value = Attributes<'static>> {
+    ("[0-9]\\.[0-9]{1,2}", any::<[u8; 32]>()).prop_map(|(version, hash)| {
+        Attributes::ZrtpHash(ZrtpHash {
+            version: Cow::Owned(version),
+            hash,
+        })
+    })
+}
+
+fn msrp() -> impl Strategy<Value = Attributes<'static>> {
+    let uri = (any::<bool>(), token(), proptest::option::of(token()), vec(token(), 0..2)).prop_map(
+        |(secure, authority, session_id, params)| MsrpUri {
+            secure,
+            authority: Cow::Owned(format!("{}.example.com:2855", authority)),
+            session_id,
+            transport: Cow::Borrowed("tcp"),
+            params,
+        },
+    );
+
+    let types = vec(
+        prop_oneof![
+            Just(Cow::Borrowed("*")),
+            (token(), token()).prop_map(|(t, s)| Cow::Owned(format!("{}/{}", t, s))),
+        ],
+        1..4,
+    );
+
+    prop_oneof![
+        vec(uri, 1..3).prop_map(Attributes::Path),
+        types.clone().prop_map(Attributes::AcceptTypes),
+        types.prop_map(Attributes::AcceptWrappedTypes),
+        any::<u64>().prop_map(Attributes::MaxSize),
+    ]
+}
+
+fn file_transfer() -> impl Strategy<Value = Attributes<'static>> {
+    let text = "[a-zA-Z0-9][a-zA-Z0-9 ,.+-]{0,15}[a-zA-Z0-9]".prop_map(Cow::Owned);
+    let hash = (token(), vec(any::<u8>(), 1..21))
+        .prop_map(|(algorithm, value)| FileHash { algorithm, value });
+    let selector = (
+        proptest::option::of(text.clone()),
+        proptest::option::of((token(), token()).prop_map(|(t, s)| Cow::Owned(format!("{}/{}", t, s)))),
+        proptest::option::of(any::<u64>()),
+        proptest::option::of(hash),
+    )
+        .prop_map(|(name, kind, size, hash)| FileSelector { name, kind, size, hash });
+    let date = (
+        proptest::option::of(text.clone()),
+        proptest::option::of(text.clone()),
+        proptest::option::of(text),
+    )
+        .prop_map(|(creation, modification, read)| FileDate { creation, modification, read });
+
+    prop_oneof![
+        selector.prop_map(Attributes::FileSelector),
+        token().prop_map(Attributes::FileTransferId),
+        token().prop_map(Attributes::FileDisposition),
+        date.prop_map(Attributes::FileDate),
+        token().prop_map(Attributes::FileIcon),
+    ]
+}
+
+fn capneg() -> impl Strategy<Value = Attributes<'static>> {
+    let number = 1u32..100;
+    let alternative = (vec(number.clone(), 0..3), vec(number.clone(), 0..3))
+        .prop_filter("empty alternative", |(m, o)| !m.is_empty() || !o.is_empty())
+        .prop_map(|(mandatory, optional)| AttributeAlternative { mandatory, optional });
+    let delete = prop_oneof![
+        Just(None),
+        Just(Some(DeleteAttributes::Media)),
+        Just(Some(DeleteAttributes::Session)),
+        Just(Some(DeleteAttributes::Both)),
+    ];
+
+    let config = (
+        number.clone(),
+        delete,
+        vec(alternative, 0..3),
+        vec(number.clone(), 0..3),
+        vec((token(), token()), 0..2),
+    )
+        .prop_map(|(number, delete, attributes, transports, extensions)| Config {
+            number,
+            // a deletion is only written with attribute alternatives.
+            delete: if attributes.is_empty() { None } else { delete },
+            attributes,
+            transports,
+            extensions: extensions
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(format!("x-{}", k)), v))
+                .collect(),
+        });
+
+    prop_oneof![
+        (number.clone(), vec(token().prop_map(|v| Cow::Owned(format!("RTP/{}", v))), 1..3))
+            .prop_map(|(number, protos)| Attributes::Tcap(Tcap { number, protos })),
+        (number, 1u64..=120).prop_map(|(number, ptime)| Attributes::Acap(Acap {
+            number,
+            attribute: Cow::Owned(format!("ptime:{}", ptime)),
+        })),
+        (config, any::<bool>()).prop_map(|(config, potential)| match potential {
+            true => Attributes::Pcfg(config),
+            false => Attributes::Acfg(config),
+        }),
+    ]
+}
+
+fn content() -> impl Strategy<Value = Attributes<'static>> {
+    let tag = prop_oneof![
+        Just(Content::Slides),
+        Just(Content::Speaker),
+        Just(Content::Sl),
+        Just(Content::Main),
+        Just(Content::Alt),
+        "x-[a-z0-9]{1,8}".prop_map(|v| Content::Other(Cow::Owned(v))),
+    ];
+
+    prop_oneof![
+        token().prop_map(Attributes::Label),
+        vec(tag, 1..4).prop_map(Attributes::Content),
+    ]
+}
+
+fn mid() -> impl Strategy<Value = Mid<'static>> {
+    prop_oneof![
+        Just(Mid::Audio),
+        Just(Mid::Video),
+        any::<u8>().prop_map(Mid::Ref),
+        "m[a-z0-9_]{0,8}".prop_map(|v| Mid::Other(Cow::Owned(v))),
+    ]
+}
+
+fn media_attribute() -> impl Strategy<Value = Attributes<'static>> {
+    prop_oneof![
+        rtpmap(),
+        fmtp(),
+        extmap(),
+        ssrc(),
+        candidate(),
+        rtsp(),
+        clock(),
+        source_filter(),
+        rtcp_xr(),
+        zrtp_hash(),
+        msrp(),
+        file_transfer(),
+        capneg(),
+        content(),
+        prop_oneof![
+            Just(Setup::Active),
+            Just(Setup::Passive),
+            Just(Setup::Actpass),
+            Just(Setup::Holdconn),
+        ]
+        .prop_map(Attributes::Setup),
+        prop_oneof![Just(TcpConnection::New), Just(TcpConnection::Existing)].prop_map(Attributes::Connection),
+        "[a-zA-Z0-9+/_-]{20,120}".prop_map(|v| Attributes::TlsId(TlsId(Cow::Owned(v)))),
+        (1u64..=120).prop_map(Attributes::Ptime),
+        (1u64..=120).prop_map(Attributes::MaxPtime),
+        Just(Attributes::Other(Cow::Borrowed("rtcp-mux"), None)),
+        Just(Attributes::BundleOnly),
+        mid().prop_map(Attributes::Mid),
+        Just(Attributes::RtcpMuxOnly),
+        Just(Attributes::IceMismatch),
+        token().prop_map(Attributes::XGoogleFlag),
+        (1u32..10000, 0u32..10).prop_map(|(v, f)| Attributes::MaxPrate(v as f64 + f as f64 / 10.0)),
+        pss(),
+        silence_supp(),
+        fingerprint(),
+    ]
+}
+
+fn pss() -> impl Strategy<Value = Attributes<'static>> {
+    prop_oneof![
+        (any::<u8>(), 1u32..8192, 1u32..8192)
+            .prop_map(|(key, width, height)| Attributes::Framesize(Framesize { key, width, height })),
+        any::<u32>().prop_map(Attributes::AdaptationSupport),
+        any::<u32>().prop_map(Attributes::PreDecBufSize),
+        any::<u32>().prop_map(Attributes::InitPreDecBufPeriod),
+        any::<u32>().prop_map(Attributes::InitPostDecBufPeriod),
+        any::<u32>().prop_map(Attributes::DecByteRate),
+    ]
+}
+
+fn silence_supp() -> impl Strategy<Value = Attributes<'static>> {
+    let pref = prop_oneof![Just(SuppPref::Standard), Just(SuppPref::Custom)];
+    let sid_use = prop_oneof![Just(SidUse::NoSid), Just(SidUse::FixedNoise), Just(SidUse::SampledNoise)];
+    (
+        any::<bool>(),
+        proptest::option::of(0u32..100000),
+        proptest::option::of(pref),
+        proptest::option::of(sid_use),
+        proptest::option::of(0u8..=127),
+    )
+        .prop_map(|(enable, timer, pref, sid_use, fxnslevel)| {
+            Attributes::SilenceSupp(SilenceSupp {
+                enable,
+                timer,
+                pref,
+                sid_use,
+                fxnslevel,
+            })
+        })
+}
+
+fn fingerprint() -> impl Strategy<Value = Attributes<'static>> {
+    let hash = prop_oneof![
+        Just(HashFunction::Sha1),
+        Just(HashFunction::Sha256),
+        Just(HashFunction::Sha512),
+        Just(HashFunction::Md5),
+        "x-[a-z0-9]{1,8}".prop_map(|v| HashFunction::Other(Cow::Owned(v))),
+    ];
+
+    (hash, vec(any::<u8>(), 1..=64)).prop_map(|(hash, value)| {
+        Attributes::Fingerprint(Fingerprint { hash, value: value.into() })
+    })
+}
+
+fn simcap() -> impl Strategy<Value = Attributes<'static>> {
+    prop_oneof![
+        any::<u8>().prop_map(Attributes::Sqn),
+        (1u8.., token(), token(), vec(token(), 1..4)).prop_map(|(number, media, proto, fmts)| {
+            Attributes::Cdsc(Cdsc { number, media, proto, fmts })
+        }),
+        (1u64..=120).prop_map(|v| Attributes::Cpar(Cow::Owned(format!(" a=ptime:{}", v)))),
+        (1u64..1000).prop_map(|v| Attributes::CparMin(Cow::Owned(format!(" b=AS:{}", v)))),
+        (1u64..1000).prop_map(|v| Attributes::CparMax(Cow::Owned(format!(" b=AS:{}", v)))),
+    ]
+}
+
+fn session_attribute() -> impl Strategy<Value = Attributes<'static>> {
+    prop_oneof![
+        extmap(),
+        token().prop_map(Attributes::Charset),
+        token().prop_map(Attributes::SdpLang),
+        "[a-zA-Z0-9]{1,8}( [a-zA-Z0-9]{1,8}){0,3}".prop_map(|v| Attributes::Keywds(Cow::Owned(v))),
+        "[a-z0-9-]{1,8}(\\.[a-z0-9-]{1,8}){0,3}".prop_map(|v| Attributes::Cat(Category(Cow::Owned(v)))),
+        ("[a-zA-Z][a-zA-Z ]{0,15}[a-zA-Z]", proptest::option::of("[0-9][0-9.]{0,7}"))
+            .prop_map(|(name, version)| Attributes::Tool(Tool {
+                name: Cow::Owned(name),
+                version: version.map(Cow::Owned),
+            })),
+        prop_oneof![
+            Just(Kind::Broadcast),
+            Just(Kind::Meeting),
+            Just(Kind::Moderated),
+        ]
+        .prop_map(Attributes::Kind),
+        Just(Attributes::Other(Cow::Borrowed("ice-lite"), None)),
+        (1u64..=1000).prop_map(Attributes::IcePacing),
+        rtsp(),
+        clock(),
+        source_filter(),
+        simcap(),
+        (
+            prop_oneof![
+                Just(Semantics::Bundle),
+                Just(Semantics::Ls),
+                Just(Semantics::Fid),
+                Just(Semantics::Other(Cow::Borrowed("FEC"))),
+            ],
+            vec(mid(), 0..4),
+        )
+            .prop_map(|(semantics, mids)| Attributes::Group(Group {
+                semantics,
+                mids: mids.into_iter().collect(),
+            })),
+        ("[a-zA-Z0-9+/]{4,64}={0,2}", vec((token(), proptest::option::of(token())), 0..3))
+            .prop_map(|(assertion, extensions)| Attributes::Identity(Identity {
+                assertion: Cow::Owned(assertion),
+                extensions,
+            })),
+    ]
+}
+
+fn media() -> impl Strategy<Value = Media<'static>> {
+    (
+        prop_oneof![Just(Encoding::Audio), Just(Encoding::Video)],
+        any::<u16>(),
+        prop_oneof![
+            Just(vec![Proto::Udp, Proto::Tls, Proto::Rtp, Proto::Savpf]),
+            Just(vec![Proto::Rtp, Proto::Avp]),
+            Just(vec![Proto::Rtp, Proto::Savp]),
+        ],
+        vec(any::<u8>(), 1..8),
+        proptest::option::of(token()),
+        vec(connection(), 0..3),
+        vec(bandwidth(), 0..2),
+        vec(media_attribute(), 0..12),
+    )
+        .prop_map(|(encoding, port, protos, fmts, info, connections, bandwidth, attributes)| Media {
+            encoding,
+            port: Port {
+                num: port,
+                count: None,
+            },
+            protos: protos.into_iter().collect(),
+            fmts: fmts.into_iter().collect(),
+            info,
+            connections: connections.into_iter().collect(),
+            bandwidth: bandwidth.into_iter().collect(),
+            attributes: attributes.into_iter().map(Attribute::from).collect(),
+            unknown_lines: Vec::new(),
+            section: None,
+        })
+}
+
+fn session() -> impl Strategy<Value = Sdp<'static>> {
+    (
+        origin(),
+        proptest::option::of(token()),
+        proptest::option::of(token()),
+        vec(contact("[a-z0-9.]{1,8}@[a-z0-9]{1,8}\\.example\\.com"), 0..3),
+        vec(contact("\\+[0-9]{1,3}( [0-9-]{1,8}){0,3}"), 0..3),
+        proptest::option::of(connection()),
+        vec(bandwidth(), 0..2),
+        (any::<u32>(), any::<u32>()),
+        vec(session_attribute(), 0..6),
+        vec(media(), 0..4),
+    )
+        .prop_map(
+            |(origin, session_name, session_info, emails, phones, connection, bandwidth, timing, attributes, medias)| {
+                Sdp {
+                    origin: Some(origin),
+                    session_name,
+                    session_info,
+                    emails,
+                    phones,
+                    connection,
+                    bandwidth,
+                    timing: Some(Timing {
+                        start: timing.0 as u64,
+                        stop: timing.1 as u64,
+                    }),
+                    attributes: attributes.into_iter().map(Attribute::from).collect(),
+                    medias,
+                    ..Sdp::default()
+                }
+            },
+        )
+}
+
+proptest! {
+    #[test]
+    fn session_round_trip(sdp in session()) {
+        let text = sdp.to_string();
+        let parsed = Sdp::try_from(text.as_str()).unwrap().into_owned();
+        prop_assert_eq!(parsed, sdp);
+    }
+
+    #[test]
+    fn raw_sections_round_trip(sdp in session()) {
+        let text = sdp.to_string();
+        let parsed = Sdp::try_from(text.as_str()).unwrap();
+        prop_assert!(parsed.medias.iter().all(|m| m.original().is_some()));
+
+        let options = WriteOptions {
+            raw_sections: true,
+            ..Default::default()
+        };
+
+        prop_assert_eq!(parsed.display(&options).to_string(), text.as_str());
+    }
+
+    #[test]
+    fn attribute_round_trip(value in prop_oneof![media_attribute(), session_attribute()]) {
+        let text = value.to_string();
+        let parsed = Attributes::try_from(text.as_str()).unwrap().into_owned();
+        prop_assert_eq!(parsed, value);
+    }
+}